@@ -0,0 +1,804 @@
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap, HashSet},
+    fmt::Display,
+    mem,
+};
+
+use crate::{
+    id::Id,
+    meta::header::schema::{FileSchema, FileSchemas},
+    parser::{
+        layouts::{self, InSchema},
+        unknown_entity::UnknownEntity,
+        EntityRegistry,
+    },
+    prelude::*,
+};
+
+/// Attributes which reference other entities and are optional in all schemas. A reference to a
+/// dropped entity is cleared instead of dropping the entity which holds it.
+const OPTIONAL_REFERENCES: &[&str] = &["ObjectPlacement", "PlacementRelTo", "Representation"];
+
+/// Information which was lost or changed while converting a model to another schema.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionIssue {
+    /// the entity doesn't exist in the target schema and was removed
+    DroppedEntity { id: Id, keyword: String },
+    /// the entity was removed, since it required the removed entity `dependency`
+    DroppedDependent {
+        id: Id,
+        keyword: String,
+        dependency: Id,
+    },
+    /// the attribute doesn't exist in the target schema, so `value` was removed
+    DroppedAttribute {
+        id: Id,
+        keyword: String,
+        attribute: String,
+        value: StepValue,
+    },
+    /// the entity was replaced by a similar entity of the target schema
+    Approximated {
+        id: Id,
+        keyword: String,
+        reason: String,
+    },
+    /// the entity was kept as it is, since its attributes couldn't be converted
+    Unconverted { id: Id, reason: String },
+}
+
+impl Display for ConversionIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionIssue::DroppedEntity { id, keyword } => {
+                write!(f, "{id}: dropped {keyword}, which isn't part of the schema")
+            }
+            ConversionIssue::DroppedDependent {
+                id,
+                keyword,
+                dependency,
+            } => write!(f, "{id}: dropped {keyword}, which required {dependency}"),
+            ConversionIssue::DroppedAttribute {
+                id,
+                keyword,
+                attribute,
+                value,
+            } => write!(f, "{id}: dropped {attribute} = {value} of {keyword}"),
+            ConversionIssue::Approximated {
+                id,
+                keyword,
+                reason,
+            } => write!(f, "{id}: approximated {keyword}, {reason}"),
+            ConversionIssue::Unconverted { id, reason } => {
+                write!(f, "{id}: kept as it is, {reason}")
+            }
+        }
+    }
+}
+
+/// Result of [`convert_schema`], lists everything which couldn't be converted exactly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionReport {
+    /// schema of the model before the conversion
+    pub from: FileSchema,
+    /// schema the model was converted to
+    pub to: FileSchema,
+    /// issues in the order of the entity ids
+    pub issues: Vec<ConversionIssue>,
+}
+
+impl ConversionReport {
+    /// whether the model was converted without losing any information
+    pub fn is_lossless(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Display for ConversionReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "converted from {} to {} with {} issue(s)",
+            self.from,
+            self.to,
+            self.issues.len()
+        )?;
+
+        for issue in &self.issues {
+            write!(f, "\n  {issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts the model to the given schema, e.g. to deliver a model created with the
+/// [`IfcProjectBuilder`](crate::prelude::IfcProjectBuilder) as IFC2X3.
+///
+/// The model is expected to be in the schema of its header, which is replaced by `schema`.
+/// The conversion
+///
+/// * replaces entities which don't exist in the target schema by their supertype, e.g.
+///   `IfcSlabStandardCase` by `IfcSlab` for IFC2X3 and `IfcWallStandardCase` by `IfcWall` for
+///   IFC4X3
+/// * rearranges the attributes of entities whose layout differs, e.g. `IfcBuildingStorey`
+///   requires a `CompositionType` in IFC2X3, while `IfcWall` has no `PredefinedType` yet
+/// * converts `IfcIndexedPolyCurve` to `IfcPolyline` for IFC2X3, arcs become straight lines
+/// * drops other entities which don't exist in the target schema, as well as all entities
+///   which require them
+///
+/// `IfcRelDefinesByType` and all other entities which have the same layout in both schemas are
+/// kept as they are. Everything which was dropped or changed is listed in the returned report.
+///
+/// Entities which this crate models keep their types, they are written in the layout of the
+/// target schema since it's the schema of the header then. [`Dummy`] entities are kept unchanged, since their attributes are unknown.
+pub fn convert_schema(ifc: &mut IFC, schema: FileSchema) -> ConversionReport {
+    let from = ifc
+        .header
+        .schema
+        .0
+        .first()
+        .copied()
+        .unwrap_or(FileSchema::IFC4);
+
+    let mut converter = Converter {
+        to: schema,
+        issues: Vec::new(),
+        created: Vec::new(),
+        next_id: ifc.data.iter().map(|(id, _)| id.0).max().unwrap_or(0) + 1,
+    };
+
+    let mut entities = BTreeMap::new();
+    for (id, entity) in ifc.data.iter() {
+        // the entities of this crate are always written in the IFC4 layout
+        let source = if entity.downcast_ref::<UnknownEntity>().is_some() {
+            from
+        } else {
+            FileSchema::IFC4
+        };
+
+        match UnknownEntity::from_entity(&**entity) {
+            Ok(unknown) => {
+                entities.insert(*id, (unknown, source));
+            }
+            Err(err) => converter.issues.push(ConversionIssue::Unconverted {
+                id: *id,
+                reason: err.to_string(),
+            }),
+        }
+    }
+
+    let mut converted = entities
+        .iter()
+        .filter_map(|(id, (entity, source))| {
+            let entity = converter.convert(*id, entity.clone(), *source, &entities)?;
+            Some((*id, entity))
+        })
+        .collect::<BTreeMap<_, _>>();
+    converted.extend(mem::take(&mut converter.created));
+
+    let dropped = entities
+        .keys()
+        .filter(|id| !converted.contains_key(id))
+        .copied()
+        .collect();
+    let dropped = converter.drop_dependents(&mut converted, dropped);
+
+    for id in dropped {
+        ifc.data.remove(id);
+    }
+
+    let registry = EntityRegistry::for_file(&FileSchemas(vec![schema]));
+    for (id, entity) in converted {
+        ifc.data
+            .insert_boxed(id, rebuild(entity, &registry, schema));
+    }
+
+    ifc.header.schema = FileSchemas(vec![schema]);
+
+    converter.issues.sort_by_key(|issue| match issue {
+        ConversionIssue::DroppedEntity { id, .. }
+        | ConversionIssue::DroppedDependent { id, .. }
+        | ConversionIssue::DroppedAttribute { id, .. }
+        | ConversionIssue::Approximated { id, .. }
+        | ConversionIssue::Unconverted { id, .. } => *id,
+    });
+
+    ConversionReport {
+        from,
+        to: schema,
+        issues: converter.issues,
+    }
+}
+
+/// Parses the entity into the type of this crate with the layouts of the target schema, if the
+/// type writes exactly the same entity in that schema.
+fn rebuild(
+    entity: UnknownEntity,
+    registry: &EntityRegistry,
+    schema: FileSchema,
+) -> Box<dyn IfcType> {
+    let text = entity.to_string();
+
+    match entity.clone().into_entity(registry) {
+        Ok(parsed) if InSchema(&*parsed, Some(schema)).to_string() == text => parsed,
+        _ => Box::new(entity),
+    }
+}
+
+struct Converter {
+    to: FileSchema,
+    issues: Vec<ConversionIssue>,
+    /// entities which were added to replace converted ones
+    created: Vec<(Id, UnknownEntity)>,
+    next_id: usize,
+}
+
+impl Converter {
+    /// Converts the entity from the `source` layout to the target schema, `None` if it has to be
+    /// dropped.
+    fn convert(
+        &mut self,
+        id: Id,
+        mut entity: UnknownEntity,
+        source: FileSchema,
+        entities: &BTreeMap<Id, (UnknownEntity, FileSchema)>,
+    ) -> Option<UnknownEntity> {
+        if let Some(keyword) = layouts::replacement(self.to, &entity.keyword) {
+            entity.keyword = keyword.to_owned();
+        }
+
+        if self.to == FileSchema::IFC2X3 && entity.keyword == "IFCINDEXEDPOLYCURVE" {
+            if let Some(polyline) = self.polyline(id, &entity, entities) {
+                return Some(polyline);
+            }
+        }
+
+        if layouts::is_unavailable(self.to, &entity.keyword) {
+            self.issues.push(ConversionIssue::DroppedEntity {
+                id,
+                keyword: entity.keyword,
+            });
+            return None;
+        }
+
+        self.convert_attributes(id, &mut entity, source);
+
+        if entity.keyword == "IFCMONETARYUNIT" {
+            self.convert_currency(&mut entity);
+        }
+
+        Some(entity)
+    }
+
+    /// rearranges the attributes by their names, see [`layouts::IFC2X3_LAYOUTS`]
+    fn convert_attributes(&mut self, id: Id, entity: &mut UnknownEntity, source: FileSchema) {
+        let (Some(source_layout), Some(target_layout)) = (
+            layouts::layout(source, &entity.keyword),
+            layouts::layout(self.to, &entity.keyword),
+        ) else {
+            return;
+        };
+
+        if source_layout == target_layout {
+            return;
+        }
+
+        let count = layouts::attribute_names(source_layout).count();
+        if count != entity.args.len() {
+            self.issues.push(ConversionIssue::Unconverted {
+                id,
+                reason: format!(
+                    "{} has {} instead of {count} attributes of the {source} schema",
+                    entity.keyword,
+                    entity.args.len(),
+                ),
+            });
+            return;
+        }
+
+        for (name, value) in layouts::rearrange(&mut entity.args, source_layout, target_layout) {
+            // unset values don't carry any information
+            if value == StepValue::Omitted || value == StepValue::Enum("NOTDEFINED".into()) {
+                continue;
+            }
+
+            self.issues.push(ConversionIssue::DroppedAttribute {
+                id,
+                keyword: entity.keyword.clone(),
+                attribute: name.to_owned(),
+                value,
+            });
+        }
+    }
+
+    /// the currency is an enumeration in IFC2X3 and a label since IFC4
+    fn convert_currency(&self, entity: &mut UnknownEntity) {
+        let Some(currency) = entity.args.first_mut() else {
+            return;
+        };
+
+        *currency = match (self.to, &*currency) {
+            (FileSchema::IFC2X3, StepValue::String(code)) => StepValue::Enum(code.clone()),
+            (FileSchema::IFC4 | FileSchema::IFC4X3_ADD2, StepValue::Enum(code)) => {
+                StepValue::String(code.clone())
+            }
+            _ => return,
+        };
+    }
+
+    /// Replaces an `IfcIndexedPolyCurve` by an `IfcPolyline` through the same points, `None` if
+    /// the curve can't be read.
+    fn polyline(
+        &mut self,
+        id: Id,
+        curve: &UnknownEntity,
+        entities: &BTreeMap<Id, (UnknownEntity, FileSchema)>,
+    ) -> Option<UnknownEntity> {
+        let [StepValue::Id(points), segments, ..] = curve.args.as_slice() else {
+            return None;
+        };
+        let (point_list, _) = entities.get(points)?;
+        let Some(StepValue::List(coordinates)) = point_list.args.first() else {
+            return None;
+        };
+
+        let mut has_arcs = false;
+        let indices = match segments {
+            StepValue::Omitted => (1..=coordinates.len()).collect(),
+            StepValue::List(segments) => {
+                let mut indices = Vec::new();
+
+                for segment in segments {
+                    let StepValue::Typed(kind, segment) = segment else {
+                        return None;
+                    };
+                    let StepValue::List(segment) = &**segment else {
+                        return None;
+                    };
+                    has_arcs |= kind == "IFCARCINDEX";
+
+                    for index in segment {
+                        let StepValue::Integer(index) = index else {
+                            return None;
+                        };
+                        let index = usize::try_from(*index).ok()?;
+
+                        // consecutive segments share their end and start point
+                        if indices.last() != Some(&index) {
+                            indices.push(index);
+                        }
+                    }
+                }
+
+                indices
+            }
+            _ => return None,
+        };
+
+        if indices.len() < 2
+            || indices
+                .iter()
+                .any(|index| !(1..=coordinates.len()).contains(index))
+        {
+            return None;
+        }
+
+        let mut created = HashMap::new();
+        let points = indices
+            .into_iter()
+            .map(|index| {
+                let point = match created.entry(index) {
+                    Entry::Occupied(entry) => *entry.get(),
+                    Entry::Vacant(entry) => {
+                        let point = Id(self.next_id);
+                        self.next_id += 1;
+
+                        self.created.push((
+                            point,
+                            UnknownEntity::new(
+                                "IFCCARTESIANPOINT",
+                                vec![coordinates[index - 1].clone()],
+                            ),
+                        ));
+
+                        *entry.insert(point)
+                    }
+                };
+
+                StepValue::Id(point)
+            })
+            .collect();
+
+        self.issues.push(ConversionIssue::Approximated {
+            id,
+            keyword: curve.keyword.clone(),
+            reason: if has_arcs {
+                "replaced by IFCPOLYLINE with straight lines instead of arcs".to_owned()
+            } else {
+                "replaced by IFCPOLYLINE".to_owned()
+            },
+        });
+
+        Some(UnknownEntity::new(
+            "IFCPOLYLINE",
+            vec![StepValue::List(points)],
+        ))
+    }
+
+    /// Removes all references to the dropped entities and drops the entities which can't do
+    /// without them. Returns all dropped entities.
+    fn drop_dependents(
+        &mut self,
+        entities: &mut BTreeMap<Id, UnknownEntity>,
+        mut dropped: HashSet<Id>,
+    ) -> HashSet<Id> {
+        let mut newly_dropped = !dropped.is_empty();
+
+        while newly_dropped {
+            let dependents = entities
+                .iter_mut()
+                .filter_map(|(id, entity)| {
+                    let dependency = self.remove_references(*id, entity, &dropped)?;
+                    Some((*id, dependency))
+                })
+                .collect::<Vec<_>>();
+
+            newly_dropped = !dependents.is_empty();
+
+            for (id, dependency) in dependents {
+                let Some(entity) = entities.remove(&id) else {
+                    continue;
+                };
+
+                self.issues.push(ConversionIssue::DroppedDependent {
+                    id,
+                    keyword: entity.keyword,
+                    dependency,
+                });
+                dropped.insert(id);
+            }
+        }
+
+        dropped
+    }
+
+    /// Removes references to dropped entities from the attributes of the entity. Returns the
+    /// dropped entity which is required by the entity, if there is one.
+    fn remove_references(
+        &mut self,
+        id: Id,
+        entity: &mut UnknownEntity,
+        dropped: &HashSet<Id>,
+    ) -> Option<Id> {
+        let names = EntityRegistry::global().entity_type(&entity.keyword);
+
+        for (index, value) in entity.args.iter_mut().enumerate() {
+            let StepValue::Id(reference) = value else {
+                if let Some(dependency) = remove_from_lists(value, dropped) {
+                    return Some(dependency);
+                }
+                continue;
+            };
+
+            if !dropped.contains(reference) {
+                continue;
+            }
+
+            let name = names
+                .and_then(|entity_type| entity_type.attributes.get(index))
+                .map(|attribute| attribute.name)
+                .filter(|name| OPTIONAL_REFERENCES.contains(name));
+
+            let Some(name) = name else {
+                return Some(*reference);
+            };
+
+            self.issues.push(ConversionIssue::DroppedAttribute {
+                id,
+                keyword: entity.keyword.clone(),
+                attribute: name.to_owned(),
+                value: mem::replace(value, StepValue::Omitted),
+            });
+        }
+
+        None
+    }
+}
+
+/// Removes dropped entities from the lists in the value. Returns a dropped entity if the value
+/// itself is a dropped reference or a list which is empty without the dropped entities.
+fn remove_from_lists(value: &mut StepValue, dropped: &HashSet<Id>) -> Option<Id> {
+    match value {
+        StepValue::Id(id) => dropped.contains(id).then_some(*id),
+        StepValue::Typed(_, value) => remove_from_lists(value, dropped),
+        StepValue::List(values) => {
+            let mut removed = None;
+            values.retain_mut(|value| match remove_from_lists(value, dropped) {
+                Some(id) => {
+                    removed = Some(id);
+                    false
+                }
+                None => true,
+            });
+
+            removed.filter(|_| values.is_empty())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{convert_schema, ConversionIssue};
+    use crate::{id::Id, parser::layouts::InSchema, prelude::*};
+
+    fn model(schema: &str, data: &str) -> IFC {
+        IFC::from_str(&format!(
+            "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('{schema}'));
+ENDSEC;
+DATA;
+{data}
+ENDSEC;
+END-ISO-10303-21;
+"
+        ))
+        .unwrap()
+    }
+
+    /// the entity as it's written in the schema of the model
+    fn entity(ifc: &IFC, id: usize) -> String {
+        let schema = ifc.header.schema.0.first().copied();
+
+        InSchema(ifc.data.get_untyped(Id(id)), schema).to_string()
+    }
+
+    #[test]
+    fn converts_models_to_ifc2x3() {
+        let mut ifc = IFC::from_file("resources/wall-standard-case.ifc").unwrap();
+        assert_eq!(ifc.data.find_all_of_type::<Wall>().count(), 1);
+        let report = convert_schema(&mut ifc, FileSchema::IFC2X3);
+
+        assert_eq!(report.from, FileSchema::IFC4);
+        assert_eq!(report.to, FileSchema::IFC2X3);
+        assert!(report.issues.contains(&ConversionIssue::DroppedEntity {
+            id: Id(14),
+            keyword: "IFCRELDECLARES".to_owned(),
+        }));
+
+        assert_eq!(
+            entity(&ifc, 1),
+            "IFCBUILDING('39t4Pu3nTC4ekXYRIHJB9W',#2,'IfcBuilding',$,$,$,$,$,.ELEMENT.,$,$,$);"
+        );
+
+        // typed entities are kept and written in the IFC2X3 layouts
+        let (_, wall) = ifc.data.find_all_of_type::<Wall>().next().unwrap();
+        assert!(wall.predefined_type.is_omitted());
+        assert!(ifc
+            .to_string()
+            .contains("IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,#33,#25,$);"));
+
+        let mut converted = IFC::from_str(&ifc.to_string()).unwrap();
+        assert_eq!(converted.header.schema.0, [FileSchema::IFC2X3]);
+
+        let report = convert_schema(&mut converted, FileSchema::IFC2X3);
+        assert!(report.is_lossless(), "{report}");
+        assert_eq!(converted.to_string(), ifc.to_string());
+    }
+
+    #[test]
+    fn replaces_standard_cases_by_their_supertype() {
+        let mut ifc = model(
+            "IFC4",
+            "#1= IFCMEMBERSTANDARDCASE('0DWgwt6o1FOx7466fPk$jl',$,'Brace',$,$,$,$,$,.BRACE.);",
+        );
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC2X3);
+
+        assert_eq!(
+            entity(&ifc, 1),
+            "IFCMEMBER('0DWgwt6o1FOx7466fPk$jl',$,'Brace',$,$,$,$,$);"
+        );
+        assert_eq!(
+            report.issues,
+            [ConversionIssue::DroppedAttribute {
+                id: Id(1),
+                keyword: "IFCMEMBER".to_owned(),
+                attribute: "PredefinedType".to_owned(),
+                value: StepValue::Enum("BRACE".to_owned()),
+            }]
+        );
+    }
+
+    #[test]
+    fn converts_indexed_poly_curves_to_polylines() {
+        let mut ifc = model(
+            "IFC4",
+            "#1= IFCCARTESIANPOINTLIST2D(((0.,0.),(1.,0.),(1.,1.)));
+#2= IFCINDEXEDPOLYCURVE(#1,(IFCLINEINDEX((1,2)),IFCLINEINDEX((2,3,1))),.F.);",
+        );
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC2X3);
+
+        assert_eq!(entity(&ifc, 2), "IFCPOLYLINE((#3,#4,#5,#3));");
+        assert_eq!(entity(&ifc, 3), "IFCCARTESIANPOINT((0.,0.));");
+        assert_eq!(entity(&ifc, 5), "IFCCARTESIANPOINT((1.,1.));");
+        assert!(!ifc.data.contains(&Id(1)));
+
+        assert_eq!(
+            report.to_string(),
+            "converted from IFC4 to IFC2X3 with 2 issue(s)
+  #1: dropped IFCCARTESIANPOINTLIST2D, which isn't part of the schema
+  #2: approximated IFCINDEXEDPOLYCURVE, replaced by IFCPOLYLINE"
+        );
+    }
+
+    #[test]
+    fn drops_entities_which_require_dropped_ones() {
+        let mut ifc = model(
+            "IFC4",
+            "#1= IFCCARTESIANPOINTLIST3D(((0.,0.,0.),(1.,0.,0.),(1.,1.,0.)));
+#2= IFCTRIANGULATEDFACESET(#1,$,$,((1,2,3)),$);
+#3= IFCSHAPEREPRESENTATION(#4,'Body','Tessellation',(#2));
+#4= IFCGEOMETRICREPRESENTATIONCONTEXT($,'Model',3,0.0001,#7,$);
+#5= IFCPRODUCTDEFINITIONSHAPE($,$,(#3));
+#6= IFCWALL('0DWgwt6o1FOx7466fPk$jl',$,'Wall',$,$,$,#5,$,.SOLIDWALL.);
+#7= IFCAXIS2PLACEMENT3D(#8,$,$);
+#8= IFCCARTESIANPOINT((0.,0.,0.));",
+        );
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC2X3);
+
+        assert_eq!(ifc.data.len(), 4);
+        assert_eq!(
+            entity(&ifc, 6),
+            "IFCWALL('0DWgwt6o1FOx7466fPk$jl',$,'Wall',$,$,$,$,$);"
+        );
+        assert_eq!(
+            report.to_string(),
+            "converted from IFC4 to IFC2X3 with 6 issue(s)
+  #1: dropped IFCCARTESIANPOINTLIST3D, which isn't part of the schema
+  #2: dropped IFCTRIANGULATEDFACESET, which isn't part of the schema
+  #3: dropped IFCSHAPEREPRESENTATION, which required #2
+  #5: dropped IFCPRODUCTDEFINITIONSHAPE, which required #3
+  #6: dropped PredefinedType = .SOLIDWALL. of IFCWALL
+  #6: dropped Representation = #5 of IFCWALL"
+        );
+    }
+
+    #[test]
+    fn converts_building_element_proxies() {
+        let mut ifc = model(
+            "IFC4",
+            "#1= IFCBUILDINGELEMENTPROXY('0DWgwt6o1FOx7466fPk$jl',$,'Void',$,$,$,$,$,.PROVISIONFORVOID.);",
+        );
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC2X3);
+
+        assert_eq!(
+            entity(&ifc, 1),
+            "IFCBUILDINGELEMENTPROXY('0DWgwt6o1FOx7466fPk$jl',$,'Void',$,$,$,$,$,$);"
+        );
+        assert_eq!(
+            report.to_string(),
+            "converted from IFC4 to IFC2X3 with 1 issue(s)
+  #1: dropped PredefinedType = .PROVISIONFORVOID. of IFCBUILDINGELEMENTPROXY"
+        );
+
+        let mut ifc = model(
+            "IFC2X3",
+            "#1= IFCBUILDINGELEMENTPROXY('0DWgwt6o1FOx7466fPk$jl',$,'Proxy',$,$,$,$,$,.ELEMENT.);",
+        );
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC4);
+
+        assert_eq!(
+            entity(&ifc, 1),
+            "IFCBUILDINGELEMENTPROXY('0DWgwt6o1FOx7466fPk$jl',$,'Proxy',$,$,$,$,$,$);"
+        );
+        assert_eq!(
+            report.to_string(),
+            "converted from IFC2X3 to IFC4 with 1 issue(s)
+  #1: dropped CompositionType = .ELEMENT. of IFCBUILDINGELEMENTPROXY"
+        );
+    }
+
+    #[test]
+    fn keeps_unset_predefined_types_of_slabs() {
+        let mut ifc = model(
+            "IFC4",
+            "#1= IFCSLAB('0DWgwt6o1FOx7466fPk$jl',$,'Slab',$,$,$,$,$,$);",
+        );
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC2X3);
+
+        assert!(report.is_lossless(), "{report}");
+        assert_eq!(
+            entity(&ifc, 1),
+            "IFCSLAB('0DWgwt6o1FOx7466fPk$jl',$,'Slab',$,$,$,$,$,$);"
+        );
+    }
+
+    #[test]
+    fn converts_interior_or_exterior_spaces() {
+        let data = "#1= IFCSPACE('0DWgwt6o1FOx7466fPk$jl',$,'Space',$,$,$,$,'Kitchen',.ELEMENT.,.INTERNAL.,0.);";
+        let mut ifc = model("IFC2X3", data);
+
+        // the value has no place in the IFC4 layout, so the entity keeps the layout of the file
+        assert!(ifc.to_string().contains(data));
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC4);
+
+        assert_eq!(
+            entity(&ifc, 1),
+            "IFCSPACE('0DWgwt6o1FOx7466fPk$jl',$,'Space',$,$,$,$,'Kitchen',.ELEMENT.,$,0.);"
+        );
+        assert_eq!(
+            report.to_string(),
+            "converted from IFC2X3 to IFC4 with 1 issue(s)
+  #1: dropped InteriorOrExteriorSpace = .INTERNAL. of IFCSPACE"
+        );
+
+        let mut ifc = model(
+            "IFC4",
+            "#1= IFCSPACE('0DWgwt6o1FOx7466fPk$jl',$,'Space',$,$,$,$,'Parking',.ELEMENT.,.PARKING.,$);",
+        );
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC2X3);
+
+        // the space type isn't written into the interior or exterior flag of IFC2X3
+        assert_eq!(
+            entity(&ifc, 1),
+            "IFCSPACE('0DWgwt6o1FOx7466fPk$jl',$,'Space',$,$,$,$,'Parking',.ELEMENT.,.NOTDEFINED.,$);"
+        );
+        assert_eq!(
+            report.to_string(),
+            "converted from IFC4 to IFC2X3 with 1 issue(s)
+  #1: dropped PredefinedType = .PARKING. of IFCSPACE"
+        );
+    }
+
+    #[test]
+    fn converts_ifc2x3_models_to_ifc4() {
+        let data = "#1= IFCWALLSTANDARDCASE('0DWgwt6o1FOx7466fPk$jl',$,'Wall',$,$,$,$,$);
+#2= IFCBUILDINGSTOREY('0DWgwt6o1FOx7466fPk$jm',$,'Storey',$,$,$,$,$,.ELEMENT.,0.);
+#3= IFCMONETARYUNIT(.EUR.);
+#4= IFCWALLTYPE('0DWgwt6o1FOx7466fPk$jn',$,'Type',$,$,$,$,$,$,.STANDARD.);
+#5= IFCRELDEFINESBYTYPE('0DWgwt6o1FOx7466fPk$jo',$,$,$,(#1),#4);";
+        let mut ifc = model("IFC2X3", data);
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC4);
+        assert!(report.is_lossless(), "{report}");
+
+        // standard case walls are read as `IfcWall`
+        assert_eq!(
+            entity(&ifc, 1),
+            "IFCWALL('0DWgwt6o1FOx7466fPk$jl',$,'Wall',$,$,$,$,$,$);"
+        );
+        assert_eq!(entity(&ifc, 3), "IFCMONETARYUNIT('EUR');");
+        assert_eq!(
+            entity(&ifc, 5),
+            "IFCRELDEFINESBYTYPE('0DWgwt6o1FOx7466fPk$jo',$,$,$,(#1),#4);"
+        );
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC4X3_ADD2);
+        assert!(report.is_lossless(), "{report}");
+        assert_eq!(ifc.header.schema.0, [FileSchema::IFC4X3_ADD2]);
+
+        let report = convert_schema(&mut ifc, FileSchema::IFC2X3);
+        assert!(report.is_lossless(), "{report}");
+        assert_eq!(
+            (1..=5).map(|id| entity(&ifc, id)).collect::<Vec<_>>(),
+            data.replace("IFCWALLSTANDARDCASE", "IFCWALL")
+                .lines()
+                .map(|line| line.split_once("= ").unwrap().1)
+                .collect::<Vec<_>>()
+        );
+    }
+}
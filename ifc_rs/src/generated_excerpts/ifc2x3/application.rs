@@ -0,0 +1,16 @@
+use super::*;
+
+/// IfcApplication of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcApplication")]
+pub struct Application {
+    pub application_developer: TypedId<Organization>,
+
+    pub version: StringPrimitive,
+
+    pub application_full_name: StringPrimitive,
+
+    pub application_identifier: StringPrimitive,
+}
+
+impl IfcType for Application {}
@@ -0,0 +1,27 @@
+use super::*;
+
+/// IfcAxis2Placement2D of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcAxis2Placement2D")]
+pub struct Axis2Placement2D {
+    #[inherited]
+    placement: Placement,
+
+    pub ref_direction: OptionalParameter<TypedId<Direction>>,
+}
+
+impl Deref for Axis2Placement2D {
+    type Target = Placement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.placement
+    }
+}
+
+impl DerefMut for Axis2Placement2D {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.placement
+    }
+}
+
+impl IfcType for Axis2Placement2D {}
@@ -0,0 +1,22 @@
+use super::*;
+
+/// IfcBuildingElement of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct BuildingElement {
+    #[inherited]
+    element: Element,
+}
+
+impl Deref for BuildingElement {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for BuildingElement {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
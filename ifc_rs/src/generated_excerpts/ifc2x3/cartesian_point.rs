@@ -0,0 +1,10 @@
+use super::*;
+
+/// IfcCartesianPoint of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcCartesianPoint")]
+pub struct CartesianPoint {
+    pub coordinates: IfcList<RealPrimitive>,
+}
+
+impl IfcType for CartesianPoint {}
@@ -0,0 +1,10 @@
+use super::*;
+
+/// IfcDirection of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcDirection")]
+pub struct Direction {
+    pub direction_ratios: IfcList<RealPrimitive>,
+}
+
+impl IfcType for Direction {}
@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcElement of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct Element {
+    #[inherited]
+    product: Product,
+
+    pub tag: OptionalParameter<StringPrimitive>,
+}
+
+impl Deref for Element {
+    type Target = Product;
+
+    fn deref(&self) -> &Self::Target {
+        &self.product
+    }
+}
+
+impl DerefMut for Element {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.product
+    }
+}
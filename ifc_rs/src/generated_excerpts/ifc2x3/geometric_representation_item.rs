@@ -0,0 +1,5 @@
+use super::*;
+
+/// IfcGeometricRepresentationItem of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct GeometricRepresentationItem {}
@@ -0,0 +1,14 @@
+use super::*;
+
+/// IfcLocalPlacement of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcLocalPlacement")]
+pub struct LocalPlacement {
+    #[ifc_types(LocalPlacement)]
+    pub placement_rel_to: OptionalParameter<Id>,
+
+    #[ifc_types(Axis2Placement2D, Axis2Placement3D)]
+    pub relative_placement: Id,
+}
+
+impl IfcType for LocalPlacement {}
@@ -0,0 +1,8 @@
+use super::*;
+
+/// IfcLoop of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcLoop")]
+pub struct Loop {}
+
+impl IfcType for Loop {}
@@ -0,0 +1,10 @@
+use super::*;
+
+/// IfcMaterial of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcMaterial")]
+pub struct Material {
+    pub name: StringPrimitive,
+}
+
+impl IfcType for Material {}
@@ -0,0 +1,14 @@
+use super::*;
+
+/// IfcMaterialLayer of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcMaterialLayer")]
+pub struct MaterialLayer {
+    pub material: OptionalParameter<TypedId<Material>>,
+
+    pub layer_thickness: RealPrimitive,
+
+    pub is_ventilated: OptionalParameter<BoolPrimitive>,
+}
+
+impl IfcType for MaterialLayer {}
@@ -0,0 +1,12 @@
+use super::*;
+
+/// IfcMaterialLayerSet of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcMaterialLayerSet")]
+pub struct MaterialLayerSet {
+    pub material_layers: IfcList<TypedId<MaterialLayer>>,
+
+    pub layer_set_name: OptionalParameter<StringPrimitive>,
+}
+
+impl IfcType for MaterialLayerSet {}
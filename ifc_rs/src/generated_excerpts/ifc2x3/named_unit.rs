@@ -0,0 +1,9 @@
+use super::*;
+
+/// IfcNamedUnit of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct NamedUnit {
+    pub dimensions: OptionalParameter<TypedId<DimensionalExponents>>,
+
+    pub unit_type: UnitEnum,
+}
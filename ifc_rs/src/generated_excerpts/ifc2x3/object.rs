@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcObject of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct Object {
+    #[inherited]
+    object_definition: ObjectDefinition,
+
+    pub object_type: OptionalParameter<StringPrimitive>,
+}
+
+impl Deref for Object {
+    type Target = ObjectDefinition;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object_definition
+    }
+}
+
+impl DerefMut for Object {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object_definition
+    }
+}
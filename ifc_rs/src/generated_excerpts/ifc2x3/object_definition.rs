@@ -0,0 +1,22 @@
+use super::*;
+
+/// IfcObjectDefinition of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct ObjectDefinition {
+    #[inherited]
+    root: Root,
+}
+
+impl Deref for ObjectDefinition {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl DerefMut for ObjectDefinition {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.root
+    }
+}
@@ -0,0 +1,14 @@
+use super::*;
+
+/// IfcOrganization of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcOrganization")]
+pub struct Organization {
+    pub id: OptionalParameter<StringPrimitive>,
+
+    pub name: StringPrimitive,
+
+    pub description: OptionalParameter<StringPrimitive>,
+}
+
+impl IfcType for Organization {}
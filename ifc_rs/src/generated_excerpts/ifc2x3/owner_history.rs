@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcOwnerHistory of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcOwnerHistory")]
+pub struct OwnerHistory {
+    pub owning_user: TypedId<PersonAndOrganization>,
+
+    pub owning_application: TypedId<Application>,
+
+    pub state: OptionalParameter<StateEnum>,
+
+    pub change_action: ChangeActionEnum,
+
+    pub last_modified_date: OptionalParameter<IntegerPrimitive>,
+
+    pub last_modifying_user: OptionalParameter<TypedId<PersonAndOrganization>>,
+
+    pub last_modifying_application: OptionalParameter<TypedId<Application>>,
+
+    pub creation_date: IntegerPrimitive,
+}
+
+impl IfcType for OwnerHistory {}
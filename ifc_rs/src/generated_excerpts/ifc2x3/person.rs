@@ -0,0 +1,20 @@
+use super::*;
+
+/// IfcPerson of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcPerson")]
+pub struct Person {
+    pub id: OptionalParameter<StringPrimitive>,
+
+    pub family_name: OptionalParameter<StringPrimitive>,
+
+    pub given_name: OptionalParameter<StringPrimitive>,
+
+    pub middle_names: OptionalParameter<IfcList<StringPrimitive>>,
+
+    pub prefix_titles: OptionalParameter<IfcList<StringPrimitive>>,
+
+    pub suffix_titles: OptionalParameter<IfcList<StringPrimitive>>,
+}
+
+impl IfcType for Person {}
@@ -0,0 +1,12 @@
+use super::*;
+
+/// IfcPersonAndOrganization of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcPersonAndOrganization")]
+pub struct PersonAndOrganization {
+    pub the_person: TypedId<Person>,
+
+    pub the_organization: TypedId<Organization>,
+}
+
+impl IfcType for PersonAndOrganization {}
@@ -0,0 +1,7 @@
+use super::*;
+
+/// IfcPlacement of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct Placement {
+    pub location: TypedId<CartesianPoint>,
+}
@@ -0,0 +1,10 @@
+use super::*;
+
+/// IfcPolyLoop of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcPolyLoop")]
+pub struct PolyLoop {
+    pub polygon: IfcList<TypedId<CartesianPoint>>,
+}
+
+impl IfcType for PolyLoop {}
@@ -0,0 +1,28 @@
+use super::*;
+
+/// IfcProduct of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct Product {
+    #[inherited]
+    object: Object,
+
+    #[ifc_types(LocalPlacement)]
+    pub object_placement: OptionalParameter<Id>,
+
+    #[ifc_types(ProductDefinitionShape, ProductRepresentation)]
+    pub representation: OptionalParameter<Id>,
+}
+
+impl Deref for Product {
+    type Target = Object;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl DerefMut for Product {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+/// IfcProductDefinitionShape of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcProductDefinitionShape")]
+pub struct ProductDefinitionShape {
+    #[inherited]
+    product_representation: ProductRepresentation,
+}
+
+impl Deref for ProductDefinitionShape {
+    type Target = ProductRepresentation;
+
+    fn deref(&self) -> &Self::Target {
+        &self.product_representation
+    }
+}
+
+impl DerefMut for ProductDefinitionShape {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.product_representation
+    }
+}
+
+impl IfcType for ProductDefinitionShape {}
@@ -0,0 +1,15 @@
+use super::*;
+
+/// IfcProductRepresentation of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcProductRepresentation")]
+pub struct ProductRepresentation {
+    pub name: OptionalParameter<StringPrimitive>,
+
+    pub description: OptionalParameter<StringPrimitive>,
+
+    #[ifc_types(Representation, ShapeRepresentation)]
+    pub representations: IfcList<Id>,
+}
+
+impl IfcType for ProductRepresentation {}
@@ -0,0 +1,9 @@
+use super::*;
+
+/// IfcProperty of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct Property {
+    pub name: StringPrimitive,
+
+    pub description: OptionalParameter<StringPrimitive>,
+}
@@ -0,0 +1,30 @@
+use super::*;
+
+/// IfcPropertySingleValue of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcPropertySingleValue")]
+pub struct PropertySingleValue {
+    #[inherited]
+    simple_property: SimpleProperty,
+
+    pub nominal_value: OptionalParameter<StepValue>,
+
+    #[ifc_types(SIUnit)]
+    pub unit: OptionalParameter<Id>,
+}
+
+impl Deref for PropertySingleValue {
+    type Target = SimpleProperty;
+
+    fn deref(&self) -> &Self::Target {
+        &self.simple_property
+    }
+}
+
+impl DerefMut for PropertySingleValue {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.simple_property
+    }
+}
+
+impl IfcType for PropertySingleValue {}
@@ -0,0 +1,18 @@
+use super::*;
+
+/// IfcRepresentation of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcRepresentation")]
+pub struct Representation {
+    #[ifc_types(GeometricRepresentationContext, GeometricRepresentationSubContext, RepresentationContext)]
+    pub context_of_items: Id,
+
+    pub representation_identifier: OptionalParameter<StringPrimitive>,
+
+    pub representation_type: OptionalParameter<StringPrimitive>,
+
+    #[ifc_types(Axis2Placement2D, Axis2Placement3D, CartesianPoint, Direction, Loop, PolyLoop)]
+    pub items: IfcList<Id>,
+}
+
+impl IfcType for Representation {}
@@ -0,0 +1,12 @@
+use super::*;
+
+/// IfcRepresentationContext of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcRepresentationContext")]
+pub struct RepresentationContext {
+    pub context_identifier: OptionalParameter<StringPrimitive>,
+
+    pub context_type: OptionalParameter<StringPrimitive>,
+}
+
+impl IfcType for RepresentationContext {}
@@ -0,0 +1,13 @@
+use super::*;
+
+/// IfcRoot of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct Root {
+    pub global_id: IfcGloballyUniqueId,
+
+    pub owner_history: TypedId<OwnerHistory>,
+
+    pub name: OptionalParameter<StringPrimitive>,
+
+    pub description: OptionalParameter<StringPrimitive>,
+}
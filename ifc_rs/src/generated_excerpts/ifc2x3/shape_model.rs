@@ -0,0 +1,22 @@
+use super::*;
+
+/// IfcShapeModel of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct ShapeModel {
+    #[inherited]
+    representation: Representation,
+}
+
+impl Deref for ShapeModel {
+    type Target = Representation;
+
+    fn deref(&self) -> &Self::Target {
+        &self.representation
+    }
+}
+
+impl DerefMut for ShapeModel {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.representation
+    }
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+/// IfcShapeRepresentation of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcShapeRepresentation")]
+pub struct ShapeRepresentation {
+    #[inherited]
+    shape_model: ShapeModel,
+}
+
+impl Deref for ShapeRepresentation {
+    type Target = ShapeModel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.shape_model
+    }
+}
+
+impl DerefMut for ShapeRepresentation {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.shape_model
+    }
+}
+
+impl IfcType for ShapeRepresentation {}
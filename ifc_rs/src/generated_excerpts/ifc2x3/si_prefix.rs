@@ -0,0 +1,37 @@
+use std::str::FromStr;
+
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// IfcSIPrefix of the IFC2X3 schema.
+#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+pub enum SIPrefix {
+    #[strum(to_string = ".KILO.")]
+    Kilo,
+
+    #[strum(to_string = ".CENTI.")]
+    Centi,
+
+    #[strum(to_string = ".MILLI.")]
+    Milli,
+}
+
+impl IFCParse for SIPrefix {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid SIPrefix")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+crate::traits::reflect::step_enumeration!(SIPrefix);
@@ -0,0 +1,29 @@
+use super::*;
+
+/// IfcSIUnit of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcSIUnit", derived = "Dimensions")]
+pub struct SIUnit {
+    #[inherited]
+    named_unit: NamedUnit,
+
+    pub prefix: OptionalParameter<SIPrefix>,
+
+    pub name: SIUnitName,
+}
+
+impl Deref for SIUnit {
+    type Target = NamedUnit;
+
+    fn deref(&self) -> &Self::Target {
+        &self.named_unit
+    }
+}
+
+impl DerefMut for SIUnit {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.named_unit
+    }
+}
+
+impl IfcType for SIUnit {}
@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// IfcSIUnitName of the IFC2X3 schema.
+#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+pub enum SIUnitName {
+    #[strum(to_string = ".METRE.")]
+    Metre,
+
+    #[strum(to_string = ".SQUARE_METRE.")]
+    SquareMetre,
+
+    #[strum(to_string = ".CUBIC_METRE.")]
+    CubicMetre,
+
+    #[strum(to_string = ".RADIAN.")]
+    Radian,
+}
+
+impl IFCParse for SIUnitName {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid SIUnitName")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+crate::traits::reflect::step_enumeration!(SIUnitName);
@@ -0,0 +1,22 @@
+use super::*;
+
+/// IfcSimpleProperty of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct SimpleProperty {
+    #[inherited]
+    property: Property,
+}
+
+impl Deref for SimpleProperty {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        &self.property
+    }
+}
+
+impl DerefMut for SimpleProperty {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.property
+    }
+}
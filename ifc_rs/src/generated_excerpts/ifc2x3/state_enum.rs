@@ -0,0 +1,43 @@
+use std::str::FromStr;
+
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// IfcStateEnum of the IFC2X3 schema.
+#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+pub enum StateEnum {
+    #[strum(to_string = ".READWRITE.")]
+    Readwrite,
+
+    #[strum(to_string = ".READONLY.")]
+    Readonly,
+
+    #[strum(to_string = ".LOCKED.")]
+    Locked,
+
+    #[strum(to_string = ".READWRITELOCKED.")]
+    Readwritelocked,
+
+    #[strum(to_string = ".READONLYLOCKED.")]
+    Readonlylocked,
+}
+
+impl IFCParse for StateEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid StateEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+crate::traits::reflect::step_enumeration!(StateEnum);
@@ -0,0 +1,5 @@
+use super::*;
+
+/// IfcTopologicalRepresentationItem of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+pub struct TopologicalRepresentationItem {}
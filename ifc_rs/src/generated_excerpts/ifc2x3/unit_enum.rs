@@ -0,0 +1,43 @@
+use std::str::FromStr;
+
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// IfcUnitEnum of the IFC2X3 schema.
+#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+pub enum UnitEnum {
+    #[strum(to_string = ".AREAUNIT.")]
+    Areaunit,
+
+    #[strum(to_string = ".LENGTHUNIT.")]
+    Lengthunit,
+
+    #[strum(to_string = ".PLANEANGLEUNIT.")]
+    Planeangleunit,
+
+    #[strum(to_string = ".VOLUMEUNIT.")]
+    Volumeunit,
+
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+}
+
+impl IFCParse for UnitEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid UnitEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+crate::traits::reflect::step_enumeration!(UnitEnum);
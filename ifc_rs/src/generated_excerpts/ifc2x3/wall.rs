@@ -0,0 +1,25 @@
+use super::*;
+
+/// IfcWall of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcWall")]
+pub struct Wall {
+    #[inherited]
+    building_element: BuildingElement,
+}
+
+impl Deref for Wall {
+    type Target = BuildingElement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.building_element
+    }
+}
+
+impl DerefMut for Wall {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.building_element
+    }
+}
+
+impl IfcType for Wall {}
@@ -0,0 +1,25 @@
+use super::*;
+
+/// IfcWallStandardCase of the IFC2X3 schema.
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcWallStandardCase")]
+pub struct WallStandardCase {
+    #[inherited]
+    wall: Wall,
+}
+
+impl Deref for WallStandardCase {
+    type Target = Wall;
+
+    fn deref(&self) -> &Self::Target {
+        &self.wall
+    }
+}
+
+impl DerefMut for WallStandardCase {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.wall
+    }
+}
+
+impl IfcType for WallStandardCase {}
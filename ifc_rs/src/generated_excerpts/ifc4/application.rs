@@ -0,0 +1,18 @@
+use super::*;
+
+/// IfcApplication of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcapplication.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcApplication")]
+pub struct Application {
+    pub application_developer: TypedId<Organization>,
+
+    pub version: StringPrimitive,
+
+    pub application_full_name: StringPrimitive,
+
+    pub application_identifier: StringPrimitive,
+}
+
+impl IfcType for Application {}
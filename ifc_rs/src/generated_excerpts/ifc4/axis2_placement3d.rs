@@ -0,0 +1,31 @@
+use super::*;
+
+/// IfcAxis2Placement3D of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcaxis2placement3d.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcAxis2Placement3D")]
+pub struct Axis2Placement3D {
+    #[inherited]
+    placement: Placement,
+
+    pub axis: OptionalParameter<TypedId<Direction>>,
+
+    pub ref_direction: OptionalParameter<TypedId<Direction>>,
+}
+
+impl Deref for Axis2Placement3D {
+    type Target = Placement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.placement
+    }
+}
+
+impl DerefMut for Axis2Placement3D {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.placement
+    }
+}
+
+impl IfcType for Axis2Placement3D {}
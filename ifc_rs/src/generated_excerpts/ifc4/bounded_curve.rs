@@ -0,0 +1,7 @@
+use super::*;
+
+/// IfcBoundedCurve of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcboundedcurve.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct BoundedCurve {}
@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcBuildingElement of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcbuildingelement.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct BuildingElement {
+    #[inherited]
+    element: Element,
+}
+
+impl Deref for BuildingElement {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for BuildingElement {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
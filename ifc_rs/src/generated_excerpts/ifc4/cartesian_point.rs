@@ -0,0 +1,12 @@
+use super::*;
+
+/// IfcCartesianPoint of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifccartesianpoint.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcCartesianPoint")]
+pub struct CartesianPoint {
+    pub coordinates: IfcList<RealPrimitive>,
+}
+
+impl IfcType for CartesianPoint {}
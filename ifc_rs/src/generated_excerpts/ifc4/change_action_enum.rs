@@ -0,0 +1,45 @@
+use std::str::FromStr;
+
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// IfcChangeActionEnum of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcchangeactionenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+pub enum ChangeActionEnum {
+    #[strum(to_string = ".NOCHANGE.")]
+    Nochange,
+
+    #[strum(to_string = ".MODIFIED.")]
+    Modified,
+
+    #[strum(to_string = ".ADDED.")]
+    Added,
+
+    #[strum(to_string = ".DELETED.")]
+    Deleted,
+
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for ChangeActionEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid ChangeActionEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+crate::traits::reflect::step_enumeration!(ChangeActionEnum);
@@ -0,0 +1,16 @@
+use super::*;
+
+/// IfcDerivedUnit of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcderivedunit.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcDerivedUnit")]
+pub struct DerivedUnit {
+    pub elements: IfcList<TypedId<DerivedUnitElement>>,
+
+    pub unit_type: DerivedUnitEnum,
+
+    pub user_defined_type: OptionalParameter<StringPrimitive>,
+}
+
+impl IfcType for DerivedUnit {}
@@ -0,0 +1,15 @@
+use super::*;
+
+/// IfcDerivedUnitElement of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcderivedunitelement.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcDerivedUnitElement")]
+pub struct DerivedUnitElement {
+    #[ifc_types(SIUnit)]
+    pub unit: Id,
+
+    pub exponent: IntegerPrimitive,
+}
+
+impl IfcType for DerivedUnitElement {}
@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// IfcDerivedUnitEnum of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcderivedunitenum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+pub enum DerivedUnitEnum {
+    #[strum(to_string = ".AREADENSITYUNIT.")]
+    Areadensityunit,
+
+    #[strum(to_string = ".LINEARVELOCITYUNIT.")]
+    Linearvelocityunit,
+
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+}
+
+impl IFCParse for DerivedUnitEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid DerivedUnitEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+crate::traits::reflect::step_enumeration!(DerivedUnitEnum);
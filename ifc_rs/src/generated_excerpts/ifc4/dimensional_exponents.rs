@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcDimensionalExponents of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcdimensionalexponents.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcDimensionalExponents")]
+pub struct DimensionalExponents {
+    pub length_exponent: IntegerPrimitive,
+
+    pub mass_exponent: IntegerPrimitive,
+
+    pub time_exponent: IntegerPrimitive,
+
+    pub electric_current_exponent: IntegerPrimitive,
+
+    pub thermodynamic_temperature_exponent: IntegerPrimitive,
+
+    pub amount_of_substance_exponent: IntegerPrimitive,
+
+    pub luminous_intensity_exponent: IntegerPrimitive,
+}
+
+impl IfcType for DimensionalExponents {}
@@ -0,0 +1,12 @@
+use super::*;
+
+/// IfcDirection of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcdirection.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcDirection")]
+pub struct Direction {
+    pub direction_ratios: IfcList<RealPrimitive>,
+}
+
+impl IfcType for Direction {}
@@ -0,0 +1,26 @@
+use super::*;
+
+/// IfcElement of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcelement.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Element {
+    #[inherited]
+    product: Product,
+
+    pub tag: OptionalParameter<StringPrimitive>,
+}
+
+impl Deref for Element {
+    type Target = Product;
+
+    fn deref(&self) -> &Self::Target {
+        &self.product
+    }
+}
+
+impl DerefMut for Element {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.product
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+/// IfcGeometricRepresentationContext of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcgeometricrepresentationcontext.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcGeometricRepresentationContext")]
+pub struct GeometricRepresentationContext {
+    #[inherited]
+    representation_context: RepresentationContext,
+
+    pub coordinate_space_dimension: OptionalParameter<IntegerPrimitive>,
+
+    pub precision: OptionalParameter<RealPrimitive>,
+
+    #[ifc_types(Axis2Placement2D, Axis2Placement3D)]
+    pub world_coordinate_system: OptionalParameter<Id>,
+
+    pub true_north: OptionalParameter<TypedId<Direction>>,
+}
+
+impl Deref for GeometricRepresentationContext {
+    type Target = RepresentationContext;
+
+    fn deref(&self) -> &Self::Target {
+        &self.representation_context
+    }
+}
+
+impl DerefMut for GeometricRepresentationContext {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.representation_context
+    }
+}
+
+impl IfcType for GeometricRepresentationContext {}
@@ -0,0 +1,36 @@
+use super::*;
+
+/// IfcGeometricRepresentationSubContext of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcgeometricrepresentationsubcontext.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcGeometricRepresentationSubContext", derived = "WorldCoordinateSystem", derived = "CoordinateSpaceDimension", derived = "TrueNorth", derived = "Precision")]
+pub struct GeometricRepresentationSubContext {
+    #[inherited]
+    geometric_representation_context: GeometricRepresentationContext,
+
+    #[ifc_types(GeometricRepresentationContext, GeometricRepresentationSubContext)]
+    pub parent_context: Id,
+
+    pub target_scale: OptionalParameter<RealPrimitive>,
+
+    pub target_view: GeometricProjectionEnum,
+
+    pub user_defined_target_view: OptionalParameter<StringPrimitive>,
+}
+
+impl Deref for GeometricRepresentationSubContext {
+    type Target = GeometricRepresentationContext;
+
+    fn deref(&self) -> &Self::Target {
+        &self.geometric_representation_context
+    }
+}
+
+impl DerefMut for GeometricRepresentationSubContext {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.geometric_representation_context
+    }
+}
+
+impl IfcType for GeometricRepresentationSubContext {}
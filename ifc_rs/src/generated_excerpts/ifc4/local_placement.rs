@@ -0,0 +1,16 @@
+use super::*;
+
+/// IfcLocalPlacement of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifclocalplacement.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcLocalPlacement")]
+pub struct LocalPlacement {
+    #[ifc_types(LocalPlacement)]
+    pub placement_rel_to: OptionalParameter<Id>,
+
+    #[ifc_types(Axis2Placement2D, Axis2Placement3D)]
+    pub relative_placement: Id,
+}
+
+impl IfcType for LocalPlacement {}
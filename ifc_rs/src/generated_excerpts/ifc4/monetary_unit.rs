@@ -0,0 +1,12 @@
+use super::*;
+
+/// IfcMonetaryUnit of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcmonetaryunit.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcMonetaryUnit")]
+pub struct MonetaryUnit {
+    pub currency: StringPrimitive,
+}
+
+impl IfcType for MonetaryUnit {}
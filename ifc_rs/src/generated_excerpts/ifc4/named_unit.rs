@@ -0,0 +1,11 @@
+use super::*;
+
+/// IfcNamedUnit of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcnamedunit.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct NamedUnit {
+    pub dimensions: OptionalParameter<TypedId<DimensionalExponents>>,
+
+    pub unit_type: UnitEnum,
+}
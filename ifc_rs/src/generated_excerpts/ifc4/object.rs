@@ -0,0 +1,26 @@
+use super::*;
+
+/// IfcObject of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcobject.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Object {
+    #[inherited]
+    object_definition: ObjectDefinition,
+
+    pub object_type: OptionalParameter<StringPrimitive>,
+}
+
+impl Deref for Object {
+    type Target = ObjectDefinition;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object_definition
+    }
+}
+
+impl DerefMut for Object {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object_definition
+    }
+}
@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcObjectDefinition of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcobjectdefinition.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct ObjectDefinition {
+    #[inherited]
+    root: Root,
+}
+
+impl Deref for ObjectDefinition {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl DerefMut for ObjectDefinition {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.root
+    }
+}
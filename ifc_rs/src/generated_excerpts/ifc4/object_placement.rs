@@ -0,0 +1,7 @@
+use super::*;
+
+/// IfcObjectPlacement of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcobjectplacement.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct ObjectPlacement {}
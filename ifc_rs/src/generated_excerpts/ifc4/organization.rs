@@ -0,0 +1,16 @@
+use super::*;
+
+/// IfcOrganization of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcorganization.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcOrganization")]
+pub struct Organization {
+    pub identification: OptionalParameter<StringPrimitive>,
+
+    pub name: StringPrimitive,
+
+    pub description: OptionalParameter<StringPrimitive>,
+}
+
+impl IfcType for Organization {}
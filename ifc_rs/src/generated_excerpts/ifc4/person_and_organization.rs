@@ -0,0 +1,14 @@
+use super::*;
+
+/// IfcPersonAndOrganization of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcpersonandorganization.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcPersonAndOrganization")]
+pub struct PersonAndOrganization {
+    pub the_person: TypedId<Person>,
+
+    pub the_organization: TypedId<Organization>,
+}
+
+impl IfcType for PersonAndOrganization {}
@@ -0,0 +1,9 @@
+use super::*;
+
+/// IfcPlacement of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcplacement.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Placement {
+    pub location: TypedId<CartesianPoint>,
+}
@@ -0,0 +1,12 @@
+use super::*;
+
+/// IfcPolyline of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcpolyline.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcPolyline")]
+pub struct Polyline {
+    pub points: IfcList<TypedId<CartesianPoint>>,
+}
+
+impl IfcType for Polyline {}
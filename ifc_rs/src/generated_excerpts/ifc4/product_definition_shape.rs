@@ -0,0 +1,27 @@
+use super::*;
+
+/// IfcProductDefinitionShape of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcproductdefinitionshape.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcProductDefinitionShape")]
+pub struct ProductDefinitionShape {
+    #[inherited]
+    product_representation: ProductRepresentation,
+}
+
+impl Deref for ProductDefinitionShape {
+    type Target = ProductRepresentation;
+
+    fn deref(&self) -> &Self::Target {
+        &self.product_representation
+    }
+}
+
+impl DerefMut for ProductDefinitionShape {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.product_representation
+    }
+}
+
+impl IfcType for ProductDefinitionShape {}
@@ -0,0 +1,14 @@
+use super::*;
+
+/// IfcProductRepresentation of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcproductrepresentation.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct ProductRepresentation {
+    pub name: OptionalParameter<StringPrimitive>,
+
+    pub description: OptionalParameter<StringPrimitive>,
+
+    #[ifc_types(ShapeRepresentation)]
+    pub representations: IfcList<Id>,
+}
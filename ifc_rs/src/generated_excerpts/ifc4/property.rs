@@ -0,0 +1,11 @@
+use super::*;
+
+/// IfcProperty of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcproperty.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Property {
+    pub name: StringPrimitive,
+
+    pub description: OptionalParameter<StringPrimitive>,
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+/// IfcPropertySingleValue of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcpropertysinglevalue.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcPropertySingleValue")]
+pub struct PropertySingleValue {
+    #[inherited]
+    simple_property: SimpleProperty,
+
+    pub nominal_value: OptionalParameter<StepValue>,
+
+    #[ifc_types(DerivedUnit, MonetaryUnit, SIUnit)]
+    pub unit: OptionalParameter<Id>,
+}
+
+impl Deref for PropertySingleValue {
+    type Target = SimpleProperty;
+
+    fn deref(&self) -> &Self::Target {
+        &self.simple_property
+    }
+}
+
+impl DerefMut for PropertySingleValue {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.simple_property
+    }
+}
+
+impl IfcType for PropertySingleValue {}
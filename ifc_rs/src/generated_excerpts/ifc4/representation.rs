@@ -0,0 +1,17 @@
+use super::*;
+
+/// IfcRepresentation of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcrepresentation.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Representation {
+    #[ifc_types(GeometricRepresentationContext, GeometricRepresentationSubContext)]
+    pub context_of_items: Id,
+
+    pub representation_identifier: OptionalParameter<StringPrimitive>,
+
+    pub representation_type: OptionalParameter<StringPrimitive>,
+
+    #[ifc_types(Axis2Placement2D, Axis2Placement3D, CartesianPoint, Direction, Polyline)]
+    pub items: IfcList<Id>,
+}
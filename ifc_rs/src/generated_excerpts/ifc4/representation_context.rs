@@ -0,0 +1,11 @@
+use super::*;
+
+/// IfcRepresentationContext of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcrepresentationcontext.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct RepresentationContext {
+    pub context_identifier: OptionalParameter<StringPrimitive>,
+
+    pub context_type: OptionalParameter<StringPrimitive>,
+}
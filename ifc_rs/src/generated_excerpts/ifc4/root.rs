@@ -0,0 +1,15 @@
+use super::*;
+
+/// IfcRoot of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcroot.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Root {
+    pub global_id: IfcGloballyUniqueId,
+
+    pub owner_history: OptionalParameter<TypedId<OwnerHistory>>,
+
+    pub name: OptionalParameter<StringPrimitive>,
+
+    pub description: OptionalParameter<StringPrimitive>,
+}
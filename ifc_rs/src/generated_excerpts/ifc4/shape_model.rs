@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcShapeModel of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcshapemodel.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct ShapeModel {
+    #[inherited]
+    representation: Representation,
+}
+
+impl Deref for ShapeModel {
+    type Target = Representation;
+
+    fn deref(&self) -> &Self::Target {
+        &self.representation
+    }
+}
+
+impl DerefMut for ShapeModel {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.representation
+    }
+}
@@ -0,0 +1,27 @@
+use super::*;
+
+/// IfcShapeRepresentation of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcshaperepresentation.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcShapeRepresentation")]
+pub struct ShapeRepresentation {
+    #[inherited]
+    shape_model: ShapeModel,
+}
+
+impl Deref for ShapeRepresentation {
+    type Target = ShapeModel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.shape_model
+    }
+}
+
+impl DerefMut for ShapeRepresentation {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.shape_model
+    }
+}
+
+impl IfcType for ShapeRepresentation {}
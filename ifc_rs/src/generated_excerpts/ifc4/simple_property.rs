@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcSimpleProperty of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcsimpleproperty.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct SimpleProperty {
+    #[inherited]
+    property: Property,
+}
+
+impl Deref for SimpleProperty {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        &self.property
+    }
+}
+
+impl DerefMut for SimpleProperty {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.property
+    }
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+/// IfcWall of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcwall.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcWall")]
+pub struct Wall {
+    #[inherited]
+    building_element: BuildingElement,
+
+    pub predefined_type: OptionalParameter<WallTypeEnum>,
+}
+
+impl Deref for Wall {
+    type Target = BuildingElement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.building_element
+    }
+}
+
+impl DerefMut for Wall {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.building_element
+    }
+}
+
+impl IfcType for Wall {}
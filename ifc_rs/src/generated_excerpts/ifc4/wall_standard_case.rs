@@ -0,0 +1,27 @@
+use super::*;
+
+/// IfcWallStandardCase of the IFC4 schema.
+///
+/// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/ifcwallstandardcase.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcWallStandardCase")]
+pub struct WallStandardCase {
+    #[inherited]
+    wall: Wall,
+}
+
+impl Deref for WallStandardCase {
+    type Target = Wall;
+
+    fn deref(&self) -> &Self::Target {
+        &self.wall
+    }
+}
+
+impl DerefMut for WallStandardCase {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.wall
+    }
+}
+
+impl IfcType for WallStandardCase {}
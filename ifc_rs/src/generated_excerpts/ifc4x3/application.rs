@@ -0,0 +1,18 @@
+use super::*;
+
+/// IfcApplication of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcApplication.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcApplication")]
+pub struct Application {
+    pub application_developer: TypedId<Organization>,
+
+    pub version: StringPrimitive,
+
+    pub application_full_name: StringPrimitive,
+
+    pub application_identifier: StringPrimitive,
+}
+
+impl IfcType for Application {}
@@ -0,0 +1,27 @@
+use super::*;
+
+/// IfcBuiltElement of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcBuiltElement.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcBuiltElement")]
+pub struct BuiltElement {
+    #[inherited]
+    element: Element,
+}
+
+impl Deref for BuiltElement {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl DerefMut for BuiltElement {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.element
+    }
+}
+
+impl IfcType for BuiltElement {}
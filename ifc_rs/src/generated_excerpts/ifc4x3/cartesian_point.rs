@@ -0,0 +1,12 @@
+use super::*;
+
+/// IfcCartesianPoint of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcCartesianPoint.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcCartesianPoint")]
+pub struct CartesianPoint {
+    pub coordinates: IfcList<RealPrimitive>,
+}
+
+impl IfcType for CartesianPoint {}
@@ -0,0 +1,14 @@
+use super::*;
+
+/// IfcCartesianPointList2D of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcCartesianPointList2D.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcCartesianPointList2D")]
+pub struct CartesianPointList2D {
+    pub coord_list: IfcList<IfcList<RealPrimitive>>,
+
+    pub tag_list: OptionalParameter<IfcList<StringPrimitive>>,
+}
+
+impl IfcType for CartesianPointList2D {}
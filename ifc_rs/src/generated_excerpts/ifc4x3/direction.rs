@@ -0,0 +1,12 @@
+use super::*;
+
+/// IfcDirection of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcDirection.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcDirection")]
+pub struct Direction {
+    pub direction_ratios: IfcList<RealPrimitive>,
+}
+
+impl IfcType for Direction {}
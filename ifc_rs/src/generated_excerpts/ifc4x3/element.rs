@@ -0,0 +1,26 @@
+use super::*;
+
+/// IfcElement of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcElement.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Element {
+    #[inherited]
+    product: Product,
+
+    pub tag: OptionalParameter<StringPrimitive>,
+}
+
+impl Deref for Element {
+    type Target = Product;
+
+    fn deref(&self) -> &Self::Target {
+        &self.product
+    }
+}
+
+impl DerefMut for Element {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.product
+    }
+}
@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// IfcGeometricProjectionEnum of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcGeometricProjectionEnum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+pub enum GeometricProjectionEnum {
+    #[strum(to_string = ".GRAPH_VIEW.")]
+    GraphView,
+
+    #[strum(to_string = ".SKETCH_VIEW.")]
+    SketchView,
+
+    #[strum(to_string = ".MODEL_VIEW.")]
+    ModelView,
+
+    #[strum(to_string = ".PLAN_VIEW.")]
+    PlanView,
+
+    #[strum(to_string = ".REFLECTED_PLAN_VIEW.")]
+    ReflectedPlanView,
+
+    #[strum(to_string = ".SECTION_VIEW.")]
+    SectionView,
+
+    #[strum(to_string = ".ELEVATION_VIEW.")]
+    ElevationView,
+
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for GeometricProjectionEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid GeometricProjectionEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+crate::traits::reflect::step_enumeration!(GeometricProjectionEnum);
@@ -0,0 +1,7 @@
+use super::*;
+
+/// IfcGeometricRepresentationItem of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcGeometricRepresentationItem.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct GeometricRepresentationItem {}
@@ -0,0 +1,17 @@
+use super::*;
+
+/// IfcIndexedPolyCurve of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcIndexedPolyCurve.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcIndexedPolyCurve")]
+pub struct IndexedPolyCurve {
+    #[ifc_types(CartesianPointList2D)]
+    pub points: Id,
+
+    pub segments: OptionalParameter<StepValue>,
+
+    pub self_intersect: OptionalParameter<BoolPrimitive>,
+}
+
+impl IfcType for IndexedPolyCurve {}
@@ -0,0 +1,30 @@
+use super::*;
+
+/// IfcLocalPlacement of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcLocalPlacement.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcLocalPlacement")]
+pub struct LocalPlacement {
+    #[inherited]
+    object_placement: ObjectPlacement,
+
+    #[ifc_types(Axis2Placement2D, Axis2Placement3D)]
+    pub relative_placement: Id,
+}
+
+impl Deref for LocalPlacement {
+    type Target = ObjectPlacement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object_placement
+    }
+}
+
+impl DerefMut for LocalPlacement {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object_placement
+    }
+}
+
+impl IfcType for LocalPlacement {}
@@ -0,0 +1,133 @@
+//! Entities of the IFC4X3_ADD2 schema, generated from its EXPRESS definition by `ifc_rs_codegen`.
+//!
+//! Don't edit these modules, run the generator again instead.
+
+#![allow(unused_imports)]
+
+pub(crate) mod application;
+pub(crate) mod axis2_placement2d;
+pub(crate) mod axis2_placement3d;
+pub(crate) mod bounded_curve;
+pub(crate) mod built_element;
+pub(crate) mod cartesian_point;
+pub(crate) mod cartesian_point_list;
+pub(crate) mod cartesian_point_list2d;
+pub(crate) mod change_action_enum;
+pub(crate) mod curve;
+pub(crate) mod direction;
+pub(crate) mod element;
+pub(crate) mod geometric_projection_enum;
+pub(crate) mod geometric_representation_context;
+pub(crate) mod geometric_representation_item;
+pub(crate) mod geometric_representation_sub_context;
+pub(crate) mod indexed_poly_curve;
+pub(crate) mod local_placement;
+pub(crate) mod monetary_unit;
+pub(crate) mod object;
+pub(crate) mod object_definition;
+pub(crate) mod object_placement;
+pub(crate) mod organization;
+pub(crate) mod owner_history;
+pub(crate) mod person;
+pub(crate) mod person_and_organization;
+pub(crate) mod placement;
+pub(crate) mod point;
+pub(crate) mod product;
+pub(crate) mod product_definition_shape;
+pub(crate) mod product_representation;
+pub(crate) mod property;
+pub(crate) mod property_abstraction;
+pub(crate) mod property_single_value;
+pub(crate) mod representation;
+pub(crate) mod representation_context;
+pub(crate) mod representation_item;
+pub(crate) mod root;
+pub(crate) mod shape_model;
+pub(crate) mod shape_representation;
+pub(crate) mod simple_property;
+pub(crate) mod state_enum;
+pub(crate) mod wall;
+pub(crate) mod wall_type_enum;
+
+use std::ops::{Deref, DerefMut};
+
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
+
+use crate::{
+    id::{Id, IfcGloballyUniqueId, TypedId},
+    parser::{
+        bool::BoolPrimitive, integer::IntegerPrimitive, list::IfcList, optional::OptionalParameter,
+        real::RealPrimitive, string::StringPrimitive, EntityRegistry,
+    },
+    prelude::{Dummy, IfcType, IfcVerify, StepValue, UnknownEntity},
+    IFC,
+};
+
+pub use application::Application;
+pub use axis2_placement2d::Axis2Placement2D;
+pub use axis2_placement3d::Axis2Placement3D;
+pub use bounded_curve::BoundedCurve;
+pub use built_element::BuiltElement;
+pub use cartesian_point::CartesianPoint;
+pub use cartesian_point_list::CartesianPointList;
+pub use cartesian_point_list2d::CartesianPointList2D;
+pub use change_action_enum::ChangeActionEnum;
+pub use curve::Curve;
+pub use direction::Direction;
+pub use element::Element;
+pub use geometric_projection_enum::GeometricProjectionEnum;
+pub use geometric_representation_context::GeometricRepresentationContext;
+pub use geometric_representation_item::GeometricRepresentationItem;
+pub use geometric_representation_sub_context::GeometricRepresentationSubContext;
+pub use indexed_poly_curve::IndexedPolyCurve;
+pub use local_placement::LocalPlacement;
+pub use monetary_unit::MonetaryUnit;
+pub use object::Object;
+pub use object_definition::ObjectDefinition;
+pub use object_placement::ObjectPlacement;
+pub use organization::Organization;
+pub use owner_history::OwnerHistory;
+pub use person::Person;
+pub use person_and_organization::PersonAndOrganization;
+pub use placement::Placement;
+pub use point::Point;
+pub use product::Product;
+pub use product_definition_shape::ProductDefinitionShape;
+pub use product_representation::ProductRepresentation;
+pub use property::Property;
+pub use property_abstraction::PropertyAbstraction;
+pub use property_single_value::PropertySingleValue;
+pub use representation::Representation;
+pub use representation_context::RepresentationContext;
+pub use representation_item::RepresentationItem;
+pub use root::Root;
+pub use shape_model::ShapeModel;
+pub use shape_representation::ShapeRepresentation;
+pub use simple_property::SimpleProperty;
+pub use state_enum::StateEnum;
+pub use wall::Wall;
+pub use wall_type_enum::WallTypeEnum;
+
+/// registers the parsers of all entities which can be instantiated
+pub fn register(registry: &mut EntityRegistry) {
+    registry.register::<Application>("IFCAPPLICATION");
+    registry.register::<Axis2Placement2D>("IFCAXIS2PLACEMENT2D");
+    registry.register::<Axis2Placement3D>("IFCAXIS2PLACEMENT3D");
+    registry.register::<BuiltElement>("IFCBUILTELEMENT");
+    registry.register::<CartesianPoint>("IFCCARTESIANPOINT");
+    registry.register::<CartesianPointList2D>("IFCCARTESIANPOINTLIST2D");
+    registry.register::<Direction>("IFCDIRECTION");
+    registry.register::<GeometricRepresentationContext>("IFCGEOMETRICREPRESENTATIONCONTEXT");
+    registry.register::<GeometricRepresentationSubContext>("IFCGEOMETRICREPRESENTATIONSUBCONTEXT");
+    registry.register::<IndexedPolyCurve>("IFCINDEXEDPOLYCURVE");
+    registry.register::<LocalPlacement>("IFCLOCALPLACEMENT");
+    registry.register::<MonetaryUnit>("IFCMONETARYUNIT");
+    registry.register::<Organization>("IFCORGANIZATION");
+    registry.register::<OwnerHistory>("IFCOWNERHISTORY");
+    registry.register::<Person>("IFCPERSON");
+    registry.register::<PersonAndOrganization>("IFCPERSONANDORGANIZATION");
+    registry.register::<ProductDefinitionShape>("IFCPRODUCTDEFINITIONSHAPE");
+    registry.register::<PropertySingleValue>("IFCPROPERTYSINGLEVALUE");
+    registry.register::<ShapeRepresentation>("IFCSHAPEREPRESENTATION");
+    registry.register::<Wall>("IFCWALL");
+}
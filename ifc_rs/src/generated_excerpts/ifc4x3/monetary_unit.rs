@@ -0,0 +1,12 @@
+use super::*;
+
+/// IfcMonetaryUnit of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcMonetaryUnit.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcMonetaryUnit")]
+pub struct MonetaryUnit {
+    pub currency: StringPrimitive,
+}
+
+impl IfcType for MonetaryUnit {}
@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcObjectDefinition of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcObjectDefinition.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct ObjectDefinition {
+    #[inherited]
+    root: Root,
+}
+
+impl Deref for ObjectDefinition {
+    type Target = Root;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+impl DerefMut for ObjectDefinition {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.root
+    }
+}
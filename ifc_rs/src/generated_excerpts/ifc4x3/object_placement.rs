@@ -0,0 +1,10 @@
+use super::*;
+
+/// IfcObjectPlacement of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcObjectPlacement.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct ObjectPlacement {
+    #[ifc_types(LocalPlacement)]
+    pub placement_rel_to: OptionalParameter<Id>,
+}
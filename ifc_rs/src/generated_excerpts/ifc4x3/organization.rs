@@ -0,0 +1,16 @@
+use super::*;
+
+/// IfcOrganization of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcOrganization.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcOrganization")]
+pub struct Organization {
+    pub identification: OptionalParameter<StringPrimitive>,
+
+    pub name: StringPrimitive,
+
+    pub description: OptionalParameter<StringPrimitive>,
+}
+
+impl IfcType for Organization {}
@@ -0,0 +1,22 @@
+use super::*;
+
+/// IfcPerson of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcPerson.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcPerson")]
+pub struct Person {
+    pub identification: OptionalParameter<StringPrimitive>,
+
+    pub family_name: OptionalParameter<StringPrimitive>,
+
+    pub given_name: OptionalParameter<StringPrimitive>,
+
+    pub middle_names: OptionalParameter<IfcList<StringPrimitive>>,
+
+    pub prefix_titles: OptionalParameter<IfcList<StringPrimitive>>,
+
+    pub suffix_titles: OptionalParameter<IfcList<StringPrimitive>>,
+}
+
+impl IfcType for Person {}
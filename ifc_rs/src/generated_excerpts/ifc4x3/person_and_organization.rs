@@ -0,0 +1,14 @@
+use super::*;
+
+/// IfcPersonAndOrganization of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcPersonAndOrganization.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcPersonAndOrganization")]
+pub struct PersonAndOrganization {
+    pub the_person: TypedId<Person>,
+
+    pub the_organization: TypedId<Organization>,
+}
+
+impl IfcType for PersonAndOrganization {}
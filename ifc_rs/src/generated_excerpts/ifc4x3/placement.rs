@@ -0,0 +1,9 @@
+use super::*;
+
+/// IfcPlacement of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcPlacement.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Placement {
+    pub location: TypedId<CartesianPoint>,
+}
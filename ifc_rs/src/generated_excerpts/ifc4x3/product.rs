@@ -0,0 +1,30 @@
+use super::*;
+
+/// IfcProduct of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcProduct.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Product {
+    #[inherited]
+    object: Object,
+
+    #[ifc_types(LocalPlacement)]
+    pub object_placement: OptionalParameter<Id>,
+
+    #[ifc_types(ProductDefinitionShape)]
+    pub representation: OptionalParameter<Id>,
+}
+
+impl Deref for Product {
+    type Target = Object;
+
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl DerefMut for Product {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
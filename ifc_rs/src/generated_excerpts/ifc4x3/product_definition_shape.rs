@@ -0,0 +1,27 @@
+use super::*;
+
+/// IfcProductDefinitionShape of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcProductDefinitionShape.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcProductDefinitionShape")]
+pub struct ProductDefinitionShape {
+    #[inherited]
+    product_representation: ProductRepresentation,
+}
+
+impl Deref for ProductDefinitionShape {
+    type Target = ProductRepresentation;
+
+    fn deref(&self) -> &Self::Target {
+        &self.product_representation
+    }
+}
+
+impl DerefMut for ProductDefinitionShape {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.product_representation
+    }
+}
+
+impl IfcType for ProductDefinitionShape {}
@@ -0,0 +1,14 @@
+use super::*;
+
+/// IfcProductRepresentation of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcProductRepresentation.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct ProductRepresentation {
+    pub name: OptionalParameter<StringPrimitive>,
+
+    pub description: OptionalParameter<StringPrimitive>,
+
+    #[ifc_types(ShapeRepresentation)]
+    pub representations: IfcList<Id>,
+}
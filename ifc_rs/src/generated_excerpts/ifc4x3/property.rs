@@ -0,0 +1,11 @@
+use super::*;
+
+/// IfcProperty of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcProperty.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Property {
+    pub name: StringPrimitive,
+
+    pub specification: OptionalParameter<StringPrimitive>,
+}
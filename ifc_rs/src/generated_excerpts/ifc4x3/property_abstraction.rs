@@ -0,0 +1,7 @@
+use super::*;
+
+/// IfcPropertyAbstraction of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcPropertyAbstraction.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct PropertyAbstraction {}
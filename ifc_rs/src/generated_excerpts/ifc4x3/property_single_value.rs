@@ -0,0 +1,32 @@
+use super::*;
+
+/// IfcPropertySingleValue of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcPropertySingleValue.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcPropertySingleValue")]
+pub struct PropertySingleValue {
+    #[inherited]
+    simple_property: SimpleProperty,
+
+    pub nominal_value: OptionalParameter<StepValue>,
+
+    #[ifc_types(MonetaryUnit)]
+    pub unit: OptionalParameter<Id>,
+}
+
+impl Deref for PropertySingleValue {
+    type Target = SimpleProperty;
+
+    fn deref(&self) -> &Self::Target {
+        &self.simple_property
+    }
+}
+
+impl DerefMut for PropertySingleValue {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.simple_property
+    }
+}
+
+impl IfcType for PropertySingleValue {}
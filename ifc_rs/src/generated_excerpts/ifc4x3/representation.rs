@@ -0,0 +1,17 @@
+use super::*;
+
+/// IfcRepresentation of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcRepresentation.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Representation {
+    #[ifc_types(GeometricRepresentationContext, GeometricRepresentationSubContext)]
+    pub context_of_items: Id,
+
+    pub representation_identifier: OptionalParameter<StringPrimitive>,
+
+    pub representation_type: OptionalParameter<StringPrimitive>,
+
+    #[ifc_types(Axis2Placement2D, Axis2Placement3D, CartesianPoint, CartesianPointList2D, Direction, IndexedPolyCurve)]
+    pub items: IfcList<Id>,
+}
@@ -0,0 +1,11 @@
+use super::*;
+
+/// IfcRepresentationContext of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcRepresentationContext.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct RepresentationContext {
+    pub context_identifier: OptionalParameter<StringPrimitive>,
+
+    pub context_type: OptionalParameter<StringPrimitive>,
+}
@@ -0,0 +1,7 @@
+use super::*;
+
+/// IfcRepresentationItem of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcRepresentationItem.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct RepresentationItem {}
@@ -0,0 +1,15 @@
+use super::*;
+
+/// IfcRoot of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcRoot.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct Root {
+    pub global_id: IfcGloballyUniqueId,
+
+    pub owner_history: OptionalParameter<TypedId<OwnerHistory>>,
+
+    pub name: OptionalParameter<StringPrimitive>,
+
+    pub description: OptionalParameter<StringPrimitive>,
+}
@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcShapeModel of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcShapeModel.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct ShapeModel {
+    #[inherited]
+    representation: Representation,
+}
+
+impl Deref for ShapeModel {
+    type Target = Representation;
+
+    fn deref(&self) -> &Self::Target {
+        &self.representation
+    }
+}
+
+impl DerefMut for ShapeModel {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.representation
+    }
+}
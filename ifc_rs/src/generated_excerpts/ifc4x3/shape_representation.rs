@@ -0,0 +1,27 @@
+use super::*;
+
+/// IfcShapeRepresentation of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcShapeRepresentation.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcShapeRepresentation")]
+pub struct ShapeRepresentation {
+    #[inherited]
+    shape_model: ShapeModel,
+}
+
+impl Deref for ShapeRepresentation {
+    type Target = ShapeModel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.shape_model
+    }
+}
+
+impl DerefMut for ShapeRepresentation {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.shape_model
+    }
+}
+
+impl IfcType for ShapeRepresentation {}
@@ -0,0 +1,24 @@
+use super::*;
+
+/// IfcSimpleProperty of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcSimpleProperty.htm
+#[derive(IfcVerify, IfcEntity)]
+pub struct SimpleProperty {
+    #[inherited]
+    property: Property,
+}
+
+impl Deref for SimpleProperty {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        &self.property
+    }
+}
+
+impl DerefMut for SimpleProperty {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.property
+    }
+}
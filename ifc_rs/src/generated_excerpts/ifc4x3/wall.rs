@@ -0,0 +1,29 @@
+use super::*;
+
+/// IfcWall of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcWall.htm
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcWall")]
+pub struct Wall {
+    #[inherited]
+    built_element: BuiltElement,
+
+    pub predefined_type: OptionalParameter<WallTypeEnum>,
+}
+
+impl Deref for Wall {
+    type Target = BuiltElement;
+
+    fn deref(&self) -> &Self::Target {
+        &self.built_element
+    }
+}
+
+impl DerefMut for Wall {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.built_element
+    }
+}
+
+impl IfcType for Wall {}
@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use strum::{Display, EnumString, VariantNames};
+use winnow::combinator::{alt, delimited};
+use winnow::Parser;
+
+use crate::parser::*;
+
+/// IfcWallTypeEnum of the IFC4X3_ADD2 schema.
+///
+/// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcWallTypeEnum.htm
+#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+pub enum WallTypeEnum {
+    #[strum(to_string = ".ELEMENTEDWALL.")]
+    Elementedwall,
+
+    #[strum(to_string = ".MOVABLE.")]
+    Movable,
+
+    #[strum(to_string = ".PARAPET.")]
+    Parapet,
+
+    #[strum(to_string = ".PARTITIONING.")]
+    Partitioning,
+
+    #[strum(to_string = ".PLUMBINGWALL.")]
+    Plumbingwall,
+
+    #[strum(to_string = ".POLYGONAL.")]
+    Polygonal,
+
+    #[strum(to_string = ".RETAININGWALL.")]
+    Retainingwall,
+
+    #[strum(to_string = ".SHEAR.")]
+    Shear,
+
+    #[strum(to_string = ".SOLIDWALL.")]
+    Solidwall,
+
+    #[strum(to_string = ".STANDARD.")]
+    Standard,
+
+    #[strum(to_string = ".WAVEWALL.")]
+    Wavewall,
+
+    #[strum(to_string = ".USERDEFINED.")]
+    UserDefined,
+
+    #[strum(to_string = ".NOTDEFINED.")]
+    NotDefined,
+}
+
+impl IFCParse for WallTypeEnum {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        let variants: [&str; Self::VARIANTS.len()] =
+            Self::VARIANTS.try_into().expect("statically known");
+
+        delimited(
+            p_space_or_comment(),
+            alt(variants
+                .map(|v| (v, Self::from_str(v).expect("valid WallTypeEnum")))
+                .map(|(k, v)| k.map(move |_| v))),
+            p_space_or_comment(),
+        )
+    }
+}
+
+crate::traits::reflect::step_enumeration!(WallTypeEnum);
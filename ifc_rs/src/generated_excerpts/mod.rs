@@ -0,0 +1,49 @@
+//! Modules generated by `ifc_rs_codegen` from the schema excerpts of its tests. They are only
+//! compiled with the tests of this crate, which makes sure that the generated code fits it.
+//!
+//! Don't edit them, run the tests of `ifc_rs_codegen` with `UPDATE_GENERATED=1` instead.
+
+mod ifc2x3;
+mod ifc4;
+mod ifc4x3;
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{ifc2x3, ifc4, ifc4x3};
+    use crate::parser::IFCParse;
+    use crate::prelude::*;
+
+    fn round_trip<T: IFCParse + IfcType>(text: &str) {
+        let entity = T::parse().parse(text).unwrap();
+
+        assert_eq!(entity.to_string(), text);
+        assert_eq!(entity.attribute_values().len(), T::attribute_types().len());
+    }
+
+    #[test]
+    fn generated_entities_round_trip() {
+        round_trip::<ifc2x3::Wall>("IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,'Wall',$,$,#33,#25,$);");
+        round_trip::<ifc2x3::PolyLoop>("IFCPOLYLOOP((#1,#2,#3));");
+        round_trip::<ifc2x3::MaterialLayer>("IFCMATERIALLAYER(#5,0.2,$);");
+
+        round_trip::<ifc4::Wall>(
+            "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,'Wall',$,$,#33,#25,$,.SOLIDWALL.);",
+        );
+        round_trip::<ifc4::SIUnit>("IFCSIUNIT(*,.LENGTHUNIT.,.MILLI.,.METRE.);");
+        round_trip::<ifc4::PropertySingleValue>(
+            "IFCPROPERTYSINGLEVALUE('IsExternal',$,IFCBOOLEAN(.T.),$);",
+        );
+
+        round_trip::<ifc4x3::Wall>(
+            "IFCWALL('0DWgwt6o1FOx7466fPk$jl',$,$,$,$,#33,#25,$,.WAVEWALL.);",
+        );
+        round_trip::<ifc4x3::CartesianPointList2D>(
+            "IFCCARTESIANPOINTLIST2D(((0.,0.),(1.,0.5)),('A','B'));",
+        );
+        round_trip::<ifc4x3::IndexedPolyCurve>(
+            "IFCINDEXEDPOLYCURVE(#7,(IFCLINEINDEX((1,2)),IFCARCINDEX((2,3,4))),$);",
+        );
+    }
+}
@@ -28,6 +28,7 @@ pub struct MappedArbitraryClosedProfileDef {
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifcarbitraryclosedprofiledef.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcArbitraryClosedProfileDef")]
 pub struct ArbitraryClosedProfileDef {
     /// Defines the type of geometry into which this profile definition shall be resolved, either a
     /// curve or a surface area. In case of curve the profile should be referenced by a swept
@@ -34,12 +34,14 @@ pub struct MappedAxis2D<'a> {
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcaxis2placement2d.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcAxis2Placement2D")]
 pub struct Axis2D {
     /// The geometric position of a reference point, such as the center of a circle, of the item to
     /// be located.
     pub location: TypedId<Point2D>,
     /// The direction used to determine the direction of the local X axis. If a value is omited
     /// that it defaults to [1.0, 0.0].
+    #[ifc(name = "RefDirection")]
     pub local_x: OptionalParameter<TypedId<Direction2D>>,
 }
 
@@ -84,15 +86,18 @@ pub struct MappedAxis3D<'a> {
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcaxis2placement3d.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcAxis2Placement3D")]
 pub struct Axis3D {
     /// The geometric position of a reference point, such as the center of a circle, of the item to
     /// be located.
     pub location: TypedId<Point3D>,
     /// The exact direction of the local Z Axis. If a value is omited that it defaults to [0.0, 0.0, 0.1]
+    #[ifc(name = "Axis")]
     pub local_z: OptionalParameter<TypedId<Direction3D>>,
     /// The direction used to determine the direction of the local X Axis. If necessary an
     /// adjustment is made to maintain orthogonality to the Axis direction. If Axis and/or
     /// RefDirection is omitted, these directions are taken from the geometric coordinate system.
+    #[ifc(name = "RefDirection")]
     pub local_x: OptionalParameter<TypedId<Direction3D>>,
 }
 
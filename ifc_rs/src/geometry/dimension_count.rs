@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use anyhow::{anyhow, bail, Result};
 use optional::OptionalParameter;
 use place_holder::Inherited;
 use strum::{Display, EnumString, VariantNames};
@@ -7,7 +8,10 @@ use winnow::ascii::dec_int;
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
 
-use crate::parser::*;
+use crate::{
+    parser::*,
+    traits::reflect::{AttributeKind, StepAttribute},
+};
 
 /// The IfcDimensionCount is restricted to have the dimensionality of either 1, 2, or 3
 /// - the WR1 had been added as an addition to the STEP P42 entity dimension_count.
@@ -53,6 +57,22 @@ impl IFCParse for DimensionCount {
     }
 }
 
+impl StepAttribute for DimensionCount {
+    const KIND: AttributeKind = AttributeKind::Integer;
+
+    fn to_step_value(&self) -> StepValue {
+        StepValue::Integer(*self as i64 + 1)
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        match value {
+            StepValue::Integer(count) => Self::from_str(&count.to_string())
+                .map_err(|_| anyhow!("dimension count must be 1, 2 or 3, found {count}")),
+            value => bail!("expected integer, found `{value}`"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use winnow::Parser;
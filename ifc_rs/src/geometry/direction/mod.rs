@@ -17,7 +17,8 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcdirection.htm
 #[derive(Debug, Clone, Copy, PartialEq, IfcVerify)]
-pub struct Direction2D(pub(crate) IfcDVec2);
+#[ifc(name = "IfcDirection")]
+pub struct Direction2D(#[ifc(name = "DirectionRatios")] pub(crate) IfcDVec2);
 
 impl From<DVec2> for Direction2D {
     fn from(value: DVec2) -> Self {
@@ -36,7 +37,8 @@ impl IfcType for Direction2D {}
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcdirection.htm
 #[derive(Debug, Clone, Copy, PartialEq, IfcVerify)]
-pub struct Direction3D(pub(crate) IfcDVec3);
+#[ifc(name = "IfcDirection")]
+pub struct Direction3D(#[ifc(name = "DirectionRatios")] pub(crate) IfcDVec3);
 
 impl From<DVec3> for Direction3D {
     fn from(value: DVec3) -> Self {
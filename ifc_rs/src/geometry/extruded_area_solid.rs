@@ -29,6 +29,7 @@ pub struct MappedExtrudedAreaSolid<'a> {
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcextrudedareasolid.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcExtrudedAreaSolid")]
 pub struct ExtrudedAreaSolid {
     /// The surface defining the area to be swept. It is given as a
     /// profile definition within the xy plane of the position coordinate system.
@@ -80,3 +80,5 @@ impl IFCParse for GeometricProjection {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(GeometricProjection);
@@ -16,6 +16,7 @@ mod serialize;
 /// IfcCartesianPointList are connected by straight line segments in the
 /// order they appear in the IfcCartesianPointList.
 #[derive(IfcVerify)]
+#[ifc(name = "IfcIndexedPolyCurve")]
 pub struct IndexedPolyCurve {
     /// A list of points, provided by a point list of either two, or three
     /// dimensions, that is used to define the poly curve. If the attribute
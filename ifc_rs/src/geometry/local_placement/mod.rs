@@ -17,6 +17,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifclocalplacement.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcLocalPlacement")]
 pub struct LocalPlacement {
     /// Reference to object placement that provides the relative placement with
     /// its placement in a grid, local coordinate system or linear referenced placement.
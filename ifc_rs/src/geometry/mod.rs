@@ -1,9 +1,4 @@
-use winnow::combinator::alt;
-
-use crate::{
-    parser::{IFCParse, IFCParser},
-    prelude::*,
-};
+use crate::parser::EntityRegistry;
 
 pub(crate) mod arbitrary_closed_profile_def;
 pub(crate) mod axis;
@@ -30,28 +25,39 @@ pub(crate) mod uniform_transformations;
 pub struct Geometry;
 
 impl Geometry {
-    pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
-        alt((
-            arbitrary_closed_profile_def::ArbitraryClosedProfileDef::parse_any(),
-            axis::Axis2D::parse_any(),
-            axis::Axis3D::parse_any(),
-            direction::Direction2D::parse_any(),
-            direction::Direction3D::parse_any(),
-            extruded_area_solid::ExtrudedAreaSolid::parse_any(),
-            indexed_poly_curve::IndexedPolyCurve::parse_any(),
-            point::Point2D::parse_any(),
-            point::Point3D::parse_any(),
-            point_list::PointList2D::parse_any(),
-            point_list::PointList3D::parse_any(),
-            polyline::PolyLine::parse_any(),
-            product_definition_shape::ProductDefinitionShape::parse_any(),
-            rectangle_profile_def::RectangleProfileDef::parse_any(),
-            representation_context::GeometricRepresentationContext::parse_any(),
-            representation_subcontext::GeometricRepresentationSubContext::parse_any(),
-            shape_representation::ShapeRepresentation::parse_any(),
-            local_placement::LocalPlacement::parse_any(),
-            non_uniform_transformations::CartesianTransformationOperator3DnonUniform::parse_any(),
-            uniform_transformations::CartesianTransformationOperator3D::parse_any(),
-        ))
+    pub(crate) fn register(registry: &mut EntityRegistry) {
+        registry.register::<arbitrary_closed_profile_def::ArbitraryClosedProfileDef>(
+            "IFCARBITRARYCLOSEDPROFILEDEF",
+        );
+        registry.register::<axis::Axis2D>("IFCAXIS2PLACEMENT2D");
+        registry.register::<axis::Axis3D>("IFCAXIS2PLACEMENT3D");
+        registry.register::<direction::Direction2D>("IFCDIRECTION");
+        registry.register::<direction::Direction3D>("IFCDIRECTION");
+        registry.register::<extruded_area_solid::ExtrudedAreaSolid>("IFCEXTRUDEDAREASOLID");
+        registry.register::<indexed_poly_curve::IndexedPolyCurve>("IFCINDEXEDPOLYCURVE");
+        registry.register::<point::Point2D>("IFCCARTESIANPOINT");
+        registry.register::<point::Point3D>("IFCCARTESIANPOINT");
+        registry.register::<point_list::PointList2D>("IFCCARTESIANPOINTLIST2D");
+        registry.register::<point_list::PointList3D>("IFCCARTESIANPOINTLIST3D");
+        registry.register::<polyline::PolyLine>("IFCPOLYLINE");
+        registry.register::<product_definition_shape::ProductDefinitionShape>(
+            "IFCPRODUCTDEFINITIONSHAPE",
+        );
+        registry.register::<rectangle_profile_def::RectangleProfileDef>("IFCRECTANGLEPROFILEDEF");
+        registry.register::<representation_context::GeometricRepresentationContext>(
+            "IFCGEOMETRICREPRESENTATIONCONTEXT",
+        );
+        registry.register::<representation_subcontext::GeometricRepresentationSubContext>(
+            "IFCGEOMETRICREPRESENTATIONSUBCONTEXT",
+        );
+        registry.register::<shape_representation::ShapeRepresentation>("IFCSHAPEREPRESENTATION");
+        registry.register::<local_placement::LocalPlacement>("IFCLOCALPLACEMENT");
+        registry
+            .register::<non_uniform_transformations::CartesianTransformationOperator3DnonUniform>(
+                "IFCCARTESIANTRANSFORMATIONOPERATOR3DNONUNIFORM",
+            );
+        registry.register::<uniform_transformations::CartesianTransformationOperator3D>(
+            "IFCCARTESIANTRANSFORMATIONOPERATOR3D",
+        );
     }
 }
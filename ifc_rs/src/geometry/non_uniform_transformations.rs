@@ -40,14 +40,17 @@ impl<'a> Deref for NonUniformTransformMapping<'a> {
 /// to 1.0. If the Scale2 or the Scale3 factor is omitted, it defaults to the value of Scale (the x
 /// axis scale factor).
 #[derive(IfcVerify, Debug)]
+#[ifc(name = "IfcCartesianTransformationOperator3DnonUniform")]
 pub struct CartesianTransformationOperator3DnonUniform {
     #[inherited]
     base: Transform3DBase,
     /// The scaling value specified for the transformation along the axis 2. This is normally the y
     /// scale factor.
+    #[ifc(name = "Scale2")]
     pub scale_y: OptionalParameter<RealPrimitive>,
     /// The scaling value specified for the transformation along the axis 3. This is normally the z
     /// scale factor.
+    #[ifc(name = "Scale3")]
     pub scale_z: OptionalParameter<RealPrimitive>,
 }
 
@@ -21,7 +21,8 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccartesianpoint.htm
 #[derive(Debug, Clone, Copy, PartialEq, IfcVerify)]
-pub struct Point2D(pub(crate) IfcDVec2);
+#[ifc(name = "IfcCartesianPoint")]
+pub struct Point2D(#[ifc(name = "Coordinates")] pub(crate) IfcDVec2);
 
 impl Deref for Point2D {
     type Target = IfcDVec2;
@@ -56,7 +57,8 @@ impl CartesianPoint for Point2D {}
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccartesianpoint.htm
 #[derive(Debug, Clone, Copy, PartialEq, IfcVerify)]
-pub struct Point3D(pub(crate) IfcDVec3);
+#[ifc(name = "IfcCartesianPoint")]
+pub struct Point3D(#[ifc(name = "Coordinates")] pub(crate) IfcDVec3);
 
 impl Deref for Point3D {
     type Target = IfcDVec3;
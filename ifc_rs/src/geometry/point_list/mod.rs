@@ -24,6 +24,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcgeometricmodelresource/lexical/ifccartesianpointlist2d.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcCartesianPointList2D")]
 pub struct PointList2D {
     pub coord_list: IfcList<IfcDVec2>,
     pub tag_list: OptionalParameter<IfcList<StringPrimitive>>,
@@ -52,6 +53,7 @@ impl PointList for PointList2D {}
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcgeometricmodelresource/lexical/ifccartesianpointlist3d.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcCartesianPointList3D")]
 pub struct PointList3D {
     pub coord_list: IfcList<IfcDVec3>,
     pub tag_list: OptionalParameter<IfcList<StringPrimitive>>,
@@ -11,6 +11,7 @@ mod serialize;
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcpolyline.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcPolyline")]
 pub struct PolyLine {
     /// The points defining the polyline.
     #[ifc_types(Point2D, Point3D)]
@@ -27,6 +27,7 @@ use crate::{
 ///   representations) that may include geometric representation items (vertex points, edge curves,
 ///   face surfaces)
 #[derive(IfcVerify)]
+#[ifc(name = "IfcProductDefinitionShape")]
 pub struct ProductDefinitionShape {
     // from IfcProductRepresentation https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcproductrepresentation.htm
     //
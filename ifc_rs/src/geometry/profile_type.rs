@@ -33,3 +33,5 @@ impl IFCParse for ProfileType {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(ProfileType);
@@ -25,6 +25,7 @@ pub struct MappedRectangleProfileDef<'a> {
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcprofileresource/lexical/ifcrectangleprofiledef.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcRectangleProfileDef")]
 pub struct RectangleProfileDef {
     /// Defines the type of geometry into which this profile definition shall be resolved, either a
     /// curve or a surface area. In case of curve the profile should be referenced by a swept
@@ -19,6 +19,7 @@ use crate::prelude::*;
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcrepresentationresource/lexical/ifcgeometricrepresentationcontext.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcGeometricRepresentationContext")]
 pub struct GeometricRepresentationContext {
     // NOTE: from IfcRepresentationContext
     //
@@ -29,6 +30,7 @@ pub struct GeometricRepresentationContext {
     pub context_type: OptionalParameter<StringPrimitive>,
 
     /// The integer dimension count of the coordinate space modeled in a geometric representation context.
+    #[ifc(name = "CoordinateSpaceDimension")]
     pub coord_space_dimension: DimensionCount,
     /// Value of the model precision for geometric models. It is a double value (REAL), typically in 1E-5 to 1E-8 range,
     /// that indicates the tolerance under which two given points are still assumed to be identical.
@@ -37,6 +39,7 @@ pub struct GeometricRepresentationContext {
     /// Establishment of the engineering coordinate system (often referred to as the world coordinate
     /// system in CAD) for all representation contexts used by the project.
     #[ifc_types(Axis2D, Axis3D)]
+    #[ifc(name = "WorldCoordinateSystem")]
     pub world_coord_system: Id,
 
     /// Direction of the true north, or geographic northing direction, relative to the underlying project coordinate system.
@@ -33,6 +33,13 @@ use super::representation_context::GeometricRepresentationContext;
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcrepresentationresource/lexical/ifcgeometricrepresentationsubcontext.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(
+    name = "IfcGeometricRepresentationSubContext",
+    derived = "CoordinateSpaceDimension",
+    derived = "Precision",
+    derived = "WorldCoordinateSystem",
+    derived = "TrueNorth"
+)]
 pub struct GeometricRepresentationSubContext {
     // first six fields inherited from IfcGeometricRepresentationContext
     //
@@ -46,6 +53,7 @@ pub struct GeometricRepresentationSubContext {
     pub context_type: OptionalParameter<StringPrimitive>,
     /// The integer dimension count of the coordinate space modeled in a geometric representation
     /// context.
+    #[ifc(name = "CoordinateSpaceDimension")]
     pub coord_space_dimension: OptionalParameter<DimensionCount>,
     /// Value of the model precision for geometric models. It is a double value (REAL), typically
     /// in 1E-5 to 1E-8 range, that indicates the tolerance under which two given points are still
@@ -66,6 +74,7 @@ pub struct GeometricRepresentationSubContext {
     /// base point" and "survey point") should be coincidental. However it is possible to offset
     /// the geographic reference point from the local zero point.
     #[ifc_types(Axis2D, Axis3D)]
+    #[ifc(name = "WorldCoordinateSystem")]
     pub world_coord_system: OptionalParameter<Id>,
     /// Direction of the true north, or geographic northing direction, relative to the underlying
     /// project coordinate system. It is given by a 2 dimensional direction within the xy-plane of
@@ -20,6 +20,7 @@ pub enum ShapeItemEnum<'a> {
     MappedItem(&'a MappedItem),
     ExtrudedAreaSolid(&'a ExtrudedAreaSolid),
     Dummy(&'a Dummy),
+    Unknown(&'a UnknownEntity),
     Other(&'a dyn IfcType),
 }
 
@@ -29,6 +30,7 @@ impl<'a> Display for ShapeItemEnum<'a> {
             ShapeItemEnum::MappedItem(mapped_item) => write!(f, "{mapped_item}"),
             ShapeItemEnum::ExtrudedAreaSolid(solid) => write!(f, "{solid}"),
             ShapeItemEnum::Dummy(dummy) => write!(f, "{dummy}"),
+            ShapeItemEnum::Unknown(unknown) => write!(f, "{unknown}"),
             ShapeItemEnum::Other(ifc_type) => write!(f, "{ifc_type}"),
         }
     }
@@ -47,6 +49,7 @@ impl<'a> Display for ShapeItemEnum<'a> {
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcshaperepresentation.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcShapeRepresentation")]
 pub struct ShapeRepresentation {
     // All fields from IfcRepresentation https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrepresentation.htm
     //
@@ -97,6 +100,8 @@ impl ShapeRepresentation {
                 ShapeItemEnum::ExtrudedAreaSolid(extruded_area_solid)
             } else if let Some(dummy) = item.downcast_ref::<Dummy>() {
                 ShapeItemEnum::Dummy(dummy)
+            } else if let Some(unknown) = item.downcast_ref::<UnknownEntity>() {
+                ShapeItemEnum::Unknown(unknown)
             } else {
                 ShapeItemEnum::Other(item)
             }
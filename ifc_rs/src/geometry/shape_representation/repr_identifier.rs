@@ -62,3 +62,5 @@ impl IFCParse for RepresentationIdentifier {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(RepresentationIdentifier, String);
@@ -109,3 +109,5 @@ impl IFCParse for RepresentationType {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(RepresentationType, String);
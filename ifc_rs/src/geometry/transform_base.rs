@@ -22,8 +22,10 @@ pub struct TransformBaseMapping<'a> {
 #[derive(IfcVerify, Debug)]
 pub struct Transform3DBase {
     /// The direction used to determine U[1], the derived X axis direction.
+    #[ifc(name = "Axis1")]
     pub axis_x: OptionalParameter<IdOr<Direction3D>>,
     /// The direction used to determine U[2], the derived Y axis direction.
+    #[ifc(name = "Axis2")]
     pub axis_y: OptionalParameter<IdOr<Direction3D>>,
     /// The required translation, specified as a cartesian point. The actual translation included
     /// in the transformation is from the geometric origin to the local origin.
@@ -31,6 +33,7 @@ pub struct Transform3DBase {
     /// The scaling value specified for the transformation.
     pub scale: OptionalParameter<RealPrimitive>,
     /// The exact direction of U[3], the derived Z axis direction.
+    #[ifc(name = "Axis3")]
     pub axis_z: OptionalParameter<IdOr<Direction3D>>,
 }
 
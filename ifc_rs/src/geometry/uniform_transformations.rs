@@ -27,6 +27,7 @@ impl<'a> Deref for TransformMapping<'a> {
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccartesiantransformationoperator3d.htm
 #[derive(IfcVerify, Debug)]
+#[ifc(name = "IfcCartesianTransformationOperator3D")]
 pub struct CartesianTransformationOperator3D {
     #[inherited]
     base: Transform3DBase,
@@ -1,9 +1,12 @@
 use std::fmt::Display;
+
+use anyhow::{anyhow, bail, Result};
 use winnow::{combinator::alt, Parser};
 
 use crate::{
     parser::{IFCParse, IFCParser},
     prelude::*,
+    traits::reflect::{AttributeKind, StepAttribute},
 };
 
 use super::{Id, TypedId};
@@ -24,6 +27,13 @@ impl<T: IfcType> IdOr<T> {
         }
     }
 
+    pub fn id_mut(&mut self) -> Option<&mut TypedId<T>> {
+        match self {
+            Self::Id(id) => Some(id),
+            Self::Custom(_) => None,
+        }
+    }
+
     pub fn custom(&self) -> Option<&T> {
         match self {
             Self::Id(_) => None,
@@ -69,6 +79,43 @@ impl<T: IFCParse + IfcType> IFCParse for IdOr<T> {
     }
 }
 
+/// Values which aren't referenced are written inline, e.g. `IFCPLANEANGLEMEASURE(0.5)`.
+impl<T: IFCParse + IfcType> StepAttribute for IdOr<T> {
+    const KIND: AttributeKind = AttributeKind::Reference;
+
+    fn to_step_value(&self) -> StepValue {
+        match self {
+            IdOr::Id(id) => id.to_step_value(),
+            IdOr::Custom(t) => {
+                let mut values = t.attribute_values();
+                let value = match values.len() {
+                    1 => values.remove(0),
+                    _ => StepValue::List(values),
+                };
+
+                StepValue::Typed(t.keyword().to_owned(), Box::new(value))
+            }
+        }
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        match value {
+            StepValue::Id(id) => Ok(Self::Id(TypedId::new(*id))),
+            StepValue::Typed(..) => {
+                let text = value.to_string();
+
+                let parsed = T::parse()
+                    .parse(&text)
+                    .map(Self::Custom)
+                    .map_err(|err| anyhow!("invalid value `{text}`: {err}"));
+
+                parsed
+            }
+            value => bail!("expected entity reference, found `{value}`"),
+        }
+    }
+}
+
 impl<T: Display + IfcType> Display for IdOr<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1,6 +1,10 @@
+use anyhow::{anyhow, Result};
 use winnow::{token::take_while, Parser};
 
-use crate::parser::*;
+use crate::{
+    parser::*,
+    traits::reflect::{AttributeKind, StepAttribute},
+};
 
 const ALPHABET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz_$";
 const BASE64_MASK: u8 = 0b0011_1111;
@@ -29,7 +33,7 @@ pub struct IfcGloballyUniqueId(uuid::Uuid);
 
 impl IfcGloballyUniqueId {
     /// translates a given [`uuid::Uuid`] into a [`IfcGloballyUniqueId`]
-    fn from_uuid(uuid: uuid::Uuid) -> Self {
+    pub fn from_uuid(uuid: uuid::Uuid) -> Self {
         Self(uuid)
     }
 
@@ -62,6 +66,11 @@ impl IfcGloballyUniqueId {
         Self::from_base16_bytes(decode_base16_to_base64(base64_bytes))
     }
 
+    /// the uuid which is encoded by this id
+    pub fn uuid(&self) -> uuid::Uuid {
+        self.0
+    }
+
     /// returns the shortened base64 version of the uuid string
     pub fn base64_ifc_string(&self) -> String {
         encode_base16_to_base64(self.0.into_bytes())
@@ -97,6 +106,24 @@ impl std::fmt::Display for IfcGloballyUniqueId {
     }
 }
 
+impl StepAttribute for IfcGloballyUniqueId {
+    const KIND: AttributeKind = AttributeKind::String;
+
+    fn to_step_value(&self) -> StepValue {
+        StepValue::String(self.base64_ifc_string())
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        let text = value.to_string();
+
+        let parsed = Self::parse()
+            .parse(&text)
+            .map_err(|err| anyhow!("invalid GlobalId `{text}`: {err}"));
+
+        parsed
+    }
+}
+
 fn decode_base16_to_base64(base64_bytes: impl IntoIterator<Item = u8>) -> Vec<u8> {
     let mut bytes_iter = base64_bytes.into_iter();
 
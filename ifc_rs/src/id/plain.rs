@@ -1,10 +1,12 @@
 use std::fmt::Display;
 
+use anyhow::{bail, Result};
 use winnow::{ascii::dec_uint, combinator::preceded, Parser};
 
 use crate::{
     parser::{IFCParse, IFCParser},
     prelude::*,
+    traits::reflect::{AttributeKind, StepAttribute},
 };
 
 use super::TypedId;
@@ -33,3 +35,18 @@ impl<T: IfcType> From<TypedId<T>> for Id {
         value.id()
     }
 }
+
+impl StepAttribute for Id {
+    const KIND: AttributeKind = AttributeKind::Reference;
+
+    fn to_step_value(&self) -> StepValue {
+        StepValue::Id(*self)
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        match value {
+            StepValue::Id(id) => Ok(*id),
+            value => bail!("expected entity reference, found `{value}`"),
+        }
+    }
+}
@@ -3,9 +3,14 @@ use std::{
     marker::PhantomData,
 };
 
+use anyhow::Result;
 use winnow::Parser;
 
-use crate::{parser::IFCParse, prelude::*};
+use crate::{
+    parser::IFCParse,
+    prelude::*,
+    traits::reflect::{AttributeKind, StepAttribute},
+};
 
 use super::{Id, IdOr};
 
@@ -52,6 +57,10 @@ impl<T: IfcType> TypedId<T> {
         self.id
     }
 
+    pub fn id_mut(&mut self) -> &mut Id {
+        &mut self.id
+    }
+
     pub fn id_or(&self) -> IdOr<T> {
         IdOr::Id(*self)
     }
@@ -78,6 +87,18 @@ impl<T: IfcType> Display for TypedId<T> {
     }
 }
 
+impl<T: IfcType> StepAttribute for TypedId<T> {
+    const KIND: AttributeKind = AttributeKind::Reference;
+
+    fn to_step_value(&self) -> StepValue {
+        StepValue::Id(self.id)
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        Id::from_step_value(value).map(Self::new)
+    }
+}
+
 pub struct FixMeType;
 impl IfcVerify for FixMeType {}
 impl IfcType for FixMeType {}
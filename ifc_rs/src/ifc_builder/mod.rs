@@ -35,6 +35,8 @@ pub struct OwnerInfo<'a> {
 
 #[cfg(test)]
 pub(crate) mod test {
+    use std::str::FromStr;
+
     use crate::prelude::*;
 
     pub fn create_builder() -> IfcProjectBuilder {
@@ -52,4 +54,14 @@ pub(crate) mod test {
             "IfcBuider Example Project",
         )
     }
+
+    #[test]
+    fn build_into_writes_valid_file() {
+        let mut written = Vec::new();
+        create_builder().build_into(&mut written).unwrap();
+
+        let written = String::from_utf8(written).unwrap();
+        let ifc = IFC::from_str(&written).unwrap();
+        assert_eq!(written, ifc.to_string());
+    }
 }
@@ -1,4 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, Write},
+};
 
 use bevy_math::DVec3;
 
@@ -127,8 +130,20 @@ impl IfcProjectBuilder {
         IfcSiteBuilder::new(self, site_id, self.owner_history)
     }
 
+    /// finishes the project and serializes it, see [`IfcProjectBuilder::build_ifc`]
     #[must_use]
-    pub fn build(mut self) -> String {
+    pub fn build(self) -> String {
+        self.build_ifc().to_string()
+    }
+
+    /// finishes the project and writes it to the given writer, see [`IFC::write_to`]
+    pub fn build_into(self, writer: &mut impl Write) -> io::Result<()> {
+        self.build_ifc().write_to(writer)
+    }
+
+    /// finishes the project by adding the relations which were collected while building
+    #[must_use]
+    pub fn build_ifc(mut self) -> IFC {
         self.material_to_wall
             .into_values()
             .chain(self.material_to_slab.into_values())
@@ -151,6 +166,6 @@ impl IfcProjectBuilder {
         );
         self.ifc.data.insert_new(rel_agg);
 
-        self.ifc.to_string()
+        self.ifc
     }
 }
@@ -112,7 +112,7 @@ pub(crate) trait IfcBuilderTransform {
                         .representation_identifier
                         .custom()
                         .copied()
-                        .unwrap_or_else(|| RepresentationIdentifier::Body);
+                        .unwrap_or(RepresentationIdentifier::Body);
 
                     let r = ShapeRepresentation::new(
                         self.sub_context(),
@@ -27,8 +27,7 @@ impl IfcExtractor {
     {
         self.ifc
             .data
-            .find_all_of_type::<RelAggregates>()
-            .filter(move |&(_, rel_aggregate)| rel_aggregate.relating_object == id.id())
+            .is_decomposed_by(id)
             .flat_map(|(_, rel_aggregate)| {
                 rel_aggregate.related_objects.0.iter().filter_map(|id| {
                     self.ifc
@@ -46,8 +45,7 @@ impl IfcExtractor {
     {
         self.ifc
             .data
-            .find_all_of_type::<RelContainedInSpatialStructure>()
-            .filter(move |&(_, rel_structure)| rel_structure.relating_structure == id.id())
+            .contains_elements(id)
             .flat_map(|(_, rel_structure)| rel_structure.related_elements.0.clone())
     }
 
@@ -57,14 +55,8 @@ impl IfcExtractor {
     {
         self.ifc
             .data
-            .find_all_of_type::<RelDefinesByType>()
-            .find(|(_, rel_types)| {
-                rel_types
-                    .related_objects
-                    .0
-                    .iter()
-                    .any(|rel_id| *rel_id == id.id())
-            })
+            .is_typed_by(id)
+            .next()
             .map(|(_, rel_types)| self.ifc.data.get_untyped(rel_types.relating_type))
             .unwrap()
     }
@@ -75,8 +67,7 @@ impl IfcExtractor {
     {
         self.ifc
             .data
-            .find_all_of_type::<RelVoidsElement>()
-            .filter(move |&(_, rel_voids)| (rel_voids.relating_building_element == id.id()))
+            .has_openings(id)
             .map(|(_, rel_voids)| self.ifc.data.get(rel_voids.related_opening_element))
     }
 
@@ -86,14 +77,7 @@ impl IfcExtractor {
     {
         self.ifc
             .data
-            .find_all_of_type::<RelAssociatesMaterial>()
-            .filter(move |(_, rel_material)| {
-                rel_material
-                    .related_objects
-                    .0
-                    .iter()
-                    .any(|obj_id| *obj_id == id.id())
-            })
+            .has_associations(id)
             .filter_map(|(_, rel_material)| {
                 self.ifc
                     .data
@@ -120,14 +104,7 @@ impl IfcExtractor {
     {
         self.ifc
             .data
-            .find_all_of_type::<RelAssociatesMaterial>()
-            .filter(move |(_, rel_material)| {
-                rel_material
-                    .related_objects
-                    .0
-                    .iter()
-                    .any(|obj_id| *obj_id == id.id())
-            })
+            .has_associations(id)
             .filter_map(|(_, rel_material)| {
                 self.ifc
                     .data
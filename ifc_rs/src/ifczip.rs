@@ -0,0 +1,133 @@
+use std::io::{self, BufRead, BufReader, Read, Seek, Write};
+
+use anyhow::{bail, Context, Result};
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::prelude::*;
+
+/// signature of the local file header at the start of every zip archive
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+
+/// Checks whether the reader starts with a zip archive, without consuming anything.
+pub fn is_ifczip(reader: &mut impl BufRead) -> io::Result<bool> {
+    Ok(reader.fill_buf()?.starts_with(ZIP_SIGNATURE))
+}
+
+/// Opens the IFC file inside of an ifcZIP archive and passes it to `f`, e.g. to read it with
+/// [`IfcReader`](crate::reader::IfcReader). The file is decompressed while it is read, so it
+/// never has to fit into memory.
+///
+/// The archive has to contain a single `.ifc` file, other files are ignored.
+pub fn read_ifczip<T>(
+    reader: impl Read + Seek,
+    f: impl FnOnce(&mut dyn BufRead) -> Result<T>,
+) -> Result<T> {
+    let mut archive = ZipArchive::new(reader).context("invalid ifcZIP archive")?;
+
+    let ifc_files = archive
+        .file_names()
+        .filter(|name| name.to_lowercase().ends_with(".ifc"))
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+
+    let name = match ifc_files.as_slice() {
+        [name] => name,
+        [] => bail!("ifcZIP archive doesn't contain an .ifc file"),
+        names => bail!("ifcZIP archive contains multiple .ifc files: {names:?}"),
+    };
+
+    let mut file = BufReader::new(archive.by_name(name)?);
+    f(&mut file)
+}
+
+/// Writes the model as ifcZIP archive containing a single deflate compressed file with the given
+/// name. The model is compressed while it is written, see [`IFC::write_to`].
+pub fn write_ifczip(ifc: &IFC, writer: impl Write + Seek, name: &str) -> io::Result<()> {
+    let mut zip = ZipWriter::new(writer);
+
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .large_file(true);
+    zip.start_file(name, options)?;
+
+    ifc.write_to(&mut zip)?;
+    zip.finish()?.flush()
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufReader, Cursor, Write};
+
+    use zip::{write::SimpleFileOptions, ZipWriter};
+
+    use super::{is_ifczip, read_ifczip, write_ifczip};
+    use crate::{prelude::*, reader::IfcReader};
+
+    #[test]
+    fn round_trips_models() {
+        let ifc = IFC::from_file("resources/wall-standard-case.ifc").unwrap();
+
+        let mut archive = Cursor::new(Vec::new());
+        write_ifczip(&ifc, &mut archive, "wall-standard-case.ifc").unwrap();
+
+        let archive = archive.into_inner();
+        assert!(archive.len() < ifc.to_string().len());
+        assert!(is_ifczip(&mut BufReader::new(archive.as_slice())).unwrap());
+
+        let read = read_ifczip(Cursor::new(archive), |reader| IFC::from_reader(reader)).unwrap();
+        assert_eq!(read.to_string(), ifc.to_string());
+    }
+
+    #[test]
+    fn streams_entities() {
+        let mut archive = Cursor::new(Vec::new());
+        let ifc = IFC::from_file("resources/wall-standard-case.ifc").unwrap();
+        write_ifczip(&ifc, &mut archive, "wall.ifc").unwrap();
+
+        let count = read_ifczip(archive, |reader| {
+            Ok(IfcReader::new(reader)?.filter_map(Result::ok).count())
+        })
+        .unwrap();
+
+        assert_eq!(count, ifc.data.len());
+    }
+
+    #[test]
+    fn finds_ifc_file_in_archive() {
+        let mut archive = ZipWriter::new(Cursor::new(Vec::new()));
+        archive
+            .start_file("readme.txt", SimpleFileOptions::default())
+            .unwrap();
+        archive.write_all(b"not a model").unwrap();
+        archive
+            .start_file("model/wall.IFC", SimpleFileOptions::default())
+            .unwrap();
+        archive
+            .write_all(include_bytes!("../resources/wall-standard-case.ifc"))
+            .unwrap();
+        let archive = archive.finish().unwrap();
+
+        let ifc = read_ifczip(archive, |reader| IFC::from_reader(reader)).unwrap();
+        assert!(!ifc.data.is_empty());
+
+        let mut empty = ZipWriter::new(Cursor::new(Vec::new()));
+        empty
+            .start_file("readme.txt", SimpleFileOptions::default())
+            .unwrap();
+        let empty = empty.finish().unwrap();
+
+        let Err(err) = read_ifczip(empty, |reader| IFC::from_reader(reader)) else {
+            panic!("archive without model was read");
+        };
+        assert_eq!(
+            err.to_string(),
+            "ifcZIP archive doesn't contain an .ifc file"
+        );
+    }
+
+    #[test]
+    fn plain_files_are_no_archives() {
+        let file = include_bytes!("../resources/wall-standard-case.ifc");
+        assert!(!is_ifczip(&mut BufReader::new(file.as_slice())).unwrap());
+    }
+}
@@ -0,0 +1,800 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    io::Write,
+    str::FromStr,
+    sync::Arc,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::{json, Map, Number, Value};
+use winnow::Parser;
+
+use crate::{
+    id::Id,
+    meta::{
+        datamap::DataMap,
+        header::{
+            schema::{FileSchema, FileSchemas},
+            Header,
+        },
+    },
+    parser::{
+        defined_types::{defined_type_kind, defined_type_name},
+        export::ExportedEntities,
+        real::format_real_primitive,
+        string::{decode, encode},
+        unknown_entity::UnknownEntity,
+        EntityRegistry, IFCParse,
+    },
+    prelude::*,
+    traits::reflect::{AttributeKind, EntityType},
+};
+
+/// version of the IFC-JSON format which is written
+const IFC_JSON_VERSION: &str = "0.0.1";
+
+/// `GlobalId` -> `globalId`
+fn lower_camel_case(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+/// name of the attributes of entities which aren't modelled by this crate
+fn positional_attribute(index: usize) -> String {
+    format!("attribute{}", index + 1)
+}
+
+/// Exports the model to the buildingSMART IFC-JSON format.
+///
+/// Entities with a `GlobalId` are written at the top level and entities which are only
+/// referenced once are nested into the entity referencing them. All other entities are written
+/// at the top level as well and get a `globalId` which is derived from their STEP id, so they can
+/// be referenced with `{"type": ..., "ref": ...}` objects.
+///
+/// Attribute names are taken from the schema definitions of the entities this crate models, other
+/// entities use positional names like `attribute1`. The whole header is kept in a `header`
+/// member, so [`from_value`] restores it exactly.
+///
+/// [`Dummy`] entities are written as described at [`to_value_with_dummies`].
+pub fn to_value(ifc: &IFC) -> Result<Value> {
+    to_value_with_dummies(ifc).map(|(value, _)| value)
+}
+
+/// Exports the model like [`to_value`] and also returns the ids of the [`Dummy`] entities, whose
+/// attributes couldn't be exported by their schema definition. They are written with positional
+/// attributes or, if even their attributes are malformed, as STEP text in a `raw` member with the
+/// entities it references in `references`.
+pub fn to_value_with_dummies(ifc: &IFC) -> Result<(Value, Vec<Id>)> {
+    let exporter = Exporter::new(ifc)?;
+    let dummies = exporter.dummies.clone();
+
+    let schema = ifc
+        .header
+        .schema
+        .0
+        .first()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+
+    let value = json!({
+        "type": "ifcJSON",
+        "version": IFC_JSON_VERSION,
+        "schemaIdentifier": schema,
+        "originatingSystem": decode_lossy(&ifc.header.name.originating_system.0),
+        "preprocessorVersion": decode_lossy(&ifc.header.name.preprocessor_version.0),
+        "timeStamp": ifc.header.name.timestamp.0.to_rfc3339(),
+        "header": serde_json::to_value(&ifc.header)?,
+        "data": exporter.export()?,
+    });
+
+    Ok((value, dummies))
+}
+
+/// Exports the model as pretty printed IFC-JSON, see [`to_value`].
+pub fn to_string(ifc: &IFC) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&to_value(ifc)?)?)
+}
+
+/// Writes the model as IFC-JSON to the given writer, see [`to_value`].
+pub fn to_writer(ifc: &IFC, writer: impl Write) -> Result<()> {
+    Ok(serde_json::to_writer(writer, &to_value(ifc)?)?)
+}
+
+/// Imports a model from the IFC-JSON format, see [`to_value`].
+///
+/// The entities get new STEP ids, ordered so that referenced entities come first. Members of
+/// entities which aren't attributes in their schema definition (e.g. inverse attributes) are
+/// ignored.
+pub fn from_value(value: &Value) -> Result<IFC> {
+    let header = match value.get("header") {
+        Some(header) => serde_json::from_value(header.clone()).context("invalid header")?,
+        None => default_header(value)?,
+    };
+
+    let data = value
+        .get("data")
+        .and_then(Value::as_array)
+        .context("missing `data` array")?;
+
+    let mut data = Importer::new(data)?.import(data)?;
+    data.renumber()?;
+
+    let ifc = IFC {
+        header,
+        data,
+        ..Default::default()
+    };
+    ifc.verify()?;
+
+    Ok(ifc)
+}
+
+/// Imports a model from an IFC-JSON string, see [`from_value`].
+pub fn from_str(s: &str) -> Result<IFC> {
+    from_value(&serde_json::from_str(s)?)
+}
+
+fn default_header(value: &Value) -> Result<Header> {
+    let mut header = IFC::default().header;
+
+    if let Some(schema) = value.get("schemaIdentifier").and_then(Value::as_str) {
+        let schema = FileSchema::from_str(&schema.to_uppercase())
+            .map_err(|_| anyhow!("unsupported schema `{schema}`"))?;
+        header.schema = FileSchemas(vec![schema]);
+    }
+
+    Ok(header)
+}
+
+fn decode_lossy(raw: &str) -> String {
+    decode(raw).unwrap_or_else(|| raw.to_owned())
+}
+
+struct Exporter {
+    entities: BTreeMap<Id, UnknownEntity>,
+    entity_types: HashMap<Id, &'static EntityType>,
+    top_level: HashSet<Id>,
+    raw: HashMap<Id, String>,
+    dummies: Vec<Id>,
+}
+
+impl Exporter {
+    fn new(ifc: &IFC) -> Result<Self> {
+        let ExportedEntities {
+            entities,
+            entity_types,
+            raw,
+            dummies,
+        } = ExportedEntities::new(&ifc.data, "IFC-JSON")?;
+
+        let mut references = HashMap::<Id, usize>::new();
+        for reference in entities.values().flat_map(UnknownEntity::ids) {
+            *references.entry(reference).or_default() += 1;
+        }
+
+        let top_level = entities
+            .keys()
+            .filter(|id| {
+                references.get(id) != Some(&1)
+                    || entity_types
+                        .get(id)
+                        .is_some_and(|entity_type| entity_type.has_global_id())
+            })
+            .copied()
+            .collect();
+
+        Ok(Self {
+            entities,
+            entity_types,
+            top_level,
+            raw,
+            dummies,
+        })
+    }
+
+    fn export(mut self) -> Result<Vec<Value>> {
+        let mut data = Vec::new();
+        let mut exported = HashSet::new();
+
+        for id in self.entities.keys().copied().collect::<Vec<_>>() {
+            if self.top_level.contains(&id) {
+                data.push(self.object(id, &mut exported)?);
+            }
+        }
+
+        // entities which are only referenced from within a reference cycle
+        while let Some(id) = self
+            .entities
+            .keys()
+            .find(|id| !exported.contains(*id))
+            .copied()
+        {
+            self.top_level.insert(id);
+            data.push(self.object(id, &mut exported)?);
+        }
+
+        Ok(data)
+    }
+
+    fn object(&self, id: Id, exported: &mut HashSet<Id>) -> Result<Value> {
+        exported.insert(id);
+
+        let entity = &self.entities[&id];
+        let entity_type = self.entity_types.get(&id);
+
+        let mut object = Map::new();
+        object.insert("type".into(), self.type_name(id).into());
+
+        if self.top_level.contains(&id)
+            && !entity_type.is_some_and(|entity_type| entity_type.has_global_id())
+        {
+            object.insert("globalId".into(), self.global_id(id).into());
+        }
+
+        if let Some(raw) = self.raw.get(&id) {
+            object.insert("raw".into(), raw.clone().into());
+            if !entity.args.is_empty() {
+                let references = StepValue::List(entity.args.clone());
+                object.insert("references".into(), self.value(&references, exported)?);
+            }
+
+            return Ok(object.into());
+        }
+
+        for (index, value) in entity.args.iter().enumerate() {
+            let attribute = entity_type.and_then(|entity_type| entity_type.attributes.get(index));
+
+            let name = match attribute {
+                Some(attribute) => lower_camel_case(attribute.name),
+                None => positional_attribute(index),
+            };
+
+            let value = match value {
+                // positional attributes are counted, so omitted ones have to be kept
+                StepValue::Omitted | StepValue::Inherited if attribute.is_none() => Value::Null,
+                StepValue::Omitted | StepValue::Inherited => continue,
+                // without a schema enumeration values can't be told apart from strings otherwise
+                StepValue::Enum(value)
+                    if attribute.is_none()
+                        && !matches!(value.as_str(), "T" | "TRUE" | "F" | "FALSE") =>
+                {
+                    format!(".{value}.").into()
+                }
+                StepValue::String(global_id) if index == 0 && name == "globalId" => {
+                    self.uuid(global_id).into()
+                }
+                value => self.value(value, exported)?,
+            };
+
+            object.insert(name, value);
+        }
+
+        Ok(object.into())
+    }
+
+    fn value(&self, value: &StepValue, exported: &mut HashSet<Id>) -> Result<Value> {
+        Ok(match value {
+            StepValue::Omitted | StepValue::Inherited => Value::Null,
+            StepValue::Id(id) => {
+                if !self.entities.contains_key(id) {
+                    bail!("reference to {id} which doesn't exist");
+                }
+
+                if self.top_level.contains(id) {
+                    json!({ "type": self.type_name(*id), "ref": self.global_id(*id) })
+                } else {
+                    self.object(*id, exported)?
+                }
+            }
+            StepValue::Integer(integer) => (*integer).into(),
+            StepValue::Real(real) => real
+                .parse()
+                .ok()
+                .and_then(Number::from_f64)
+                .with_context(|| format!("invalid real `{real}`"))?
+                .into(),
+            StepValue::String(string) => decode_lossy(string).into(),
+            StepValue::Enum(value) => match value.as_str() {
+                "T" | "TRUE" => true.into(),
+                "F" | "FALSE" => false.into(),
+                "U" | "UNKNOWN" => "UNKNOWN".into(),
+                value => value.into(),
+            },
+            StepValue::Binary(binary) => binary.clone().into(),
+            StepValue::Typed(keyword, value) => {
+                let name = defined_type_name(keyword).unwrap_or(keyword);
+
+                json!({ "type": name, "value": self.value(value, exported)? })
+            }
+            StepValue::List(values) => values
+                .iter()
+                .map(|value| self.value(value, exported))
+                .collect::<Result<Vec<_>>>()?
+                .into(),
+        })
+    }
+
+    fn type_name(&self, id: Id) -> &str {
+        match self.entity_types.get(&id) {
+            Some(entity_type) => entity_type.name,
+            None => &self.entities[&id].keyword,
+        }
+    }
+
+    /// uuid of the `GlobalId` attribute or one which is derived from the STEP id
+    fn global_id(&self, id: Id) -> String {
+        match self.entities[&id].args.first() {
+            Some(StepValue::String(global_id))
+                if self
+                    .entity_types
+                    .get(&id)
+                    .is_some_and(|entity_type| entity_type.has_global_id()) =>
+            {
+                self.uuid(global_id)
+            }
+            _ => uuid::Uuid::from_u128(id.0 as u128).to_string(),
+        }
+    }
+
+    /// IFC-JSON uses the uncompressed uuids
+    fn uuid(&self, global_id: &str) -> String {
+        let quoted = format!("'{global_id}'");
+        let uuid = IfcGloballyUniqueId::parse()
+            .parse(&quoted)
+            .map(|global_id| global_id.uuid().to_string())
+            .unwrap_or_else(|_| global_id.to_owned());
+        uuid
+    }
+}
+
+struct Importer {
+    registry: Arc<EntityRegistry>,
+    global_ids: HashMap<String, Id>,
+    next_id: usize,
+    entities: Vec<(Id, UnknownEntity)>,
+    dummies: Vec<(Id, Dummy)>,
+}
+
+impl Importer {
+    fn new(data: &[Value]) -> Result<Self> {
+        let mut global_ids = HashMap::new();
+
+        // top level entities get the first ids, so they can be referenced before they're imported
+        for (index, object) in data.iter().enumerate() {
+            if let Some(global_id) = object.get("globalId").and_then(Value::as_str) {
+                if global_ids
+                    .insert(global_id.to_owned(), Id(index + 1))
+                    .is_some()
+                {
+                    bail!("duplicate globalId `{global_id}`");
+                }
+            }
+        }
+
+        Ok(Self {
+            registry: EntityRegistry::global(),
+            global_ids,
+            next_id: data.len() + 1,
+            entities: Vec::new(),
+            dummies: Vec::new(),
+        })
+    }
+
+    fn import(mut self, data: &[Value]) -> Result<DataMap> {
+        for (index, object) in data.iter().enumerate() {
+            self.entity(object, Id(index + 1))?;
+        }
+
+        let registry = self.registry;
+
+        let mut entities = self
+            .entities
+            .into_iter()
+            .map(|(id, entity)| Ok((id, entity.into_entity(&registry)?)))
+            .collect::<Result<Vec<_>>>()?;
+        entities.extend(
+            self.dummies
+                .into_iter()
+                .map(|(id, dummy)| (id, Box::new(dummy) as Box<dyn IfcType>)),
+        );
+
+        Ok(DataMap::from(entities))
+    }
+
+    fn entity(&mut self, object: &Value, id: Id) -> Result<()> {
+        let type_name = object
+            .get("type")
+            .and_then(Value::as_str)
+            .with_context(|| format!("entity without type: {object}"))?;
+        let keyword = type_name.to_uppercase();
+
+        if let Some(raw) = object.get("raw") {
+            let raw = raw.as_str().context("`raw` must be a string")?;
+            let dummy = self.dummy(raw, object.get("references"))?;
+            self.dummies.push((id, dummy));
+
+            return Ok(());
+        }
+
+        // entities which couldn't be parsed on export are written with positional attributes, even
+        // if their type is modelled
+        let entity_type = self
+            .registry
+            .entity_type(&keyword)
+            .filter(|_| object.get(positional_attribute(0)).is_none());
+
+        let args = match entity_type {
+            Some(entity_type) => entity_type
+                .attributes
+                .iter()
+                .enumerate()
+                .map(|(index, attribute)| {
+                    if attribute.derived {
+                        return Ok(StepValue::Inherited);
+                    }
+
+                    match object.get(lower_camel_case(attribute.name)) {
+                        Some(Value::String(global_id))
+                            if index == 0 && attribute.name == "GlobalId" =>
+                        {
+                            Ok(StepValue::String(compressed_global_id(global_id)))
+                        }
+                        Some(value) => self.value(value, attribute.kind),
+                        None => Ok(StepValue::Omitted),
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => {
+                // omitted attributes aren't written, so the last one present gives the count
+                let count = object
+                    .as_object()
+                    .into_iter()
+                    .flat_map(Map::keys)
+                    .filter_map(|key| key.strip_prefix("attribute")?.parse::<usize>().ok())
+                    .max()
+                    .unwrap_or_default();
+
+                (0..count)
+                    .map(|index| match &object[positional_attribute(index)] {
+                        Value::String(value)
+                            if value.len() > 2
+                                && value.starts_with('.')
+                                && value.ends_with('.') =>
+                        {
+                            Ok(StepValue::Enum(value.trim_matches('.').to_owned()))
+                        }
+                        value => self.value(value, AttributeKind::Select),
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+        };
+
+        self.entities.push((id, UnknownEntity::new(keyword, args)));
+
+        Ok(())
+    }
+
+    /// Entity which is kept as raw STEP text, the references in the text are replaced by the ids
+    /// of the imported entities in the order in which they appear.
+    fn dummy(&mut self, raw: &str, references: Option<&Value>) -> Result<Dummy> {
+        let references = match references {
+            Some(references) => match self.value(references, AttributeKind::Reference)? {
+                StepValue::List(references) => references,
+                value => bail!("`references` must be a list, found `{value}`"),
+            },
+            None => Vec::new(),
+        };
+        let ids = references
+            .iter()
+            .map(|reference| match reference {
+                StepValue::Id(id) => Ok(*id),
+                value => bail!("expected reference, found `{value}`"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Dummy::with_references(raw, &ids)
+    }
+
+    fn value(&mut self, value: &Value, kind: AttributeKind) -> Result<StepValue> {
+        Ok(match value {
+            Value::Null => StepValue::Omitted,
+            Value::Bool(true) => StepValue::Enum("T".into()),
+            Value::Bool(false) => StepValue::Enum("F".into()),
+            // booleans are written as `true` and `false`, the third value of logicals as string
+            Value::String(value)
+                if kind == AttributeKind::Logical && matches!(value.as_str(), "U" | "UNKNOWN") =>
+            {
+                StepValue::Enum("U".into())
+            }
+            Value::Number(number) => match number.as_i64() {
+                Some(integer) => StepValue::Integer(integer),
+                None => StepValue::Real(format_real_primitive(
+                    number.as_f64().context("invalid number")?,
+                )),
+            },
+            Value::String(value)
+                if matches!(kind, AttributeKind::Enumeration | AttributeKind::Logical) =>
+            {
+                StepValue::Enum(value.clone())
+            }
+            Value::String(value) => StepValue::String(encode(value)),
+            Value::Array(values) => StepValue::List(
+                values
+                    .iter()
+                    .map(|value| self.value(value, kind))
+                    .collect::<Result<_>>()?,
+            ),
+            Value::Object(object) => {
+                let type_name = object
+                    .get("type")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                let keyword = type_name.to_uppercase();
+
+                if let Some(reference) = object.get("ref") {
+                    let reference = reference.as_str().context("`ref` must be a string")?;
+                    let id = self
+                        .global_ids
+                        .get(reference)
+                        .with_context(|| format!("reference to unknown globalId `{reference}`"))?;
+
+                    StepValue::Id(*id)
+                } else if let (Some(value), true) = (
+                    object.get("value"),
+                    defined_type_name(&keyword).is_some()
+                        || self.registry.entity_type(&keyword).is_none(),
+                ) {
+                    let kind = defined_type_kind(&keyword).unwrap_or(AttributeKind::Select);
+
+                    StepValue::Typed(keyword, Box::new(self.value(value, kind)?))
+                } else {
+                    let id = Id(self.next_id);
+                    self.next_id += 1;
+
+                    self.entity(value, id)?;
+                    StepValue::Id(id)
+                }
+            }
+        })
+    }
+}
+
+/// STEP files use the compressed form of the uuids
+fn compressed_global_id(global_id: &str) -> String {
+    match uuid::Uuid::parse_str(global_id) {
+        Ok(uuid) => IfcGloballyUniqueId::from_uuid(uuid).base64_ifc_string(),
+        Err(_) => global_id.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::HashMap,
+        hash::{DefaultHasher, Hash, Hasher},
+    };
+
+    use serde_json::json;
+
+    use winnow::Parser;
+
+    use crate::{
+        parser::{unknown_entity::UnknownEntity, IFCParse},
+        prelude::*,
+    };
+
+    /// hashes of all entities with references replaced by the hash of the referenced entity, so
+    /// models can be compared independent of their ids
+    fn structure(ifc: &IFC) -> Vec<u64> {
+        fn hash_value(value: &StepValue, hashes: &HashMap<Id, u64>, hasher: &mut DefaultHasher) {
+            match value {
+                StepValue::Id(id) => hashes[id].hash(hasher),
+                // derived attributes are always imported as `*`
+                StepValue::Inherited => StepValue::Omitted.to_string().hash(hasher),
+                StepValue::Typed(keyword, value) => {
+                    keyword.hash(hasher);
+                    hash_value(value, hashes, hasher);
+                }
+                StepValue::List(values) => {
+                    values.len().hash(hasher);
+                    for value in values {
+                        hash_value(value, hashes, hasher);
+                    }
+                }
+                value => value.to_string().hash(hasher),
+            }
+        }
+
+        let entities = ifc
+            .data
+            .iter()
+            .map(|(id, entity)| {
+                let text = entity.to_string();
+                let entity = UnknownEntity::parse().parse(&text).unwrap();
+                (*id, entity)
+            })
+            .collect::<HashMap<_, _>>();
+
+        // renumbered models only reference entities with lower ids
+        let mut ids = entities.keys().copied().collect::<Vec<_>>();
+        ids.sort();
+
+        let mut hashes = HashMap::new();
+        for id in ids {
+            let entity = &entities[&id];
+            let mut hasher = DefaultHasher::new();
+            entity.keyword.hash(&mut hasher);
+            for value in &entity.args {
+                hash_value(value, &hashes, &mut hasher);
+            }
+            hashes.insert(id, hasher.finish());
+        }
+
+        let mut hashes = hashes.into_values().collect::<Vec<_>>();
+        hashes.sort();
+        hashes
+    }
+
+    #[test]
+    fn round_trips_resources() {
+        for file in [
+            "resources/wall-standard-case.ifc",
+            "resources/AC20-FZK-Haus.ifc",
+        ] {
+            let mut ifc = IFC::from_file(file).unwrap();
+            ifc.renumber().unwrap();
+
+            let json = super::to_string(&ifc).unwrap();
+            let imported = super::from_str(&json).unwrap();
+
+            assert_eq!(imported.header, ifc.header, "{file}");
+            assert_eq!(structure(&imported), structure(&ifc), "{file}");
+        }
+    }
+
+    #[test]
+    fn exports_ifc_json_structure() {
+        let ifc = IFC::from_file("resources/wall-standard-case.ifc").unwrap();
+        let value = super::to_value(&ifc).unwrap();
+
+        assert_eq!(value["type"], "ifcJSON");
+        assert_eq!(value["schemaIdentifier"], "IFC4");
+
+        let data = value["data"].as_array().unwrap();
+        let wall = data
+            .iter()
+            .find(|object| object["type"] == "IfcWall")
+            .unwrap();
+
+        // uncompressed GlobalId, shared entities are referenced
+        assert_eq!(wall["globalId"].as_str().unwrap().len(), 36);
+        assert_eq!(wall["ownerHistory"]["type"], "IfcOwnerHistory");
+        assert!(wall["ownerHistory"]["ref"].is_string());
+
+        // the placement is only used by the wall, so it's nested
+        let placement = &wall["objectPlacement"];
+        assert_eq!(placement["type"], "IfcLocalPlacement");
+        assert_eq!(
+            placement["relativePlacement"]["type"],
+            "IfcAxis2Placement3D"
+        );
+        assert!(placement["relativePlacement"]["location"]["ref"].is_string());
+    }
+
+    #[test]
+    fn imports_references_and_typed_values() {
+        let value = json!({
+            "type": "ifcJSON",
+            "version": "0.0.1",
+            "schemaIdentifier": "IFC4",
+            "data": [
+                {
+                    "type": "IfcPropertySet",
+                    "globalId": "0a7b4f4a-7c1e-4a4d-9f5e-0b4b6b0c3d21",
+                    "name": "Pset_WallCommon",
+                    "hasProperties": [
+                        {
+                            "type": "IfcPropertySingleValue",
+                            "name": "IsExternal",
+                            "nominalValue": { "type": "IfcBoolean", "value": true }
+                        },
+                        {
+                            "type": "IfcPropertySingleValue",
+                            "name": "Reference",
+                            "nominalValue": { "type": "IfcLabel", "value": "O'Brien's wall" }
+                        }
+                    ]
+                },
+                {
+                    "type": "IfcPolyLoop",
+                    "attribute1": [
+                        { "type": "IfcCartesianPoint", "ref": "point" },
+                        { "type": "IfcCartesianPoint", "coordinates": [1.0, 0.0, 0.0] }
+                    ]
+                },
+                {
+                    "type": "IfcCartesianPoint",
+                    "globalId": "point",
+                    "coordinates": [0.0, 0.0, 0.0]
+                }
+            ]
+        });
+
+        let ifc = super::from_value(&value).unwrap();
+        let lines = ifc
+            .data
+            .iter()
+            .map(|(id, entity)| format!("{id}= {entity}"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            [
+                "#1= IFCPROPERTYSINGLEVALUE('IsExternal',$,IFCBOOLEAN(.TRUE.),$);",
+                "#2= IFCPROPERTYSINGLEVALUE('Reference',$,IFCLABEL('O''Brien''s wall'),$);",
+                "#3= IFCPROPERTYSET('0AUqzAV1vAJPzU2qjh33qX',$,'Pset_WallCommon',$,(#1,#2));",
+                "#4= IFCCARTESIANPOINT((0.,0.,0.));",
+                "#5= IFCCARTESIANPOINT((1.,0.,0.));",
+                "#6= IFCPOLYLOOP((#4,#5));",
+            ]
+        );
+        assert!(ifc
+            .data
+            .get_untyped(Id(6))
+            .downcast_ref::<UnknownEntity>()
+            .is_some());
+    }
+
+    #[test]
+    fn exports_dummies_and_logicals() {
+        let file = r#"ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+#1= IFCCARTESIANPOINT((0.,0.,0.));
+#2 IFCDIRECTION((1.,0.,0.));
+#3= IFCNAME('it''s;here' #1);
+#4= IFCPROPERTYSINGLEVALUE('Unknown',$,IFCLOGICAL(.U.),$);
+#5= IFCPROPERTYSINGLEVALUE('False',$,IFCBOOLEAN(.F.),$);
+ENDSEC;
+END-ISO-10303-21;
+"#;
+        let (ifc, _) = IFC::from_str_lenient(file).unwrap();
+
+        let (value, dummies) = super::to_value_with_dummies(&ifc).unwrap();
+        assert_eq!(dummies, [Id(2), Id(3)]);
+
+        let data = value["data"].as_array().unwrap();
+        let raw = data
+            .iter()
+            .find(|object| object["type"] == "IFCNAME")
+            .unwrap();
+        assert_eq!(raw["raw"], "IFCNAME('it''s;here' #1)");
+        assert_eq!(raw["references"][0]["type"], "IfcCartesianPoint");
+
+        let imported = super::from_value(&value).unwrap();
+        let lines = imported
+            .data
+            .iter()
+            .map(|(id, entity)| format!("{id}= {entity}"))
+            .collect::<Vec<_>>();
+
+        // the raw text references the imported point
+        assert_eq!(
+            lines,
+            [
+                "#1= IFCDIRECTION((1.,0.,0.));",
+                "#2= IFCCARTESIANPOINT((0.,0.,0.));",
+                "#3= IFCNAME('it''s;here' #2);",
+                "#4= IFCPROPERTYSINGLEVALUE('Unknown',$,IFCLOGICAL(.U.),$);",
+                "#5= IFCPROPERTYSINGLEVALUE('False',$,IFCBOOLEAN(.FALSE.),$);",
+            ]
+        );
+    }
+}
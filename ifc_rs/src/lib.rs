@@ -1,13 +1,22 @@
 #![allow(clippy::too_many_arguments)]
 #![allow(dead_code)]
 
-use anyhow::{anyhow, Context, Result};
-use parser::IFCParse;
-use std::{fmt::Display, fs, path::Path, str::FromStr};
+use anyhow::{Context, Result};
+use id::Id;
+use parser::{real::RealFormat, EntityRegistry, IFCParse, IfcParseError, RecoveredError};
+use reader::IfcReader;
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    path::Path,
+    str::FromStr,
+};
 use winnow::Parser;
 
 use meta::{
-    datamap::DataMap,
+    datamap::{DanglingReferences, DataMap},
     footer::Footer,
     header::{
         description::{FileDescription, ImplementationLevel},
@@ -18,6 +27,12 @@ use meta::{
     version::Version,
 };
 
+/// module containing the conversion of models between the IFC schema versions
+pub mod conversion;
+/// entities generated by `ifc_rs_codegen` from the excerpts of its tests, compiled with the tests
+#[cfg(test)]
+#[rustfmt::skip]
+mod generated_excerpts;
 /// module containing all the IFC objects related to the general geometry of the model
 pub mod geometry;
 /// module that defines IDs as they're used within the STEP IFC format. This also includes some
@@ -29,6 +44,10 @@ pub mod ifc_builder;
 /// module that defines utilities to query properties of an in-memory IFC file in the format of
 /// this crate
 pub mod ifc_extractor;
+/// module containing support for ifcZIP files, which are zip archives holding a single IFC file
+pub mod ifczip;
+/// module containing the import and export of models in the buildingSMART IFC-JSON format
+pub mod json;
 /// module containing definitions of materials which are used to define the look and other
 /// properties of IFC elements like walls, slabs, windows, etc.
 pub mod materials;
@@ -45,15 +64,24 @@ pub mod prelude;
 /// module containing property set definition and properties which can be used to enhance elements
 /// with extra data
 pub mod properties;
+/// module containing a streaming reader for IFC files which doesn't require the whole file in
+/// memory
+pub mod reader;
 /// module containing definitions of so called IFC relation objects which link one-or-more IFC
 /// objects to one-or-more other IFC objects or properties
 pub mod relations;
+/// module containing a checker for how faithfully models are written back after reading them
+pub mod roundtrip;
 /// general module containing definitions of traits used throughout the crate
 pub mod traits;
 /// module containing definitions of units for measurements and property definitions
 pub mod units;
+/// module containing the validation of models against the rules of the schema
+pub mod validation;
 /// module containing definitions of primitive values which are used in properties
 pub mod values;
+/// module containing the import and export of models in the ifcXML format (ISO 10303-28)
+pub mod xml;
 
 /// Central IFC Object which holds the information about the whole model together with meta
 /// information about the model
@@ -71,24 +99,212 @@ pub struct IFC {
 impl IFC {
     /// loads an IFC file from the given path
     ///
-    /// This may fail if the file doesn't exist or if the parsing fails
+    /// The file is streamed through an [`IfcReader`], so its contents are never held in memory
+    /// as a whole. ifcZIP archives are recognized by their signature and decompressed on the fly,
+    /// see [`ifczip::read_ifczip`]. This may fail if the file doesn't exist or if the parsing fails
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
-        let contents = fs::read_to_string(path)?;
-        let s = contents.as_str();
+        let mut reader = BufReader::new(File::open(path)?);
+
+        if ifczip::is_ifczip(&mut reader)? {
+            return ifczip::read_ifczip(reader, |reader| Self::from_reader(reader));
+        }
+
+        Self::from_reader(reader)
+    }
+
+    /// loads an IFC file from the given reader, see [`IfcReader`] for iterating the entities
+    /// without collecting them into an [`IFC`]
+    pub fn from_reader(reader: impl BufRead) -> Result<Self> {
+        IfcReader::new(reader)?.into_ifc()
+    }
+
+    /// loads an IFC file from the given path in lenient mode, see [`IFC::from_str_lenient`]
+    pub fn from_file_lenient(path: impl AsRef<Path>) -> Result<(Self, Vec<RecoveredError>)> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut contents = String::new();
+
+        if ifczip::is_ifczip(&mut reader)? {
+            ifczip::read_ifczip(reader, |reader| Ok(reader.read_to_string(&mut contents)?))?;
+        } else {
+            reader.read_to_string(&mut contents)?;
+        }
+
+        Self::from_str_lenient(&contents)
+    }
+
+    /// parses an IFC file, skipping malformed entity lines in the DATA section instead of failing
+    ///
+    /// Parsing resynchronises at the next `;` outside of strings and comments or the next line
+    /// starting with `#id=`. If the id of a broken line could be read, the line is kept as a
+    /// [`Dummy`](parser::dummy::Dummy) which holds the diagnostic. References to missing entities
+    /// or to entities of the wrong type don't fail the parse either. All recovered errors are
+    /// returned together with the model.
+    ///
+    /// Errors outside of the DATA section are still fatal.
+    pub fn from_str_lenient(s: &str) -> Result<(Self, Vec<RecoveredError>)> {
+        let (header, (mut data, skipped), footer) = Header::parse()
+            .flat_map(|header: Header| {
+                let registry = EntityRegistry::for_file(&header.schema);
+                (DataMap::parse_lenient(registry), Footer::parse())
+                    .map(move |(data, footer)| (header.clone(), data, footer))
+            })
+            .parse(s)
+            .map_err(|err| IfcParseError::new(s, err))?;
+
+        let mut errors: Vec<RecoveredError> = skipped
+            .into_iter()
+            .map(|line| {
+                let error = IfcParseError::at(s, s.len() - line.remaining, &line.error);
+
+                if let Some(id) = line.id {
+                    data.insert(
+                        id,
+                        parser::dummy::Dummy {
+                            s: line.raw,
+                            diagnostic: Some(error.clone()),
+                        },
+                    );
+                }
+
+                error.into()
+            })
+            .collect();
+
+        let me = Self {
+            header,
+            data,
+            footer,
+        };
+        errors.extend(me.recover_references());
+
+        Ok((me, errors))
+    }
+
+    /// Like [`IFC::verify`], but returns all reference errors instead of failing at the first.
+    /// Entities with dangling references aren't type checked, as the check would only report the
+    /// same missing entity again.
+    fn recover_references(&self) -> Vec<RecoveredError> {
+        let dangling = match self.data.validate_references() {
+            Ok(()) => Vec::new(),
+            Err(dangling) => dangling.0,
+        };
+
+        let invalid = self
+            .data
+            .iter()
+            .filter(|(id, _)| !dangling.iter().any(|reference| reference.entity == **id))
+            .filter_map(|(id, ifc_type)| {
+                let error = ifc_type.verify_id_types(self).err()?;
+
+                Some(RecoveredError::InvalidReference {
+                    entity: *id,
+                    message: format!("{error:#}"),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        dangling
+            .into_iter()
+            .map(Into::into)
+            .chain(invalid)
+            .collect()
+    }
+
+    /// writes the model in the STEP format to the given writer
+    ///
+    /// The entities are written one by one through a buffer, so the output is never held in
+    /// memory as a whole like with [`IFC::to_string`](ToString::to_string). Like there, they are
+    /// written in the attribute layouts of the schema in the header.
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        self.write_to_with(writer, RealFormat::Shortest)
+    }
+
+    /// Writes the model like [`IFC::write_to`], with all real numbers in the given format.
+    ///
+    /// ```no_run
+    /// # use ifc_rs::prelude::*;
+    /// # let ifc = IFC::default();
+    /// let mut file = std::fs::File::create("rounded.ifc")?;
+    /// ifc.write_to_with(&mut file, RealFormat::Fixed(6))?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn write_to_with(
+        &self,
+        writer: &mut impl Write,
+        real_format: RealFormat,
+    ) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        writeln!(writer, "{}", self.header)?;
+        self.data.write_in_schema(
+            &mut writer,
+            self.header.schema.0.first().copied(),
+            real_format,
+        )?;
+        write!(writer, "\n{}", self.footer)?;
+
+        writer.flush()
+    }
+
+    /// writes the model to the given path, see [`IFC::write_to`]
+    pub fn to_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.write_to(&mut File::create(path)?)
+    }
+
+    /// writes the model as compressed ifcZIP archive to the given path, see
+    /// [`ifczip::write_ifczip`]. The contained file is named after the archive, e.g.
+    /// `model.ifczip` contains `model.ifc`
+    pub fn to_ifczip(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+
+        ifczip::write_ifczip(
+            self,
+            BufWriter::new(File::create(path)?),
+            &format!("{name}.ifc"),
+        )
+    }
+
+    /// Assigns contiguous ids to all entities, so referenced entities come before the entities
+    /// referencing them, and returns the mapping of the old ids to the new ones.
+    ///
+    /// Useful after removing entities or merging models, see [`DataMap::renumber`]. Fails if an
+    /// entity references an id which doesn't exist.
+    pub fn renumber(&mut self) -> Result<BTreeMap<Id, Id>, DanglingReferences> {
+        self.data.renumber()
+    }
 
-        Self::from_str(s)
+    /// Checks the WHERE rules, cardinalities and uniqueness rules of the schema and returns all
+    /// violations, see [`validation::validate`]. An empty list means the model is valid.
+    pub fn validate(&self) -> Vec<validation::Violation> {
+        validation::validate(self)
+    }
+
+    fn verify(&self) -> Result<()> {
+        self.data.validate_references()?;
+
+        for (id, ifc_type) in self.data.iter() {
+            ifc_type
+                .verify_id_types(self)
+                .context(format!("ID: {id}"))?;
+        }
+
+        Ok(())
     }
 }
 
 impl IFCParse for IFC {
+    /// Parses a whole file. The entities are parsed with the attribute layouts of the schema
+    /// which is declared in the header, see [`EntityRegistry::with_schema`].
     fn parse<'a>() -> impl parser::IFCParser<'a, Self> {
-        winnow::seq! {
-            IFC {
-                header: Header::parse(),
-                data: DataMap::parse(),
-                footer: Footer::parse(),
-            }
-        }
+        Header::parse().flat_map(|header: Header| {
+            let registry = EntityRegistry::for_file(&header.schema);
+            (DataMap::parse_with(registry), Footer::parse()).map(move |(data, footer)| IFC {
+                header: header.clone(),
+                data,
+                footer,
+            })
+        })
     }
 }
 
@@ -98,11 +314,8 @@ impl FromStr for IFC {
     fn from_str(s: &str) -> Result<Self> {
         let me = IFC::parse()
             .parse(s)
-            .map_err(|err| anyhow!("parsing failed: {err:#?}"))?;
-
-        for (id, ifc_type) in me.data.0.iter() {
-            ifc_type.verify_id_types(&me).context(format!("ID: {id}"))?;
-        }
+            .map_err(|err| IfcParseError::new(s, err))?;
+        me.verify()?;
 
         Ok(me)
     }
@@ -119,6 +332,7 @@ impl Default for IFC {
                 },
                 name: FileDetails::default(),
                 schema: FileSchemas(vec![FileSchema::IFC4X3_ADD2]),
+                entities: Vec::new(),
             },
             data: Default::default(),
             footer: Footer {
@@ -130,7 +344,9 @@ impl Default for IFC {
 
 impl Display for IFC {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}\n{}\n{}", self.header, self.data, self.footer)
+        let data = self.data.in_schema(self.header.schema.0.first().copied());
+
+        write!(f, "{}\n{}\n{}", self.header, data, self.footer)
     }
 }
 
@@ -139,7 +355,12 @@ mod test {
     use crate::objects::wall::test::print_wall_hierarchy;
 
     use super::IFC;
+    use crate::{
+        id::Id,
+        parser::{dummy::Dummy, real::RealFormat, RecoveredError},
+    };
     use anyhow::Result;
+    use std::str::FromStr;
 
     #[test]
     fn load_wall_example_file() -> Result<()> {
@@ -150,6 +371,142 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn write_to_matches_display() -> Result<()> {
+        let ifc = IFC::from_file("resources/AC20-FZK-Haus.ifc")?;
+
+        let mut written = Vec::new();
+        ifc.write_to(&mut written)?;
+        assert_eq!(String::from_utf8(written)?, ifc.to_string());
+
+        let path = std::env::temp_dir().join("ifc_rs_write_to_matches_display.ifc");
+        ifc.to_file(&path)?;
+        let from_file = std::fs::read_to_string(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(from_file, ifc.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn writes_reals_with_fixed_precision() -> Result<()> {
+        let (ifc, _) = IFC::from_str_lenient(
+            r#"ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+#1= IFCCARTESIANPOINT((0.126,1.E-1,2.));
+#2= IFCPROPERTYSINGLEVALUE('Width',$,IFCLENGTHMEASURE(0.3),$);
+#3= IFCDIRECTION((0.,0.,1.)) garbage;
+ENDSEC;
+END-ISO-10303-21;
+"#,
+        )?;
+
+        let mut written = Vec::new();
+        ifc.write_to_with(&mut written, RealFormat::Fixed(2))?;
+        let written = String::from_utf8(written)?;
+
+        assert!(written.contains("#1= IFCCARTESIANPOINT((0.13,0.10,2.00));"));
+        assert!(written.contains("#2= IFCPROPERTYSINGLEVALUE('Width',$,IFCLENGTHMEASURE(0.30),$);"));
+        // dummies are written as they were read
+        assert!(written.contains("#3= IFCDIRECTION((0.,0.,1.)) garbage;"));
+
+        let mut shortest = Vec::new();
+        ifc.write_to(&mut shortest)?;
+        assert!(String::from_utf8(shortest)?.contains("#1= IFCCARTESIANPOINT((0.126,0.1,2.));"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ifczip_files_are_read_transparently() -> Result<()> {
+        let ifc = IFC::from_file("resources/wall-standard-case.ifc")?;
+
+        let path = std::env::temp_dir().join("ifc_rs_ifczip_files_are_read_transparently.ifczip");
+        ifc.to_ifczip(&path)?;
+        let from_zip = IFC::from_file(&path);
+        let (from_zip_lenient, errors) = IFC::from_file_lenient(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(from_zip?.to_string(), ifc.to_string());
+        assert_eq!(from_zip_lenient.to_string(), ifc.to_string());
+        assert!(errors.is_empty());
+
+        Ok(())
+    }
+
+    const IFC2X3_FILE: &str = "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView_V2.0]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC2X3'));
+ENDSEC;
+DATA;
+#1= IFCWALLSTANDARDCASE('0DWgwt6o1FOx7466fPk$jl',$,'Wall',$,$,$,$,$);
+#2= IFCMATERIAL('Brick');
+#3= IFCMATERIALLAYER(#2,0.24,$);
+#4= IFCMATERIALLAYERSET((#3),'Wall');
+#5= IFCMATERIALLAYERSETUSAGE(#4,.AXIS2.,.POSITIVE.,0.);
+ENDSEC;
+END-ISO-10303-21;
+";
+
+    #[test]
+    fn loads_ifc2x3_layouts() -> Result<()> {
+        use crate::prelude::*;
+
+        let ifc = IFC::from_str(IFC2X3_FILE)?;
+
+        let (_, wall) = ifc.data.find_all_of_type::<Wall>().next().unwrap();
+        assert!(wall.predefined_type.is_omitted());
+        let (_, material) = ifc.data.find_all_of_type::<Material>().next().unwrap();
+        assert!(material.description.is_omitted());
+        assert_eq!(ifc.data.find_all_of_type::<MaterialLayer>().count(), 1);
+        assert_eq!(ifc.data.find_all_of_type::<MaterialLayerSet>().count(), 1);
+        assert_eq!(
+            ifc.data.find_all_of_type::<MaterialLayerSetUsage>().count(),
+            1
+        );
+
+        let streamed = IFC::from_reader(IFC2X3_FILE.as_bytes())?;
+        assert_eq!(streamed.to_string(), ifc.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_entities_which_dont_match_the_schema() {
+        let file =
+            IFC2X3_FILE.replace("#2= IFCMATERIAL('Brick');", "#2= IFCMATERIAL('Brick',$,$);");
+        let Err(err) = IFC::from_str(&file) else {
+            panic!("IFC4 entity was read from an IFC2X3 file");
+        };
+        assert_eq!(
+            err.to_string(),
+            "parsing failed at line 9, column 5 in #2 (IFCMATERIAL) while parsing attributes of the IFC2X3 schema in entity instance"
+        );
+
+        let file = IFC2X3_FILE.replace(
+            "#2= IFCMATERIAL('Brick');",
+            "#2= IFCMATERIAL('Brick');\n#6= IFCMATERIALCONSTITUENT($,$,#2,$,$);",
+        );
+        let Err(err) = IFC::from_reader(file.as_bytes()) else {
+            panic!("IFC4 entity was read from an IFC2X3 file");
+        };
+        assert_eq!(
+            err.to_string(),
+            "parsing failed at line 10, column 5 in #6 (IFCMATERIALCONSTITUENT) while parsing entity instance: expected entity which is part of the IFC2X3 schema"
+        );
+
+        let (ifc, errors) = IFC::from_str_lenient(&file).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(ifc.data.contains(&Id(6)));
+    }
+
     #[test]
     fn load_archicad_file() -> Result<()> {
         let ifc = IFC::from_file("resources/AC20-FZK-Haus.ifc")?;
@@ -158,4 +515,103 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn lenient_parsing_skips_broken_lines() -> Result<()> {
+        let file = r#"ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+#1= IFCCARTESIANPOINT((0.,0.,0.));
+#2 IFCDIRECTION((1.,0.,0.));
+this is not an entity;
+#4= IFCDIRECTION((0.,0.,1.));
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+        assert!(IFC::from_str(file).is_err());
+
+        let (ifc, errors) = IFC::from_str_lenient(file)?;
+
+        let errors: Vec<_> = errors.iter().filter_map(|e| e.as_parse_error()).collect();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].entity_id, Some(Id(2)));
+        assert_eq!(errors[1].line, 10);
+
+        assert_eq!(ifc.data.len(), 3);
+        let dummy = ifc.data.get_untyped(Id(2)).downcast_ref::<Dummy>().unwrap();
+        assert_eq!(dummy.s, "IFCDIRECTION((1.,0.,0.))");
+        assert_eq!(dummy.diagnostic.as_ref(), Some(errors[0]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn lenient_parsing_skips_semicolons_in_strings() -> Result<()> {
+        let file = r#"ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+#1= IFCCARTESIANPOINT((0.,0.,0.));
+#3= IFCNAME('it''s;here' #1);
+#4= IFCDIRECTION((0.,0.,1.));
+#5 IFCNAME('broken;line');
+#6= IFCDIRECTION((0.,1.,0.));
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+        let (ifc, errors) = IFC::from_str_lenient(file)?;
+
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(ifc.data.len(), 5);
+        let dummy = ifc.data.get_untyped(Id(3)).downcast_ref::<Dummy>().unwrap();
+        assert_eq!(dummy.s, "IFCNAME('it''s;here' #1)");
+        let dummy = ifc.data.get_untyped(Id(5)).downcast_ref::<Dummy>().unwrap();
+        assert_eq!(dummy.s, "IFCNAME('broken;line')");
+
+        Ok(())
+    }
+
+    #[test]
+    fn lenient_parsing_recovers_dangling_references() -> Result<()> {
+        let file = r#"ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+#1= IFCCARTESIANPOINT((0.,0.,0.));
+#2= IFCAXIS2PLACEMENT3D(#1,#5,$);
+#3= IFCAXIS2PLACEMENT3D(#2,$,$);
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+        assert!(IFC::from_str(file).is_err());
+
+        let (ifc, errors) = IFC::from_str_lenient(file)?;
+
+        assert_eq!(ifc.data.len(), 3);
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        assert!(matches!(
+            &errors[0],
+            RecoveredError::DanglingReference(reference)
+                if reference.entity == Id(2) && reference.reference == Id(5)
+        ));
+        assert!(matches!(
+            errors[1],
+            RecoveredError::InvalidReference { entity: Id(3), .. }
+        ));
+
+        Ok(())
+    }
 }
@@ -10,7 +10,7 @@ use crate::parser::*;
 /// direction is positive or negative along the given axis.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcmaterialresource/lexical/ifcdirectionsenseenum.htm
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, PartialEq, Eq)]
 pub enum DirectionSenseEnum {
     /// Direction defined to be positive.
     #[strum(to_string = ".POSITIVE.")]
@@ -35,3 +35,5 @@ impl IFCParse for DirectionSenseEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(DirectionSenseEnum);
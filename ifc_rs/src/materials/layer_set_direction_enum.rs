@@ -11,7 +11,7 @@ use crate::parser::*;
 /// or direction of layer offsets.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcmaterialresource/lexical/ifclayersetdirectionenum.htm
-#[derive(EnumString, VariantNames, Display, Clone, Copy)]
+#[derive(EnumString, VariantNames, Display, Clone, Copy, PartialEq, Eq)]
 pub enum LayerSetDirectionEnum {
     /// Usually x-axis.
     #[strum(to_string = ".AXIS1.")]
@@ -40,3 +40,5 @@ impl IFCParse for LayerSetDirectionEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(LayerSetDirectionEnum);
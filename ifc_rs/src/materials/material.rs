@@ -15,8 +15,10 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmaterial.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcMaterial")]
 pub struct Material {
     /// Name of the material.
+    #[ifc(name = "Name")]
     pub material: OptionalParameter<StringPrimitive>,
 
     /// Definition of the material in more descriptive terms than given by
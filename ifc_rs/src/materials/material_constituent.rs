@@ -22,6 +22,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcmaterialresource/lexical/ifcmaterialconstituent.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcMaterialConstituent")]
 pub struct MaterialConstituent {
     /// The name by which the material constituent is known.
     pub name: OptionalParameter<StringPrimitive>,
@@ -19,6 +19,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcmaterialresource/lexical/ifcmaterialconstituentset.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcMaterialConstituentSet")]
 pub struct MaterialConstituentSet {
     /// The name by which the constituent set is known.
     pub name: OptionalParameter<StringPrimitive>,
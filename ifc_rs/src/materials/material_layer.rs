@@ -19,6 +19,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmateriallayer.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcMaterialLayer")]
 pub struct MaterialLayer {
     /// Optional reference to the material from which the layer is constructed.
     /// Note that if this value is not given, it does not denote a layer
@@ -17,6 +17,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmateriallayerset.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcMaterialLayerSet")]
 pub struct MaterialLayerSet {
     /// Identification of the IfcMaterialLayer’s from which the
     /// IfcMaterialLayerSet is composed.
@@ -20,8 +20,10 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmateriallayersetusage.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcMaterialLayerSetUsage")]
 pub struct MaterialLayerSetUsage {
     /// The IfcMaterialLayerSet set to which the usage is applied.
+    #[ifc(name = "ForLayerSet")]
     pub spatial_element_structure: TypedId<MaterialLayerSet>,
 
     /// Orientation of the material layer set relative to element reference
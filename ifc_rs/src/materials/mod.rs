@@ -1,9 +1,4 @@
-use winnow::combinator::alt;
-
-use crate::{
-    parser::{IFCParse, IFCParser},
-    prelude::*,
-};
+use crate::parser::EntityRegistry;
 
 pub(crate) mod direction_sense_enum;
 pub(crate) mod layer_set_direction_enum;
@@ -18,14 +13,16 @@ pub(crate) mod prelude;
 pub struct Materials;
 
 impl Materials {
-    pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
-        alt((
-            material_layer_set_usage::MaterialLayerSetUsage::parse_any(),
-            material_layer_set::MaterialLayerSet::parse_any(),
-            material_layer::MaterialLayer::parse_any(),
-            material::Material::parse_any(),
-            material_constituent::MaterialConstituent::parse_any(),
-            material_constituent_set::MaterialConstituentSet::parse_any(),
-        ))
+    pub(crate) fn register(registry: &mut EntityRegistry) {
+        registry.register::<material_layer_set_usage::MaterialLayerSetUsage>(
+            "IFCMATERIALLAYERSETUSAGE",
+        );
+        registry.register::<material_layer_set::MaterialLayerSet>("IFCMATERIALLAYERSET");
+        registry.register::<material_layer::MaterialLayer>("IFCMATERIALLAYER");
+        registry.register::<material::Material>("IFCMATERIAL");
+        registry.register::<material_constituent::MaterialConstituent>("IFCMATERIALCONSTITUENT");
+        registry.register::<material_constituent_set::MaterialConstituentSet>(
+            "IFCMATERIALCONSTITUENTSET",
+        );
     }
 }
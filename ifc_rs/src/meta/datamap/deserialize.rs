@@ -1,43 +1,191 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
 
 use winnow::{
-    combinator::{alt, preceded, repeat_till, separated_pair},
+    ascii::space0,
+    combinator::{eof, opt, preceded, repeat_till, separated_pair, terminated},
+    error::{ContextError, ErrMode, StrContext, StrContextValue},
+    stream::Stream,
     Parser,
 };
 
 use super::DataMap;
 use crate::{
-    geometry::Geometry,
     id::Id,
-    materials::Materials,
-    objects::Objects,
-    parser::{dummy::Dummy, p_space_or_comment_surrounded, IFCParse, IFCParser},
+    parser::{p_space_or_comment_surrounded, EntityRegistry, IFCParse, IFCParser},
     prelude::*,
-    properties::Properties,
-    relations::Relation,
-    units::Units,
+    reader::{split_statements, StatementScanner},
 };
 
+/// An entity line of the DATA section which couldn't be parsed and was skipped in lenient mode.
+pub(crate) struct SkippedLine {
+    /// the id of the entity instance, if at least that could be read
+    pub(crate) id: Option<Id>,
+    /// the raw text of the statement after the id, without the trailing `;`
+    pub(crate) raw: String,
+    /// amount of input left at the position where parsing failed, used to calculate the offset
+    /// into the whole file
+    pub(crate) remaining: usize,
+    pub(crate) error: ContextError,
+}
+
+enum LenientLine {
+    Parsed(Id, Box<dyn IfcType>),
+    Skipped(SkippedLine),
+}
+
+fn p_obj<'a>(registry: Arc<EntityRegistry>) -> impl IFCParser<'a, Box<dyn IfcType>> {
+    p_space_or_comment_surrounded(move |input: &mut &'a str| registry.parse_entity(input))
+}
+
+pub(crate) fn p_line<'a>(
+    registry: Arc<EntityRegistry>,
+) -> impl IFCParser<'a, (Id, Box<dyn IfcType>)> {
+    separated_pair(
+        Id::parse().context(StrContext::Expected(StrContextValue::Description(
+            "entity instance name like `#123`",
+        ))),
+        p_space_or_comment_surrounded("=")
+            .context(StrContext::Expected(StrContextValue::CharLiteral('='))),
+        p_obj(registry),
+    )
+    .context(StrContext::Label("entity instance"))
+}
+
+/// Parses entity lines until the end of the input. Used for chunks of the DATA section which
+/// were already split off at statement boundaries.
+pub(crate) fn p_lines_till_eof<'a>(
+    registry: Arc<EntityRegistry>,
+) -> impl IFCParser<'a, Vec<(Id, Box<dyn IfcType>)>> {
+    repeat_till(.., p_space_or_comment_surrounded(p_line(registry)), eof).map(|(lines, _)| lines)
+}
+
+/// Parses an entity line and, if that fails, skips ahead to the end of the broken statement.
+///
+/// The statement ends at the next `;` outside of strings and comments or right before the next
+/// line which looks like the start of an entity instance (`#123=`), whichever comes first.
+fn p_line_lenient<'a>(registry: Arc<EntityRegistry>) -> impl IFCParser<'a, LenientLine> {
+    let mut p_line = p_line(registry);
+    let mut p_id = opt(terminated(
+        Id::parse(),
+        p_space_or_comment_surrounded(opt("=")),
+    ));
+
+    move |input: &mut &'a str| {
+        let start = input.checkpoint();
+
+        match p_line.parse_next(input) {
+            Ok((id, obj)) => Ok(LenientLine::Parsed(id, obj)),
+            Err(ErrMode::Backtrack(error)) => {
+                let remaining = input.len();
+                input.reset(&start);
+
+                let id = p_id.parse_next(input)?;
+                let statement = split_statements(input).next().unwrap_or_default();
+                let end = next_instance_start(statement).unwrap_or(statement.len());
+                if end == 0 {
+                    return Err(ErrMode::Backtrack(error));
+                }
+
+                let raw = input[..end].trim();
+                let raw = raw.strip_suffix(';').unwrap_or(raw).trim_end().to_owned();
+                *input = &input[end..];
+
+                Ok(LenientLine::Skipped(SkippedLine {
+                    id,
+                    raw,
+                    remaining,
+                    error,
+                }))
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Finds the first line of the statement which starts with an entity instance name like `#123=`
+/// outside of strings and comments, as the `;` of a broken statement may be missing.
+fn next_instance_start(statement: &str) -> Option<usize> {
+    let mut scanner = StatementScanner::default();
+    let mut p_instance = (space0, Id::parse(), space0, "=");
+    let mut line_start = 0;
+
+    for line in statement.split_inclusive('\n') {
+        scanner.scan(line.as_bytes());
+        line_start += line.len();
+
+        let mut next_line = &statement[line_start..];
+        if !scanner.in_literal()
+            && !next_line.is_empty()
+            && p_instance.parse_next(&mut next_line).is_ok()
+        {
+            return Some(line_start);
+        }
+    }
+
+    None
+}
+
+pub(crate) fn p_data_section<'a, T>(p_lines: impl IFCParser<'a, T>) -> impl IFCParser<'a, T> {
+    p_space_or_comment_surrounded(preceded(
+        "DATA;".context(StrContext::Expected(StrContextValue::StringLiteral(
+            "DATA;",
+        ))),
+        p_lines,
+    ))
+}
+
+pub(crate) fn p_lines_sequential<'a>(
+    registry: Arc<EntityRegistry>,
+) -> impl IFCParser<'a, BTreeMap<Id, Box<dyn IfcType>>> {
+    let p_line_spaced = p_space_or_comment_surrounded(p_line(registry));
+    repeat_till(.., p_line_spaced, p_space_or_comment_surrounded("ENDSEC;"))
+        .map(|(v, _): (BTreeMap<Id, Box<dyn IfcType>>, _)| v)
+}
+
 impl IFCParse for DataMap {
+    /// Parses the DATA section with the attribute layouts of IFC4, see [`DataMap::parse_with`].
     fn parse<'a>() -> impl IFCParser<'a, Self> {
-        let p_obj = p_space_or_comment_surrounded(alt((
-            alt((
-                Objects::parse(),
-                Geometry::parse(),
-                Relation::parse(),
-                Units::parse(),
-                Materials::parse(),
-                Properties::parse(),
-            )),
-            // catch all fallback
-            Dummy::parse_any(),
-        )));
-        let p_line = separated_pair(Id::parse(), p_space_or_comment_surrounded("="), p_obj);
-        let p_line_spaced = p_space_or_comment_surrounded(p_line);
-        let p_lines = repeat_till(.., p_line_spaced, p_space_or_comment_surrounded("ENDSEC;"))
-            .map(|(v, _): (BTreeMap<Id, Box<dyn IfcType>>, _)| v);
-        let p_data_section = p_space_or_comment_surrounded(preceded("DATA;", p_lines));
-        p_data_section.map(DataMap)
+        Self::parse_with(EntityRegistry::global())
+    }
+}
+
+impl DataMap {
+    /// Parses the DATA section with the given registry, which may be set up for the schema of
+    /// the file. With the `rayon` feature enabled, the entity lines are parsed on multiple
+    /// threads.
+    pub(crate) fn parse_with<'a>(registry: Arc<EntityRegistry>) -> impl IFCParser<'a, Self> {
+        #[cfg(feature = "rayon")]
+        let p_lines = super::parallel::p_lines_parallel(registry);
+        #[cfg(not(feature = "rayon"))]
+        let p_lines = p_lines_sequential(registry);
+
+        p_data_section(p_lines).map(DataMap::from)
+    }
+
+    /// Parses the DATA section like [`DataMap::parse_with`], but skips malformed entity lines
+    /// instead of failing. The skipped lines are returned alongside the parsed entities.
+    pub(crate) fn parse_lenient<'a>(
+        registry: Arc<EntityRegistry>,
+    ) -> impl IFCParser<'a, (Self, Vec<SkippedLine>)> {
+        let p_line_spaced = p_space_or_comment_surrounded(p_line_lenient(registry));
+        let p_lines = repeat_till(.., p_line_spaced, p_space_or_comment_surrounded("ENDSEC;")).map(
+            |(lines, _): (Vec<LenientLine>, _)| {
+                let mut map = BTreeMap::new();
+                let mut skipped = Vec::new();
+
+                for line in lines {
+                    match line {
+                        LenientLine::Parsed(id, obj) => {
+                            map.insert(id, obj);
+                        }
+                        LenientLine::Skipped(line) => skipped.push(line),
+                    }
+                }
+
+                (DataMap::from(map), skipped)
+            },
+        );
+        p_data_section(p_lines)
     }
 }
 
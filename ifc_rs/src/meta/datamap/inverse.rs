@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+
+use super::DataMap;
+use crate::{
+    id::{Id, TypedId},
+    prelude::*,
+};
+
+/// Maps every id to the entities which reference it.
+pub(crate) type InverseIndex = HashMap<Id, Vec<InverseReference>>;
+
+/// Reference to an id from one of the fields of another entity, see [`DataMap::references_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InverseReference {
+    /// id of the entity which holds the reference
+    pub entity: Id,
+    /// name of the field which holds the reference, e.g. `related_objects`
+    pub field: &'static str,
+}
+
+impl DataMap {
+    /// All entities which reference the given id, ordered by their id.
+    ///
+    /// This is what the INVERSE attributes of the schema are derived from, e.g. the
+    /// `IsTypedBy` relation of an object is the `related_objects` reference of a
+    /// [`RelDefinesByType`], see [`DataMap::is_typed_by`].
+    ///
+    /// The index is built on the first call after the map was changed.
+    pub fn references_to(&self, id: impl Into<Id>) -> &[InverseReference] {
+        self.inverse_index()
+            .get(&id.into())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// `IsDecomposedBy`, the aggregations whose `relating_object` is the given object
+    pub fn is_decomposed_by(
+        &self,
+        id: impl Into<Id>,
+    ) -> impl Iterator<Item = (TypedId<RelAggregates>, &RelAggregates)> {
+        self.referencing(id, "relating_object")
+    }
+
+    /// `Decomposes`, the aggregations which list the given object in their `related_objects`
+    pub fn decomposes(
+        &self,
+        id: impl Into<Id>,
+    ) -> impl Iterator<Item = (TypedId<RelAggregates>, &RelAggregates)> {
+        self.referencing(id, "related_objects")
+    }
+
+    /// `ContainsElements`, the containments whose `relating_structure` is the given structure
+    pub fn contains_elements(
+        &self,
+        id: impl Into<Id>,
+    ) -> impl Iterator<
+        Item = (
+            TypedId<RelContainedInSpatialStructure>,
+            &RelContainedInSpatialStructure,
+        ),
+    > {
+        self.referencing(id, "relating_structure")
+    }
+
+    /// `ContainedInStructure`, the containments which list the given element in their
+    /// `related_elements`
+    pub fn contained_in_structure(
+        &self,
+        id: impl Into<Id>,
+    ) -> impl Iterator<
+        Item = (
+            TypedId<RelContainedInSpatialStructure>,
+            &RelContainedInSpatialStructure,
+        ),
+    > {
+        self.referencing(id, "related_elements")
+    }
+
+    /// `IsTypedBy`, the type relations which list the given object in their `related_objects`
+    pub fn is_typed_by(
+        &self,
+        id: impl Into<Id>,
+    ) -> impl Iterator<Item = (TypedId<RelDefinesByType>, &RelDefinesByType)> {
+        self.referencing(id, "related_objects")
+    }
+
+    /// `IsDefinedBy`, the property relations which list the given object in their
+    /// `related_objects`
+    pub fn is_defined_by(
+        &self,
+        id: impl Into<Id>,
+    ) -> impl Iterator<Item = (TypedId<RelDefinesByProperties>, &RelDefinesByProperties)> {
+        self.referencing(id, "related_objects")
+    }
+
+    /// `HasAssociations`, the material associations which list the given object in their
+    /// `related_objects`. Other associations aren't modelled yet.
+    pub fn has_associations(
+        &self,
+        id: impl Into<Id>,
+    ) -> impl Iterator<Item = (TypedId<RelAssociatesMaterial>, &RelAssociatesMaterial)> {
+        self.referencing(id, "related_objects")
+    }
+
+    /// `HasOpenings`, the voids whose `relating_building_element` is the given element
+    pub fn has_openings(
+        &self,
+        id: impl Into<Id>,
+    ) -> impl Iterator<Item = (TypedId<RelVoidsElement>, &RelVoidsElement)> {
+        self.referencing(id, "relating_building_element")
+    }
+
+    /// `FillsVoids`, the fillings whose `related_building_element` is the given element
+    pub fn fills_voids(
+        &self,
+        id: impl Into<Id>,
+    ) -> impl Iterator<Item = (TypedId<RelFillsElement>, &RelFillsElement)> {
+        self.referencing(id, "related_building_element")
+    }
+
+    /// All entities of type `T` which reference the given id in the given field. Prefer the
+    /// accessors of the INVERSE attributes like [`DataMap::is_typed_by`], which can't get the
+    /// field name wrong.
+    pub fn referencing<T: IfcType>(
+        &self,
+        id: impl Into<Id>,
+        field: &'static str,
+    ) -> impl Iterator<Item = (TypedId<T>, &T)> {
+        self.references_to(id)
+            .iter()
+            .filter(move |reference| reference.field == field)
+            .filter_map(|reference| {
+                let entity = self.try_get_untyped(reference.entity)?.downcast_ref()?;
+                Some((TypedId::new(reference.entity), entity))
+            })
+    }
+
+    fn inverse_index(&self) -> &InverseIndex {
+        self.inverse.get_or_init(|| {
+            let mut index = InverseIndex::new();
+
+            for (id, ifc_type) in self.iter() {
+                ifc_type.visit_ids(&mut |field, reference| {
+                    let reference_from = InverseReference { entity: *id, field };
+
+                    // lists may contain the same id multiple times
+                    let references = index.entry(reference).or_default();
+                    if references.last() != Some(&reference_from) {
+                        references.push(reference_from);
+                    }
+                });
+            }
+
+            index
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy_math::DVec3;
+
+    use super::InverseReference;
+    use crate::{id::Id, prelude::*};
+
+    #[test]
+    fn finds_referencing_entities() {
+        let mut ifc = IFC::default();
+
+        let axis = Axis3D::new(Point3D::from(DVec3::ZERO), &mut ifc);
+        let origin = axis.location;
+        let placement = ifc.data.insert_new(axis);
+        let x = ifc.data.insert_new(Direction3D::from(DVec3::X));
+
+        assert_eq!(
+            ifc.data.references_to(origin),
+            [InverseReference {
+                entity: placement.id(),
+                field: "location"
+            }]
+        );
+        assert!(ifc.data.references_to(x).is_empty());
+
+        // changes to the map invalidate the index
+        ifc.data.get_mut(placement).local_x = x.into();
+        assert_eq!(
+            ifc.data.references_to(x),
+            [InverseReference {
+                entity: placement.id(),
+                field: "local_x"
+            }]
+        );
+
+        ifc.data.remove(placement);
+        assert!(ifc.data.references_to(origin).is_empty());
+    }
+
+    #[test]
+    fn finds_relations_of_wall() {
+        let ifc = IFC::from_file("resources/wall-standard-case.ifc").unwrap();
+        let (wall, _) = ifc.data.find_all_of_type::<Wall>().next().unwrap();
+
+        let (_, rel_type) = ifc.data.is_typed_by(wall).next().unwrap();
+        assert!(ifc
+            .data
+            .try_get_untyped(rel_type.relating_type)
+            .and_then(|wall_type| wall_type.downcast_ref::<WallType>())
+            .is_some());
+
+        assert_eq!(ifc.data.has_associations(wall).count(), 1);
+
+        // the field has to match as well
+        assert_eq!(
+            ifc.data
+                .referencing::<RelDefinesByType>(wall, "relating_type")
+                .count(),
+            0
+        );
+        assert!(ifc.data.references_to(Id(usize::MAX)).is_empty());
+    }
+
+    #[test]
+    fn finds_inverse_attributes() {
+        let ifc = IFC::from_file("resources/AC20-FZK-Haus.ifc").unwrap();
+        let data = &ifc.data;
+
+        let (building, _) = data.find_all_of_type::<Building>().next().unwrap();
+        let (_, aggregation) = data.is_decomposed_by(building).next().unwrap();
+        let storey = aggregation.related_objects.0[0];
+        assert!(data
+            .decomposes(storey)
+            .any(|(_, rel)| rel.relating_object == building.id()));
+
+        let (_, containment) = data.contains_elements(storey).next().unwrap();
+        let element = containment.related_elements.0[0];
+        assert!(data
+            .contained_in_structure(element)
+            .any(|(_, rel)| rel.relating_structure == storey));
+
+        let (wall, (_, voids)) = data
+            .find_all_of_type::<Wall>()
+            .find_map(|(wall, _)| Some((wall, data.has_openings(wall).next()?)))
+            .unwrap();
+        assert_eq!(voids.relating_building_element, wall.id());
+        assert!(data.is_defined_by(wall).next().is_some());
+
+        let filled = data
+            .find_all_of_type::<Window>()
+            .filter(|(window, _)| data.fills_voids(*window).next().is_some())
+            .count();
+        assert!(filled > 0);
+    }
+}
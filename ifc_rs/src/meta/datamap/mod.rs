@@ -1,25 +1,47 @@
 mod deserialize;
+mod inverse;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod references;
+mod renumber;
 mod serialize;
 
-use std::{any::TypeId, collections::BTreeMap};
+#[cfg(not(feature = "rayon"))]
+pub(crate) use deserialize::p_lines_till_eof;
+pub use inverse::InverseReference;
+#[cfg(feature = "rayon")]
+pub(crate) use parallel::p_lines_parallel_till_eof;
+pub use references::{DanglingReference, DanglingReferences};
+
+use std::{
+    any::{Any, TypeId},
+    collections::{btree_map, BTreeMap, BTreeSet, HashMap},
+    sync::OnceLock,
+};
 
 use crate::{
     id::{Id, TypedId},
     prelude::*,
 };
 
-/// CRITICAL: split up the index map into a proper struct with fields which hold Hashmaps mapping
-/// indices to one specific type instead of an enum
+/// All entities of the DATA section mapped by their id.
+///
+/// Additionally the ids are indexed by the type of their entity, so looking up all entities of a
+/// specific type only costs the entities of that type. The inverse references are indexed lazily
+/// on the first lookup, see [`DataMap::references_to`].
 #[derive(Default)]
-pub struct DataMap(pub(crate) BTreeMap<Id, Box<dyn IfcType>>);
+pub struct DataMap {
+    entities: BTreeMap<Id, Box<dyn IfcType>>,
+    types: HashMap<TypeId, BTreeSet<Id>>,
+    inverse: OnceLock<inverse::InverseIndex>,
+}
 
 impl DataMap {
     pub fn insert_new<T: IfcType + 'static>(&mut self, value: T) -> TypedId<T> {
         let new_id = self
-            .0
+            .entities
             .keys()
-            .max()
-            .cloned()
+            .next_back()
             .map(|id| Id(id.0 + 1))
             .unwrap_or(Id(1));
 
@@ -33,7 +55,24 @@ impl DataMap {
         id: impl Into<Id>,
         value: T,
     ) -> Option<Box<dyn IfcType>> {
-        self.0.insert(id.into(), Box::new(value))
+        self.insert_boxed(id.into(), Box::new(value))
+    }
+
+    pub(crate) fn insert_boxed(
+        &mut self,
+        id: Id,
+        value: Box<dyn IfcType>,
+    ) -> Option<Box<dyn IfcType>> {
+        let type_id = type_of(&*value);
+        self.inverse.take();
+
+        let old = self.entities.insert(id, value);
+        if let Some(old) = &old {
+            self.remove_from_index(id, &**old);
+        }
+        self.types.entry(type_id).or_default().insert(id);
+
+        old
     }
 
     pub fn insert_if_not_exists<T: Default + IfcType + 'static>(&mut self, id: impl Into<Id>) {
@@ -44,7 +83,15 @@ impl DataMap {
     }
 
     pub fn remove(&mut self, id: impl Into<Id>) -> Option<Box<dyn IfcType>> {
-        self.0.remove(&id.into())
+        let id = id.into();
+        self.inverse.take();
+
+        let old = self.entities.remove(&id);
+        if let Some(old) = &old {
+            self.remove_from_index(id, &**old);
+        }
+
+        old
     }
 
     pub fn get<T: IfcType>(&self, typed_id: TypedId<T>) -> &T {
@@ -52,39 +99,135 @@ impl DataMap {
     }
 
     pub fn get_untyped(&self, id: impl Into<Id>) -> &dyn IfcType {
-        &**self.0.get(&id.into()).unwrap()
+        &**self.entities.get(&id.into()).unwrap()
+    }
+
+    /// like [`DataMap::get`], but returns `None` if the id doesn't exist or is of another type
+    pub fn try_get<T: IfcType>(&self, typed_id: TypedId<T>) -> Option<&T> {
+        self.try_get_untyped(typed_id.id())?.downcast_ref()
+    }
+
+    /// like [`DataMap::get_untyped`], but returns `None` if the id doesn't exist
+    pub fn try_get_untyped(&self, id: impl Into<Id>) -> Option<&dyn IfcType> {
+        self.entities.get(&id.into()).map(|ifc_type| &**ifc_type)
     }
 
     pub fn get_mut<T: IfcType>(&mut self, typed_id: TypedId<T>) -> &mut T {
-        self.0
+        // the entity might change its references
+        self.inverse.take();
+
+        self.entities
             .get_mut(&typed_id.id())
             .and_then(|any| any.downcast_mut())
             .unwrap()
     }
 
+    /// like [`DataMap::get_mut`] for entities of any type, returns `None` if the id doesn't exist
+    pub fn try_get_untyped_mut(&mut self, id: impl Into<Id>) -> Option<&mut dyn IfcType> {
+        // the entity might change its references
+        self.inverse.take();
+
+        self.entities
+            .get_mut(&id.into())
+            .map(|ifc_type| &mut **ifc_type)
+    }
+
     pub fn contains(&self, id: &Id) -> bool {
-        self.0.contains_key(id)
+        self.entities.contains_key(id)
+    }
+
+    /// number of entities in the map
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// iterates all entities ordered by their id
+    pub fn iter(&self) -> btree_map::Iter<'_, Id, Box<dyn IfcType>> {
+        self.entities.iter()
     }
 
     pub fn find_all_of_type<T: IfcType>(&self) -> impl Iterator<Item = (TypedId<T>, &T)> {
-        self.0
-            .iter()
-            .filter_map(|(id, ifc_type)| ifc_type.downcast_ref().map(|t| (TypedId::new(*id), t)))
+        self.id_of::<T>().map(|id| (id, self.get(id)))
     }
 
     pub fn id_of<T: IfcType>(&self) -> impl Iterator<Item = TypedId<T>> + '_ {
-        self.0
-            .iter()
-            .filter(|&(_, ifc_type)| (ifc_type.type_id() == TypeId::of::<T>()))
-            .map(|(id, _)| TypedId::new(*id))
+        self.types
+            .get(&TypeId::of::<T>())
+            .into_iter()
+            .flatten()
+            .map(|id| TypedId::new(*id))
+    }
+
+    fn remove_from_index(&mut self, id: Id, value: &dyn IfcType) {
+        let type_id = type_of(value);
+
+        if let Some(ids) = self.types.get_mut(&type_id) {
+            ids.remove(&id);
+            if ids.is_empty() {
+                self.types.remove(&type_id);
+            }
+        }
     }
 }
 
+/// type id of the concrete type behind the trait object
+fn type_of(value: &dyn IfcType) -> TypeId {
+    <dyn Any>::type_id(value.as_any())
+}
+
 impl<I> From<I> for DataMap
 where
     I: IntoIterator<Item = (Id, Box<dyn IfcType>)>,
 {
     fn from(value: I) -> Self {
-        Self(value.into_iter().collect())
+        let mut map = Self::default();
+        for (id, value) in value {
+            map.insert_boxed(id, value);
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy_math::DVec3;
+
+    use super::DataMap;
+    use crate::{id::Id, prelude::*};
+
+    #[test]
+    fn type_index_follows_changes() {
+        let mut data = DataMap::default();
+
+        let first = data.insert_new(Point3D::from(DVec3::ZERO));
+        let second = data.insert_new(Point3D::from(DVec3::X));
+        let direction = data.insert_new(Direction3D::from(DVec3::Z));
+
+        assert_eq!(data.id_of::<Point3D>().collect::<Vec<_>>(), [first, second]);
+        assert_eq!(data.id_of::<Direction3D>().collect::<Vec<_>>(), [direction]);
+
+        // replacing an entity moves it to the index of its new type
+        data.insert(second, Direction3D::from(DVec3::Y));
+        assert_eq!(data.id_of::<Point3D>().collect::<Vec<_>>(), [first]);
+        assert_eq!(
+            data.find_all_of_type::<Direction3D>()
+                .map(|(id, _)| id.id())
+                .collect::<Vec<_>>(),
+            [second.id(), direction.id()]
+        );
+
+        // replacing it with the same type keeps it in the index
+        data.insert(first, Point3D::from(DVec3::ONE));
+        assert_eq!(data.id_of::<Point3D>().collect::<Vec<_>>(), [first]);
+
+        data.remove(first);
+        assert_eq!(data.id_of::<Point3D>().count(), 0);
+        assert_eq!(data.len(), 2);
+        assert!(!data.contains(&Id(1)));
     }
 }
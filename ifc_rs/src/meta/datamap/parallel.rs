@@ -0,0 +1,169 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use rayon::prelude::*;
+use winnow::{
+    combinator::{eof, terminated},
+    error::{ContextError, ErrMode},
+    stream::Offset,
+    Parser,
+};
+
+use super::deserialize::p_lines_till_eof;
+use crate::{
+    id::Id,
+    parser::{p_space_or_comment_surrounded, EntityRegistry, IFCParser},
+    prelude::*,
+    reader::{is_keyword, split_statements},
+};
+
+/// Amount of statement text which is parsed as one unit of work. Constructing the entity parsers
+/// isn't free, so single statements would be too small, but the chunks shouldn't be so large that
+/// the threads can't be kept busy.
+const CHUNK_SIZE: usize = 32 * 1024;
+
+/// The parsed entity lines in the order of the input.
+type Entities = Vec<(Id, Box<dyn IfcType>)>;
+
+/// Parses the entity lines of the DATA section including the closing `ENDSEC;` on multiple
+/// threads.
+///
+/// The lines are split up at top-level `;`s first, which is cheap compared to parsing them. The
+/// resulting chunks are independent of each other and are parsed on the rayon thread pool.
+pub(crate) fn p_lines_parallel<'a>(
+    registry: Arc<EntityRegistry>,
+) -> impl IFCParser<'a, BTreeMap<Id, Box<dyn IfcType>>> {
+    terminated(
+        move |input: &mut &'a str| p_chunks_parallel(input, &registry),
+        p_space_or_comment_surrounded("ENDSEC;"),
+    )
+    .map(BTreeMap::from_iter)
+}
+
+/// Parses entity lines until the end of the input on multiple threads, like
+/// [`p_lines_till_eof`]. The entities are returned in the order of the input.
+pub(crate) fn p_lines_parallel_till_eof<'a>(
+    registry: Arc<EntityRegistry>,
+) -> impl IFCParser<'a, Entities> {
+    terminated(
+        move |input: &mut &'a str| p_chunks_parallel(input, &registry),
+        eof,
+    )
+}
+
+fn p_chunks_parallel(
+    input: &mut &str,
+    registry: &Arc<EntityRegistry>,
+) -> Result<Entities, ErrMode<ContextError>> {
+    let data = *input;
+
+    let mut chunks = Vec::new();
+    let mut chunk_start = 0;
+    let mut offset = 0;
+
+    for statement in split_statements(data) {
+        // cheap check first, the keyword parser is only needed to skip comments and whitespace
+        if statement.ends_with("ENDSEC;") && is_keyword(statement, "ENDSEC;") {
+            break;
+        }
+
+        offset += statement.len();
+        if offset - chunk_start >= CHUNK_SIZE {
+            chunks.push(&data[chunk_start..offset]);
+            chunk_start = offset;
+        }
+    }
+    chunks.push(&data[chunk_start..offset]);
+
+    let results: Vec<_> = chunks
+        .par_iter()
+        .map(|chunk| {
+            p_lines_till_eof(registry.clone())
+                .parse(chunk)
+                .map_err(|err| (chunk.offset_from(&data) + err.offset(), err.into_inner()))
+        })
+        .collect();
+
+    let mut entities = Vec::new();
+    for result in results {
+        match result {
+            Ok(lines) => entities.extend(lines),
+            Err((error_offset, error)) => {
+                // point the input to the failure, so the error is reported at the right position
+                *input = &data[error_offset..];
+                return Err(ErrMode::Backtrack(error));
+            }
+        }
+    }
+
+    *input = &data[offset..];
+
+    Ok(entities)
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use winnow::Parser;
+
+    use crate::{
+        id::Id,
+        meta::datamap::deserialize::{p_data_section, p_lines_sequential},
+        parser::{EntityRegistry, IfcParseError},
+        prelude::*,
+    };
+
+    #[test]
+    fn parses_same_entities_as_sequential_parser() {
+        let data = std::fs::read_to_string("resources/AC20-FZK-Haus.ifc").unwrap();
+        let data = &data[data.find("DATA;").unwrap()..data.find("END-ISO").unwrap()];
+
+        let parallel = p_data_section(super::p_lines_parallel(EntityRegistry::global()))
+            .parse(data)
+            .unwrap();
+        let sequential = p_data_section(p_lines_sequential(EntityRegistry::global()))
+            .parse(data)
+            .unwrap();
+
+        assert_eq!(parallel.len(), sequential.len());
+        for ((parallel_id, parallel), (sequential_id, sequential)) in
+            parallel.iter().zip(sequential.iter())
+        {
+            assert_eq!(parallel_id, sequential_id);
+            assert_eq!(parallel.to_string(), sequential.to_string());
+        }
+    }
+
+    #[test]
+    fn reports_first_error_in_file() {
+        let lines = (1..5000)
+            .map(|i| format!("#{i}= IFCCARTESIANPOINT(({i}.,0.,0.));"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let file = format!(
+            "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+{lines}
+#5000 IFCDIRECTION((1.,0.,0.));
+#5001 IFCDIRECTION((1.,0.,0.));
+ENDSEC;
+END-ISO-10303-21;
+"
+        );
+
+        let err = IFC::from_str(&file)
+            .err()
+            .expect("parsing should fail")
+            .downcast::<IfcParseError>()
+            .unwrap();
+
+        assert_eq!(err.line, 5007);
+        assert_eq!(err.column, 7);
+        assert_eq!(err.entity_id, Some(Id(5000)));
+    }
+}
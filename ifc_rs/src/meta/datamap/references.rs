@@ -0,0 +1,138 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use super::DataMap;
+use crate::id::Id;
+
+/// Reference from an entity to an id which doesn't exist in the [`DataMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DanglingReference {
+    /// id of the entity which holds the reference
+    pub entity: Id,
+    /// keyword of the entity which holds the reference, e.g. `IFCWALL`
+    pub keyword: String,
+    /// name of the field which holds the reference
+    pub field: String,
+    /// the id which doesn't exist
+    pub reference: Id,
+}
+
+impl Display for DanglingReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{entity} ({keyword}) references {reference} in field `{field}`, which doesn't exist",
+            entity = self.entity,
+            keyword = self.keyword,
+            reference = self.reference,
+            field = self.field,
+        )
+    }
+}
+
+/// Error which lists all [`DanglingReference`]s of a [`DataMap`], see
+/// [`DataMap::validate_references`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DanglingReferences(pub Vec<DanglingReference>);
+
+impl Display for DanglingReferences {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "found {count} references to missing entities:\n{references}",
+            count = self.0.len(),
+            references = self.0.iter().join("\n")
+        )
+    }
+}
+
+impl std::error::Error for DanglingReferences {}
+
+impl DataMap {
+    /// Checks that every id which is referenced by an entity exists in the map.
+    ///
+    /// Returns all dangling references at once, together with the referring entity and field.
+    pub fn validate_references(&self) -> Result<(), DanglingReferences> {
+        let mut dangling = Vec::new();
+
+        for (id, ifc_type) in self.iter() {
+            ifc_type.visit_ids(&mut |field, reference| {
+                if !self.contains(&reference) {
+                    dangling.push(DanglingReference {
+                        entity: *id,
+                        keyword: ifc_type.keyword().to_owned(),
+                        field: field.to_owned(),
+                        reference,
+                    });
+                }
+            });
+        }
+
+        if dangling.is_empty() {
+            Ok(())
+        } else {
+            Err(DanglingReferences(dangling))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{DanglingReference, DanglingReferences};
+    use crate::{id::Id, prelude::*};
+
+    #[test]
+    fn reports_dangling_references() {
+        let file = "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+#1= IFCCARTESIANPOINT((0.,0.,0.));
+#2= IFCAXIS2PLACEMENT3D(#1,#5,#3);
+#3= IFCPOLYLOOP((#1,#4));
+ENDSEC;
+END-ISO-10303-21;
+";
+
+        let err = IFC::from_str(file)
+            .err()
+            .expect("dangling references should fail")
+            .downcast::<DanglingReferences>()
+            .unwrap();
+
+        assert_eq!(
+            err.0,
+            [
+                DanglingReference {
+                    entity: Id(2),
+                    keyword: "IFCAXIS2PLACEMENT3D".to_owned(),
+                    field: "local_z".to_owned(),
+                    reference: Id(5),
+                },
+                DanglingReference {
+                    entity: Id(3),
+                    keyword: "IFCPOLYLOOP".to_owned(),
+                    field: "args".to_owned(),
+                    reference: Id(4),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn resources_have_no_dangling_references() {
+        for file in [
+            "resources/wall-standard-case.ifc",
+            "resources/AC20-FZK-Haus.ifc",
+        ] {
+            let ifc = IFC::from_file(file).unwrap();
+            assert_eq!(ifc.data.validate_references(), Ok(()), "{file}");
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::{DanglingReferences, DataMap};
+use crate::{id::Id, traits::references::References};
+
+impl DataMap {
+    /// Assigns contiguous ids starting at `#1` to all entities, so that every entity comes after
+    /// the entities it references. References in all entities are rewritten accordingly.
+    ///
+    /// Entities which are part of a reference cycle are ordered by their old ids.
+    ///
+    /// Returns the mapping of the old ids to the new ones. Fails without changing anything if an
+    /// entity references an id which doesn't exist, e.g. in files read with
+    /// [`IFC::from_str_lenient`](crate::IFC::from_str_lenient), since the new id of the
+    /// reference could belong to another entity.
+    pub fn renumber(&mut self) -> Result<BTreeMap<Id, Id>, DanglingReferences> {
+        self.validate_references()?;
+
+        let order = self.dependency_order();
+
+        let mapping: BTreeMap<Id, Id> = order
+            .into_iter()
+            .enumerate()
+            .map(|(index, old)| (old, Id(index + 1)))
+            .collect();
+
+        let entities = std::mem::take(self).entities;
+        *self = DataMap::from(entities.into_iter().map(|(old, mut entity)| {
+            entity.remap_ids(|id| mapping[&id]);
+
+            (mapping[&old], entity)
+        }));
+
+        Ok(mapping)
+    }
+
+    /// All ids in depth first post order, so referenced entities come before the ones
+    /// referencing them.
+    fn dependency_order(&self) -> Vec<Id> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            Visiting,
+            Done,
+        }
+
+        let mut states = HashMap::with_capacity(self.len());
+        let mut order = Vec::with_capacity(self.len());
+
+        // explicit stack, the reference chains of big models are too deep for recursion
+        let mut stack: Vec<(Id, Vec<Id>)> = Vec::new();
+
+        for &root in self.entities.keys() {
+            if states.contains_key(&root) {
+                continue;
+            }
+
+            states.insert(root, State::Visiting);
+            stack.push((root, self.references_of(root)));
+
+            while let Some((id, references)) = stack.last_mut() {
+                match references.pop() {
+                    Some(reference) => {
+                        if self.contains(&reference) && !states.contains_key(&reference) {
+                            states.insert(reference, State::Visiting);
+                            let references = self.references_of(reference);
+                            stack.push((reference, references));
+                        }
+                    }
+                    None => {
+                        states.insert(*id, State::Done);
+                        order.push(*id);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        debug_assert!(states.values().all(|state| *state == State::Done));
+
+        order
+    }
+
+    /// ids referenced by the entity in reverse order, so popping them yields the original order
+    fn references_of(&self, id: Id) -> Vec<Id> {
+        let mut references: Vec<_> = self.get_untyped(id).referenced_ids().collect();
+        references.reverse();
+
+        references
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use bevy_math::DVec3;
+
+    use crate::{id::Id, prelude::*};
+
+    #[test]
+    fn closes_gaps_and_rewrites_references() {
+        let mut ifc = IFC::default();
+
+        let unused = ifc.data.insert_new(Point3D::from(DVec3::X));
+        let axis = Axis3D::new(Point3D::from(DVec3::ZERO), &mut ifc);
+        let origin = axis.location;
+        let axis = ifc.data.insert_new(axis);
+        ifc.data.remove(unused);
+
+        // referencing before referenced
+        ifc.data.insert(
+            Id(10),
+            UnknownEntity::new(
+                "IFCPOLYLOOP",
+                vec![StepValue::List(vec![StepValue::Id(Id(20))])],
+            ),
+        );
+        ifc.data.insert(Id(20), Point3D::from(DVec3::Y));
+
+        let mapping = ifc.data.renumber().unwrap();
+
+        assert_eq!(
+            mapping,
+            [
+                (origin.id(), Id(1)),
+                (axis.id(), Id(2)),
+                (Id(10), Id(4)),
+                (Id(20), Id(3)),
+            ]
+            .into()
+        );
+
+        assert_eq!(
+            ifc.data.get_untyped(Id(2)).to_string(),
+            "IFCAXIS2PLACEMENT3D(#1,$,$);"
+        );
+        assert_eq!(
+            ifc.data.get_untyped(Id(4)).to_string(),
+            "IFCPOLYLOOP((#3));"
+        );
+        assert_eq!(ifc.data.find_all_of_type::<Point3D>().count(), 2);
+    }
+
+    #[test]
+    fn dangling_references_fail() {
+        let file = "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+#1= IFCCARTESIANPOINT((0.,0.,0.));
+#5= IFCCARTESIANPOINT((1.,0.,0.));
+#6= IFCAXIS2PLACEMENT3D(#5,#2,$);
+ENDSEC;
+END-ISO-10303-21;
+";
+        let (mut ifc, _) = IFC::from_str_lenient(file).unwrap();
+        let written = ifc.to_string();
+
+        // #2 would become the id of the point #5
+        let err = ifc.data.renumber().unwrap_err();
+        assert_eq!(err.0.len(), 1);
+        assert_eq!(err.0[0].entity, Id(6));
+        assert_eq!(err.0[0].reference, Id(2));
+
+        assert_eq!(ifc.to_string(), written);
+    }
+
+    #[test]
+    fn rewrites_references_in_dummies() {
+        let mut dummy = Dummy {
+            s: "IFCSOMETHING(#5,'#5',(#50))".into(),
+            diagnostic: None,
+        };
+        dummy.visit_ids_mut(&mut |_, id| id.0 += 1);
+
+        assert_eq!(dummy.s, "IFCSOMETHING(#6,'#5',(#51))");
+    }
+
+    #[test]
+    fn renumbered_file_stays_valid() {
+        let mut ifc = IFC::from_file("resources/AC20-FZK-Haus.ifc").unwrap();
+        let count = ifc.data.len();
+
+        let mapping = ifc.data.renumber().unwrap();
+        assert_eq!(mapping.len(), count);
+        assert_eq!(
+            ifc.data.iter().next_back().map(|(id, _)| *id),
+            Some(Id(count))
+        );
+
+        for (id, entity) in ifc.data.iter() {
+            entity.visit_ids(&mut |_, reference| assert!(reference < *id));
+        }
+
+        let reparsed = IFC::from_str(&ifc.to_string()).unwrap();
+        assert_eq!(reparsed.to_string(), ifc.to_string());
+    }
+}
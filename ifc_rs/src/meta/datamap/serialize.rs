@@ -1,16 +1,92 @@
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    io::{self, Write},
+};
+
+use crate::{
+    meta::header::schema::FileSchema,
+    parser::{
+        layouts::{self, InSchema},
+        unknown_entity::UnknownEntity,
+    },
+    prelude::*,
+};
 
 use super::DataMap;
 
-impl Display for DataMap {
+impl DataMap {
+    /// writes the DATA section entity by entity, see [`crate::IFC::write_to`]
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        self.write_in_schema(writer, None, RealFormat::Shortest)
+    }
+
+    /// Writes the DATA section with the attribute layouts of `schema`. The types of this crate
+    /// use the IFC4 layout, so e.g. walls of IFC2X3 files are written without `PredefinedType`.
+    pub(crate) fn write_in_schema(
+        &self,
+        writer: &mut impl Write,
+        schema: Option<FileSchema>,
+        real_format: RealFormat,
+    ) -> io::Result<()> {
+        writeln!(writer, "DATA;")?;
+
+        for (id, entity) in self.iter() {
+            match with_real_format(&**entity, schema, real_format) {
+                Some(entity) => writeln!(writer, "{id}= {entity}")?,
+                None => writeln!(writer, "{id}= {}", InSchema(&**entity, schema))?,
+            }
+        }
+
+        writeln!(writer, "ENDSEC;")
+    }
+
+    /// DATA section with the attribute layouts of `schema`, see [`DataMap::write_in_schema`]
+    pub(crate) fn in_schema(&self, schema: Option<FileSchema>) -> impl Display + '_ {
+        DataInSchema(self, schema)
+    }
+}
+
+/// The entity in the attribute layout of `schema` with its reals in `real_format`. `None` if the
+/// reals are written in the shortest form anyway or if the attributes of the entity can't be read,
+/// like the ones of [`Dummy`] entities, which are written as they are.
+fn with_real_format(
+    entity: &dyn IfcType,
+    schema: Option<FileSchema>,
+    real_format: RealFormat,
+) -> Option<UnknownEntity> {
+    if real_format == RealFormat::Shortest {
+        return None;
+    }
+
+    let mut entity = match schema.and_then(|schema| layouts::in_schema(entity, schema)) {
+        Some(entity) => entity,
+        None => UnknownEntity::from_entity(entity).ok()?,
+    };
+    entity
+        .args
+        .iter_mut()
+        .for_each(|value| real_format.reformat(value));
+
+    Some(entity)
+}
+
+struct DataInSchema<'a>(&'a DataMap, Option<FileSchema>);
+
+impl Display for DataInSchema<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "DATA;")?;
 
-        for (id_str, obj_str) in self.0.iter() {
-            writeln!(f, "{id}= {obj}", id = id_str, obj = obj_str)?;
+        for (id, entity) in self.0.iter() {
+            writeln!(f, "{id}= {}", InSchema(&**entity, self.1))?;
         }
 
         writeln!(f, "ENDSEC;")?;
         Ok(())
     }
 }
+
+impl Display for DataMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.in_schema(None))
+    }
+}
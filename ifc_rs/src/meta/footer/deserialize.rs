@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use strum::VariantNames;
 use winnow::combinator::{alt, delimited, preceded};
+use winnow::error::{StrContext, StrContextValue};
 use winnow::prelude::*;
 
 use crate::meta::footer::Footer;
@@ -13,7 +14,8 @@ impl IFCParse for Footer {
         winnow::seq! {
             Footer {
                 _: p_space_or_comment(),
-                version: p_footer_version(),
+                version: p_footer_version()
+                    .context(StrContext::Expected(StrContextValue::StringLiteral("END-ISO-10303-21;"))),
                 _: p_space_or_comment(),
             }
         }
@@ -2,9 +2,10 @@ use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
 use strum::VariantNames;
-use winnow::combinator::{alt, delimited, preceded, repeat, separated};
+use winnow::combinator::{alt, delimited, opt, preceded, repeat, separated, terminated};
+use winnow::error::{ContextError, ErrMode, StrContext, StrContextValue};
 use winnow::prelude::*;
-use winnow::token::none_of;
+use winnow::token::{none_of, one_of, take_while};
 
 use super::description::{FileDescription, ImplementationLevel, ViewDefinition};
 use super::details::{
@@ -12,6 +13,7 @@ use super::details::{
     PreprocessorVersion, TimeStamp,
 };
 use super::details::{FileDetails, FileName};
+use super::entity::{FilePopulation, HeaderEntity, SectionContext, SectionLanguage};
 use super::schema::{FileSchema, FileSchemas};
 use super::version::Version;
 use super::Header;
@@ -23,14 +25,18 @@ impl IFCParse for Header {
         winnow::seq! {
             Header {
                 _: p_space_or_comment(),
-                version: Self::p_version(),
-                _: p_space_or_comment_surrounded("HEADER;"),
-                description: Self::p_description(),
+                version: Self::p_version()
+                    .context(StrContext::Expected(StrContextValue::StringLiteral("ISO-10303-21;"))),
+                _: p_space_or_comment_surrounded("HEADER;")
+                    .context(StrContext::Expected(StrContextValue::StringLiteral("HEADER;"))),
+                description: Self::p_description().context(StrContext::Label("FILE_DESCRIPTION")),
                 _: p_space_or_comment(),
-                name: Self::p_name(),
+                name: Self::p_name().context(StrContext::Label("FILE_NAME")),
                 _: p_space_or_comment(),
-                schema: Self::p_schema(),
-                _: p_space_or_comment_surrounded("ENDSEC;"),
+                schema: Self::p_schema().context(StrContext::Label("FILE_SCHEMA")),
+                entities: repeat(.., Self::p_entity()),
+                _: p_space_or_comment_surrounded("ENDSEC;")
+                    .context(StrContext::Expected(StrContextValue::StringLiteral("ENDSEC;"))),
             }
         }
     }
@@ -70,7 +76,7 @@ impl Header {
         delimited("(", alt((p_no_items, p_any_items)), ")")
     }
 
-    fn p_view_definition<'a>() -> impl IFCParser<'a, ViewDefinition> {
+    pub(crate) fn p_view_definition<'a>() -> impl IFCParser<'a, ViewDefinition> {
         // view definition value can be anything, examples:
         //
         // "Drawing Scale: 100.000000"
@@ -198,7 +204,102 @@ impl Header {
             alt(("4x3", "4X3")).value(FileSchema::IFC4X3_ADD2),
             "4".value(FileSchema::IFC4),
         ));
-        preceded(p_prefix_any_case, p_version_any_case)
+        preceded(p_prefix_any_case, p_version_any_case).context(StrContext::Expected(
+            StrContextValue::Description("one of `IFC2X3`, `IFC4` or `IFC4X3_ADD2`"),
+        ))
+    }
+
+    fn p_entity<'a>() -> impl IFCParser<'a, HeaderEntity> {
+        let p_end = || p_space_or_comment_surrounded((")", p_space_or_comment(), ";"));
+
+        let p_population = winnow::seq! {
+            FilePopulation {
+                _: p_space_or_comment_surrounded(("FILE_POPULATION", p_space_or_comment(), "(")),
+                governing_schema: p_quote_word(),
+                _: Comma::parse(),
+                determination_method: p_quote_word(),
+                _: Comma::parse(),
+                governed_sections: Self::p_optional(Self::p_string_list()),
+                _: p_end(),
+            }
+        };
+
+        let p_language = winnow::seq! {
+            SectionLanguage {
+                _: p_space_or_comment_surrounded(("SECTION_LANGUAGE", p_space_or_comment(), "(")),
+                section: Self::p_optional(p_quote_word()),
+                _: Comma::parse(),
+                default_language: p_quote_word(),
+                _: p_end(),
+            }
+        };
+
+        let p_context = winnow::seq! {
+            SectionContext {
+                _: p_space_or_comment_surrounded(("SECTION_CONTEXT", p_space_or_comment(), "(")),
+                section: Self::p_optional(p_quote_word()),
+                _: Comma::parse(),
+                context_identifiers: Self::p_string_list(),
+                _: p_end(),
+            }
+        };
+
+        // user defined keywords start with `!`
+        let p_keyword = (
+            opt("!"),
+            one_of(|c: char| c.is_ascii_alphabetic()),
+            take_while(.., |c: char| c.is_ascii_alphanumeric() || c == '_'),
+        )
+            .take();
+        let p_other = delimited(
+            p_space_or_comment(),
+            (
+                terminated(p_keyword, p_space_or_comment()),
+                delimited("(", Self::p_parameters(), ")"),
+            ),
+            p_space_or_comment_surrounded(";"),
+        )
+        .map(|(keyword, parameters): (&str, &str)| HeaderEntity::Other {
+            keyword: keyword.to_owned(),
+            parameters: parameters.to_owned(),
+        });
+
+        alt((
+            p_population.map(HeaderEntity::FilePopulation),
+            p_language.map(HeaderEntity::SectionLanguage),
+            p_context.map(HeaderEntity::SectionContext),
+            p_other,
+        ))
+    }
+
+    /// parameters of an entity as they are written, including nested lists and strings
+    fn p_parameters<'a>() -> impl IFCParser<'a, &'a str> {
+        fn p_parameters<'a>(input: &mut &'a str) -> Result<&'a str, ErrMode<ContextError>> {
+            repeat::<_, _, (), _, _>(
+                ..,
+                alt((
+                    p_quote_word().void(),
+                    delimited("(", p_parameters, ")").void(),
+                    take_while(1.., |c: char| !['(', ')', '\''].contains(&c)).void(),
+                )),
+            )
+            .take()
+            .parse_next(input)
+        }
+
+        p_parameters
+    }
+
+    fn p_string_list<'a>() -> impl IFCParser<'a, Vec<String>> {
+        delimited(
+            "(",
+            separated(.., p_space_or_comment_surrounded(p_quote_word()), ","),
+            ")",
+        )
+    }
+
+    fn p_optional<'a, T>(p: impl IFCParser<'a, T>) -> impl IFCParser<'a, Option<T>> {
+        alt(("$".map(|_| None), p.map(Some)))
     }
 }
 
@@ -12,6 +12,10 @@ pub struct Header {
     pub description: description::FileDescription,
     pub name: details::FileDetails,
     pub schema: schema::FileSchemas,
+    /// optional and vendor specific header entities which follow `FILE_SCHEMA`, in the order in
+    /// which they were written
+    #[serde(default)]
+    pub entities: Vec<entity::HeaderEntity>,
 }
 
 impl IfcVerify for Header {}
@@ -19,8 +23,10 @@ impl IfcType for Header {}
 
 pub(crate) mod description {
 
+    use std::fmt::Display;
+
     use serde::{Deserialize, Serialize};
-    use strum::{Display, EnumString, VariantNames};
+    use strum::{EnumString, VariantNames};
 
     #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
     pub struct FileDescription {
@@ -34,12 +40,120 @@ pub(crate) mod description {
         pub items: Vec<String>,
     }
 
+    impl FileDescription {
+        /// Decodes the `ViewDefinition [...]` entries into model views, all other entries like
+        /// `ExchangeRequirement [...]` or `Option [...]` are kept as options.
+        pub fn model_view_definition(&self) -> ModelViewDefinition {
+            let (views, options): (Vec<_>, Vec<_>) = self
+                .descriptions
+                .iter()
+                .partition(|description| description.name == "ViewDefinition");
+
+            ModelViewDefinition {
+                views: views
+                    .into_iter()
+                    .flat_map(|view| view.items.iter())
+                    .map(|item| ModelView::from(item.as_str()))
+                    .collect(),
+                options: options.into_iter().cloned().collect(),
+            }
+        }
+    }
+
     #[derive(
-        Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString, VariantNames,
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Serialize,
+        Deserialize,
+        strum::Display,
+        EnumString,
+        VariantNames,
     )]
     pub enum ImplementationLevel {
+        /// edition 2 of ISO 10303-21 with conformance class 1
         #[strum(to_string = "2;1")]
         _2_1,
+        /// edition 2 of ISO 10303-21 with conformance class 2, which allows external mappings
+        #[strum(to_string = "2;2")]
+        _2_2,
+    }
+
+    /// Model view definitions and options declared in the [`FileDescription`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ModelViewDefinition {
+        /// views listed in `ViewDefinition [...]`, e.g. a main view and its add-on views
+        pub views: Vec<ModelView>,
+        /// all other entries, e.g. `ExchangeRequirement [Architecture]`
+        pub options: Vec<ViewDefinition>,
+    }
+
+    impl ModelViewDefinition {
+        /// whether the file declares the given view
+        pub fn contains(&self, view: &ModelView) -> bool {
+            self.views.contains(view)
+        }
+
+        /// items of all options with the given name, e.g. `Option` for `Option [...]` entries
+        pub fn option<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+            self.options
+                .iter()
+                .filter(move |option| option.name == name)
+                .flat_map(|option| option.items.iter().map(String::as_str))
+        }
+
+        /// items of the `ExchangeRequirement [...]` entries
+        pub fn exchange_requirements(&self) -> impl Iterator<Item = &str> + '_ {
+            self.option("ExchangeRequirement")
+        }
+    }
+
+    /// Model view definition (MVD) of buildingSMART, which defines the subset of the schema a
+    /// file was exported for.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub enum ModelView {
+        /// `CoordinationView` of IFC2X3 in version 1.0
+        CoordinationView,
+        /// `CoordinationView_V2.0` of IFC2X3
+        CoordinationView2,
+        /// `ReferenceView` of IFC4 and IFC4X3, e.g. `ReferenceView_V1.2`
+        ReferenceView,
+        /// `DesignTransferView` of IFC4, e.g. `DesignTransferView_V1.0`
+        DesignTransferView,
+        /// any other view, e.g. add-on views like `QuantityTakeOffAddOnView`
+        Other(String),
+    }
+
+    impl From<&str> for ModelView {
+        fn from(value: &str) -> Self {
+            let lowercase = value.to_lowercase();
+
+            if lowercase.starts_with("coordinationview_v2") {
+                Self::CoordinationView2
+            } else if lowercase.starts_with("coordinationview") {
+                Self::CoordinationView
+            } else if lowercase.starts_with("referenceview") {
+                Self::ReferenceView
+            } else if lowercase.starts_with("designtransferview") {
+                Self::DesignTransferView
+            } else {
+                Self::Other(value.to_owned())
+            }
+        }
+    }
+
+    impl Display for ModelView {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                ModelView::CoordinationView => write!(f, "CoordinationView"),
+                ModelView::CoordinationView2 => write!(f, "CoordinationView_V2.0"),
+                ModelView::ReferenceView => write!(f, "ReferenceView"),
+                ModelView::DesignTransferView => write!(f, "DesignTransferView"),
+                ModelView::Other(view) => write!(f, "{view}"),
+            }
+        }
     }
 }
 
@@ -146,7 +260,17 @@ pub(crate) mod schema {
     ///
     /// after which I adjusted this enum here
     #[derive(
-        Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString, VariantNames,
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Hash,
+        Serialize,
+        Deserialize,
+        Display,
+        EnumString,
+        VariantNames,
     )]
     pub enum FileSchema {
         IFC4X3_ADD2,
@@ -155,19 +279,66 @@ pub(crate) mod schema {
     }
 }
 
+pub(crate) mod entity {
+    use serde::{Deserialize, Serialize};
+
+    /// Header entity of ISO 10303-21 besides `FILE_DESCRIPTION`, `FILE_NAME` and `FILE_SCHEMA`.
+    ///
+    /// Strings are kept STEP encoded, exactly like in the other header entities.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum HeaderEntity {
+        FilePopulation(FilePopulation),
+        SectionLanguage(SectionLanguage),
+        SectionContext(SectionContext),
+        /// any other entity, e.g. a vendor specific one like `!VENDOR_INFO('...');`
+        Other {
+            keyword: String,
+            /// parameters exactly as they were written, without the surrounding parentheses
+            parameters: String,
+        },
+    }
+
+    /// `FILE_POPULATION`, describes which entity instances of a schema are part of the file
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct FilePopulation {
+        pub governing_schema: String,
+        pub determination_method: String,
+        /// `None` if all data sections are governed
+        pub governed_sections: Option<Vec<String>>,
+    }
+
+    /// `SECTION_LANGUAGE`, default language of the strings in a data section
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct SectionLanguage {
+        /// `None` for the default of all data sections
+        pub section: Option<String>,
+        pub default_language: String,
+    }
+
+    /// `SECTION_CONTEXT`, context in which the instances of a data section are interpreted
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct SectionContext {
+        /// `None` for the context of all data sections
+        pub section: Option<String>,
+        pub context_identifiers: Vec<String>,
+    }
+}
+
 #[cfg(test)]
 mod header_tests {
 
     use chrono::DateTime;
+    use winnow::Parser;
 
-    use crate::meta::version::Version;
+    use crate::{meta::version::Version, parser::IFCParse};
 
     use super::{
-        description::{FileDescription, ImplementationLevel, ViewDefinition},
+        description::{FileDescription, ImplementationLevel, ModelView, ViewDefinition},
         details::{
             Author, AuthorList, Authorization, FileDetails, FileName, Organization,
             OrganizationList, OriginatingSystem, PreprocessorVersion, TimeStamp,
         },
+        entity::{FilePopulation, HeaderEntity, SectionContext, SectionLanguage},
         schema::{FileSchema, FileSchemas},
         Header,
     };
@@ -204,6 +375,7 @@ mod header_tests {
             description: example_description(),
             name: example_filedetails(),
             schema: example_schema(),
+            entities: Vec::new(),
         }
     }
 
@@ -214,4 +386,111 @@ mod header_tests {
         let header_again: Header = serde_json::from_str(&header_str).unwrap();
         assert_eq!(header, header_again);
     }
+
+    #[test]
+    fn parses_all_header_entities() {
+        let data = "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [ReferenceView_V1.2]'),'2;2');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+FILE_POPULATION('IFC4','ALL',$);
+SECTION_LANGUAGE($,'en');
+SECTION_CONTEXT('main',('building','site'));
+!VENDOR_EXPORT('Exporter', ( 1, 'a)b' ),.T.);
+ENDSEC;
+";
+
+        let header = Header::parse().parse(data).unwrap();
+
+        assert_eq!(
+            header.description.implementation_level,
+            ImplementationLevel::_2_2
+        );
+        assert_eq!(
+            header.entities,
+            [
+                HeaderEntity::FilePopulation(FilePopulation {
+                    governing_schema: "IFC4".to_owned(),
+                    determination_method: "ALL".to_owned(),
+                    governed_sections: None,
+                }),
+                HeaderEntity::SectionLanguage(SectionLanguage {
+                    section: None,
+                    default_language: "en".to_owned(),
+                }),
+                HeaderEntity::SectionContext(SectionContext {
+                    section: Some("main".to_owned()),
+                    context_identifiers: vec!["building".to_owned(), "site".to_owned()],
+                }),
+                HeaderEntity::Other {
+                    keyword: "!VENDOR_EXPORT".to_owned(),
+                    parameters: "'Exporter', ( 1, 'a)b' ),.T.".to_owned(),
+                },
+            ]
+        );
+
+        assert_eq!(header.to_string(), data);
+    }
+
+    #[test]
+    fn decodes_model_view_definitions() {
+        let description = FileDescription {
+            descriptions: vec![
+                ViewDefinition {
+                    name: "ViewDefinition".to_owned(),
+                    items: vec![
+                        "CoordinationView_V2.0".to_owned(),
+                        "QuantityTakeOffAddOnView".to_owned(),
+                    ],
+                },
+                ViewDefinition {
+                    name: "ExchangeRequirement".to_owned(),
+                    items: vec!["Architecture".to_owned(), "Structural".to_owned()],
+                },
+                ViewDefinition {
+                    name: "Option".to_owned(),
+                    items: vec!["Drawing Scale: 100.000000".to_owned()],
+                },
+            ],
+            implementation_level: ImplementationLevel::_2_1,
+        };
+
+        let mvd = description.model_view_definition();
+
+        assert_eq!(
+            mvd.views,
+            [
+                ModelView::CoordinationView2,
+                ModelView::Other("QuantityTakeOffAddOnView".to_owned())
+            ]
+        );
+        assert!(mvd.contains(&ModelView::CoordinationView2));
+        assert!(!mvd.contains(&ModelView::ReferenceView));
+        assert_eq!(
+            mvd.exchange_requirements().collect::<Vec<_>>(),
+            ["Architecture", "Structural"]
+        );
+        assert_eq!(
+            mvd.option("Option").collect::<Vec<_>>(),
+            ["Drawing Scale: 100.000000"]
+        );
+
+        assert_eq!(
+            ModelView::from("ReferenceView_V1.2"),
+            ModelView::ReferenceView
+        );
+        assert_eq!(
+            ModelView::from("DesignTransferView_V1.0"),
+            ModelView::DesignTransferView
+        );
+        assert_eq!(
+            ModelView::from("CoordinationView"),
+            ModelView::CoordinationView
+        );
+        assert_eq!(
+            ModelView::CoordinationView2.to_string(),
+            "CoordinationView_V2.0"
+        );
+    }
 }
@@ -5,6 +5,7 @@ use itertools::Itertools;
 use super::{
     description::{FileDescription, ViewDefinition},
     details::FileDetails,
+    entity::HeaderEntity,
     schema::FileSchemas,
     Header,
 };
@@ -16,6 +17,9 @@ impl Display for Header {
         writeln!(f, "FILE_DESCRIPTION({desc});", desc = self.description)?;
         writeln!(f, "FILE_NAME({name});", name = self.name)?;
         writeln!(f, "FILE_SCHEMA({schema});", schema = self.schema)?;
+        for entity in &self.entities {
+            writeln!(f, "{entity}")?;
+        }
         writeln!(f, "ENDSEC;")?;
         Ok(())
     }
@@ -100,3 +104,41 @@ impl Display for FileSchemas {
         )
     }
 }
+
+impl Display for HeaderEntity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn optional(value: Option<String>) -> String {
+            value.unwrap_or_else(|| "$".to_string())
+        }
+
+        fn strings(strings: &[String]) -> String {
+            format!("({})", strings.iter().map(|s| format!("'{s}'")).join(","))
+        }
+
+        match self {
+            HeaderEntity::FilePopulation(population) => write!(
+                f,
+                "FILE_POPULATION('{schema}','{method}',{sections});",
+                schema = population.governing_schema,
+                method = population.determination_method,
+                sections = optional(population.governed_sections.as_deref().map(strings)),
+            ),
+            HeaderEntity::SectionLanguage(language) => write!(
+                f,
+                "SECTION_LANGUAGE({section},'{language}');",
+                section = optional(language.section.as_ref().map(|s| format!("'{s}'"))),
+                language = language.default_language,
+            ),
+            HeaderEntity::SectionContext(context) => write!(
+                f,
+                "SECTION_CONTEXT({section},{identifiers});",
+                section = optional(context.section.as_ref().map(|s| format!("'{s}'"))),
+                identifiers = strings(&context.context_identifiers),
+            ),
+            HeaderEntity::Other {
+                keyword,
+                parameters,
+            } => write!(f, "{keyword}({parameters});"),
+        }
+    }
+}
@@ -50,3 +50,5 @@ impl IFCParse for AccessState {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(AccessState);
@@ -17,6 +17,7 @@ use winnow::Parser;
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcactorrole.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcActorRole")]
 pub struct ActorRole {
     /// The name of the role played by an actor. If the Role has value USERDEFINED, then
     /// the user defined role shall be provided as a value of the attribute UserDefinedRole.
@@ -99,3 +100,5 @@ impl IFCParse for Role {
 }
 
 impl IfcType for ActorRole {}
+
+crate::traits::reflect::step_enumeration!(Role);
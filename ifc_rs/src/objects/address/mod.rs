@@ -13,6 +13,7 @@ use crate::prelude::*;
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifctelecomaddress.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcTelecomAddress")]
 pub struct TelecomAddress {
     // First three attributes from IfcAddress:
     //
@@ -33,9 +34,11 @@ pub struct TelecomAddress {
     /// The pager number at which paging messages may be received.
     pub pager_number: OptionalParameter<StringPrimitive>,
     /// The list of Email addresses at which Email messages may be received.
+    #[ifc(name = "ElectronicMailAddresses")]
     pub email_addresses: OptionalParameter<IfcList<StringPrimitive>>,
     /// The world wide web address at which the preliminary page of information
     /// for the person or organization can be located.
+    #[ifc(name = "WWWHomePageURL")]
     pub homepage_url: OptionalParameter<StringPrimitive>,
 }
 
@@ -43,9 +46,9 @@ impl IfcType for TelecomAddress {}
 impl Address for TelecomAddress {}
 
 /// The address for delivery of paper based mail.
-
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcpostaladdress.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcPostalAddress")]
 pub struct PostalAddress {
     // First three attributes from IfcAddress:
     //
@@ -11,6 +11,7 @@ use crate::prelude::*;
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcutilityresource/lexical/ifcapplication.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcApplication")]
 pub struct Application {
     /// Name of the application developer, being requested to be member
     /// of buildingSMART.
@@ -21,6 +21,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbuilding.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcBuilding")]
 pub struct Building {
     #[inherited]
     spatial_element_structure: SpatialStructureElement,
@@ -58,3 +58,5 @@ impl IFCParse for ChangeAction {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(ChangeAction);
@@ -1,9 +1,6 @@
-mod deserialize;
-mod serialize;
-
 use std::ops::{Deref, DerefMut};
 
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 
 use super::{
     shared::{
@@ -95,7 +92,8 @@ use crate::{
 /// IfcShapeRepresentation with the RepresentationIdentifier = 'Profile'.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/schema/ifcsharedbldgelements/lexical/ifcdoor.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcDoor", alias = "IfcDoorStandardCase")]
 pub struct Door {
     #[inherited]
     element: Element,
@@ -123,6 +121,7 @@ pub struct Door {
 
     /// Designator for the user defined operation type, shall only be
     /// provided, if the value of operation_type is set to USERDEFINED.
+    #[ifc(name = "UserDefinedOperationType")]
     pub user_defining_operation_type: OptionalParameter<StringPrimitive>,
 }
 
@@ -214,3 +213,18 @@ impl TransformableType for Door {
         self.representation.custom().cloned()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Door;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn parse_door_works() {
+        let data = "IFCDOOR('2jTRqchjf7oB0yhQ6462T0',#12,'Haustuer',$,$,#24218,#27009,'3CAFB746-2204-4C6C-BF-ED-F5FE276FA162',2.,1.,$,$,$);";
+        let parsed = Door::parse().parse(data).unwrap();
+        assert_eq!(data, parsed.to_string());
+    }
+}
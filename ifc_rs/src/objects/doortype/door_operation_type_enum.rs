@@ -130,3 +130,5 @@ impl IFCParse for DoorOperationTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(DoorOperationTypeEnum);
@@ -50,3 +50,5 @@ impl IFCParse for DoorTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(DoorTypeEnum);
@@ -36,6 +36,7 @@ mod serialize;
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/schema/ifcsharedbldgelements/lexical/ifcdoortype.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcDoorType")]
 pub struct DoorType {
     #[inherited]
     element_type: ElementType,
@@ -57,6 +58,7 @@ pub struct DoorType {
 
     /// Designator for the user defined partitioning type, shall only be
     /// provided, if the value of PartitioningType is set to USERDEFINED.
+    #[ifc(name = "UserDefinedOperationType")]
     pub user_defined_partitioning_type: OptionalParameter<StringPrimitive>,
 }
 
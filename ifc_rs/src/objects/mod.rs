@@ -1,15 +1,10 @@
+use crate::{parser::EntityRegistry, prelude::*};
 use door::Door;
 use roof::Roof;
 use shading_device::ShadingDevice;
 use slab::Slab;
 use wall::Wall;
 use window::Window;
-use winnow::combinator::alt;
-
-use crate::{
-    parser::{IFCParse, IFCParser},
-    prelude::*,
-};
 
 pub(crate) mod access_state;
 pub(crate) mod actor_role;
@@ -55,37 +50,37 @@ pub enum StructureType<'a> {
 pub struct Objects;
 
 impl Objects {
-    pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
-        alt((
-            actor_role::ActorRole::parse_any(),
-            address::PostalAddress::parse_any(),
-            address::TelecomAddress::parse_any(),
-            application::Application::parse_any(),
-            organization::Organization::parse_any(),
-            owner_history::OwnerHistory::parse_any(),
-            person::Person::parse_any(),
-            person_and_org::PersonAndOrganization::parse_any(),
-            wall::Wall::parse_any(),
-            walltype::WallType::parse_any(),
-            slab::Slab::parse_any(),
-            slabtype::SlabType::parse_any(),
-            roof::Roof::parse_any(),
-            rooftype::RoofType::parse_any(),
-            building::Building::parse_any(),
-            storey::Storey::parse_any(),
-            site::Site::parse_any(),
-            opening_element::OpeningElement::parse_any(),
-            window::Window::parse_any(),
-            windowtype::WindowType::parse_any(),
-            alt((
-                project::Project::parse_any(),
-                space::Space::parse_any(),
-                spacetype::SpaceType::parse_any(),
-                door::Door::parse_any(),
-                doortype::DoorType::parse_any(),
-                shading_device::ShadingDevice::parse_any(),
-                shading_device_type::ShadingDeviceType::parse_any(),
-            )),
-        ))
+    pub(crate) fn register(registry: &mut EntityRegistry) {
+        registry.register::<actor_role::ActorRole>("IFCACTORROLE");
+        registry.register::<address::PostalAddress>("IFCPOSTALADDRESS");
+        registry.register::<address::TelecomAddress>("IFCTELECOMADDRESS");
+        registry.register::<application::Application>("IFCAPPLICATION");
+        registry.register::<organization::Organization>("IFCORGANIZATION");
+        registry.register::<owner_history::OwnerHistory>("IFCOWNERHISTORY");
+        registry.register::<person::Person>("IFCPERSON");
+        registry.register::<person_and_org::PersonAndOrganization>("IFCPERSONANDORGANIZATION");
+        registry.register::<wall::Wall>("IFCWALL");
+        registry.register::<wall::Wall>("IFCWALLSTANDARDCASE");
+        registry.register::<walltype::WallType>("IFCWALLTYPE");
+        registry.register::<slab::Slab>("IFCSLAB");
+        registry.register::<slab::Slab>("IFCSLABSTANDARDCASE");
+        registry.register::<slabtype::SlabType>("IFCSLABTYPE");
+        registry.register::<roof::Roof>("IFCROOF");
+        registry.register::<rooftype::RoofType>("IFCROOFTYPE");
+        registry.register::<building::Building>("IFCBUILDING");
+        registry.register::<storey::Storey>("IFCBUILDINGSTOREY");
+        registry.register::<site::Site>("IFCSITE");
+        registry.register::<opening_element::OpeningElement>("IFCOPENINGELEMENT");
+        registry.register::<window::Window>("IFCWINDOW");
+        registry.register::<window::Window>("IFCWINDOWSTANDARDCASE");
+        registry.register::<windowtype::WindowType>("IFCWINDOWTYPE");
+        registry.register::<project::Project>("IFCPROJECT");
+        registry.register::<space::Space>("IFCSPACE");
+        registry.register::<spacetype::SpaceType>("IFCSPACETYPE");
+        registry.register::<door::Door>("IFCDOOR");
+        registry.register::<door::Door>("IFCDOORSTANDARDCASE");
+        registry.register::<doortype::DoorType>("IFCDOORTYPE");
+        registry.register::<shading_device::ShadingDevice>("IFCSHADINGDEVICE");
+        registry.register::<shading_device_type::ShadingDeviceType>("IFCSHADINGDEVICETYPE");
     }
 }
@@ -1,10 +1,8 @@
-mod deserialize;
 pub mod opening_element_type_enum;
-mod serialize;
 
 use std::ops::{Deref, DerefMut};
 
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 
 use super::{
     shared::{
@@ -57,7 +55,8 @@ use crate::{
 ///   addition to the hole in the Body shape representation of the voided element.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcopeningelement.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcOpeningElement")]
 pub struct OpeningElement {
     #[inherited]
     element: Element,
@@ -128,3 +127,18 @@ impl TransformableType for OpeningElement {
         self.representation.custom().cloned()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::OpeningElement;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn parse_opening_element_works() {
+        let data = "IFCOPENINGELEMENT('2bJiss68D6hvLKV8O1xmqJ',#2,'Opening Element for Test Example','Description of Opening',$,#84,#31,$,.OPENING.);";
+        let parsed = OpeningElement::parse().parse(data).unwrap();
+        assert_eq!(data, parsed.to_string());
+    }
+}
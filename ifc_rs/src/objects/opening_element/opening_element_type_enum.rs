@@ -44,3 +44,5 @@ impl IFCParse for OpeningElementTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(OpeningElementTypeEnum);
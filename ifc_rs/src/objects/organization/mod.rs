@@ -13,8 +13,10 @@ use crate::prelude::*;
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcorganization.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcOrganization")]
 pub struct Organization {
     /// Identification of the organization.
+    #[ifc(name = "Identification")]
     pub id: OptionalParameter<StringPrimitive>,
     /// The word, or group of words, by which the organization is referred to.
     pub name: StringPrimitive,
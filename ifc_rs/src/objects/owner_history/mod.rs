@@ -15,6 +15,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcutilityresource/lexical/ifcownerhistory.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcOwnerHistory")]
 pub struct OwnerHistory {
     /// Direct reference to the end user who currently "owns" this object.
     /// Note that IFC includes the concept of ownership transfer from one
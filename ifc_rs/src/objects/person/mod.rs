@@ -13,8 +13,10 @@ use crate::prelude::*;
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcperson.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcPerson")]
 pub struct Person {
     /// Identification of the person.
+    #[ifc(name = "Identification")]
     pub id: OptionalParameter<StringPrimitive>,
     /// The name by which the family identity of the person may be recognized.
     pub family_name: OptionalParameter<StringPrimitive>,
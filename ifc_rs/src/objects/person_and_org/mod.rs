@@ -13,6 +13,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcactorresource/lexical/ifcpersonandorganization.htm
 #[derive(Debug, Clone, IfcVerify)]
+#[ifc(name = "IfcPersonAndOrganization")]
 pub struct PersonAndOrganization {
     /// The person who is related to the organization.
     pub the_person: TypedId<Person>,
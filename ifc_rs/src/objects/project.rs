@@ -20,6 +20,7 @@ use super::shared::root::Root;
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcproject.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcProject")]
 pub struct Project {
     #[inherited]
     context: Context,
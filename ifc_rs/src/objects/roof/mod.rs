@@ -1,9 +1,6 @@
-mod deserialize;
-mod serialize;
-
 use std::ops::{Deref, DerefMut};
 
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 
 use super::{
     shared::{
@@ -37,7 +34,8 @@ use crate::{
 /// as dormers (represented by IfcRoof).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcroof.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcRoof")]
 pub struct Roof {
     #[inherited]
     element: Element,
@@ -120,3 +118,22 @@ impl TransformableType for Roof {
         self.representation.custom().cloned()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Roof;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn roof_round_trip() {
+        let example =
+            "IFCROOF('3cUkl32yn9qRSPvBJVyWh4',#42,'Basic Roof:Roof_Flat-4Felt-150Ins-50Scr-150Conc-12Plr:286419',$,'Basic Roof:Roof_Flat-4Felt-150Ins-50Scr-150Conc-12Plr:45441',#34171,#35956,'286419',.NOTDEFINED.);";
+
+        let roof = Roof::parse().parse(example).unwrap();
+        let str_roof = roof.to_string();
+
+        assert_eq!(example, str_roof);
+    }
+}
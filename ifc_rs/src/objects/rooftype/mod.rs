@@ -18,6 +18,7 @@ pub(crate) mod type_enum;
 ///
 /// https://standards.buildingsmart.org/MVD/RELEASE/IFC4/ADD2_TC1/RV1_2/HTML/schema/ifcsharedbldgelements/lexical/ifcrooftype.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcRoofType")]
 pub struct RoofType {
     #[inherited]
     element_type: ElementType,
@@ -89,3 +89,5 @@ impl IFCParse for RoofTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(RoofTypeEnum);
@@ -1,16 +1,10 @@
-use std::{
-    fmt::Display,
-    ops::{Deref, DerefMut},
-};
+use std::ops::{Deref, DerefMut};
 
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 
 use crate::{
     id::TypedId,
-    parser::{
-        comma::Comma, optional::OptionalParameter, p_space_or_comment_surrounded,
-        string::StringPrimitive, IFCParse, IFCParser,
-    },
+    parser::{optional::OptionalParameter, string::StringPrimitive},
     prelude::*,
 };
 
@@ -22,7 +16,8 @@ use super::StructureType;
 /// they can be fixed or operable.
 ///
 /// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcShadingDevice.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcShadingDevice")]
 pub struct ShadingDevice {
     #[inherited]
     element: Element,
@@ -45,32 +40,6 @@ impl ShadingDevice {
     }
 }
 
-impl IFCParse for ShadingDevice {
-    fn parse<'a>() -> impl IFCParser<'a, Self> {
-        winnow::seq! {
-            ShadingDevice {
-                _: p_space_or_comment_surrounded("IFCSHADINGDEVICE("),
-
-                element: Element::parse(),
-                _: Comma::parse(),
-                predefined_type: OptionalParameter::parse(),
-
-                _: p_space_or_comment_surrounded(");"),
-            }
-        }
-    }
-}
-
-impl Display for ShadingDevice {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "IFCSHADINGDEVICE({},{});",
-            self.element, self.predefined_type,
-        )
-    }
-}
-
 impl RootBuilder for ShadingDevice {
     fn root_mut(&mut self) -> &mut Root {
         &mut self.element
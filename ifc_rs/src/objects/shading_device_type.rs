@@ -23,6 +23,7 @@ use super::shared::{
 ///
 /// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcShadingDeviceType.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcShadingDeviceType")]
 pub struct ShadingDeviceType {
     #[inherited]
     element_type: ElementType,
@@ -51,3 +51,5 @@ impl IFCParse for ShadingDeviceTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(ShadingDeviceTypeEnum);
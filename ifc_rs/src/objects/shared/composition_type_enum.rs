@@ -36,3 +36,5 @@ impl IFCParse for CompositionTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(CompositionTypeEnum);
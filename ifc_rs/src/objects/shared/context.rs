@@ -44,6 +44,7 @@ pub struct Context {
     /// components, one or several geometric representation contexts need
     /// to be included that define e.g. the world coordinate system, the
     /// coordinate space dimensions, and/or the precision factor.
+    #[ifc(name = "RepresentationContexts")]
     pub representation_context: IfcList<TypedId<GeometricRepresentationContext>>,
 
     /// Units globally assigned to measure types used within the context.
@@ -1,14 +1,9 @@
-use std::{
-    fmt::Display,
-    ops::{Deref, DerefMut},
-};
+use std::ops::{Deref, DerefMut};
 
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 
 use crate::{
-    parser::{
-        comma::Comma, optional::OptionalParameter, string::StringPrimitive, IFCParse, IFCParser,
-    },
+    parser::{optional::OptionalParameter, string::StringPrimitive},
     prelude::*,
 };
 
@@ -17,7 +12,7 @@ use super::product::Product;
 /// An element is a generalization of all components that make up an AEC product.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcelement.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
 pub struct Element {
     #[inherited]
     product: Product,
@@ -59,21 +54,3 @@ impl DerefMut for Element {
         &mut self.product
     }
 }
-
-impl IFCParse for Element {
-    fn parse<'a>() -> impl IFCParser<'a, Self> {
-        winnow::seq! {
-            Element {
-                product: Product::parse(),
-                _: Comma::parse(),
-                tag: OptionalParameter::parse(),
-            }
-        }
-    }
-}
-
-impl Display for Element {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{},{}", self.product, self.tag)
-    }
-}
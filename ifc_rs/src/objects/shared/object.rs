@@ -1,12 +1,9 @@
-use std::ops::DerefMut;
-use std::{fmt::Display, ops::Deref};
+use std::ops::{Deref, DerefMut};
 
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 
 use crate::{
-    parser::{
-        comma::Comma, optional::OptionalParameter, string::StringPrimitive, IFCParse, IFCParser,
-    },
+    parser::{optional::OptionalParameter, string::StringPrimitive},
     prelude::*,
 };
 
@@ -16,7 +13,7 @@ use super::root::Root;
 /// thing or process. Objects are things as they appear - i.e. occurrences.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifckernel/lexical/ifcobject.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
 pub struct Object {
     #[inherited]
     root: Root,
@@ -59,21 +56,3 @@ impl DerefMut for Object {
         &mut self.root
     }
 }
-
-impl IFCParse for Object {
-    fn parse<'a>() -> impl IFCParser<'a, Self> {
-        winnow::seq! {
-            Object {
-                root: Root::parse(),
-                _: Comma::parse(),
-                object_type: OptionalParameter::parse(),
-            }
-        }
-    }
-}
-
-impl Display for Object {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{},{}", self.root, self.object_type)
-    }
-}
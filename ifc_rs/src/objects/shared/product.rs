@@ -1,13 +1,10 @@
-use std::{
-    fmt::Display,
-    ops::{Deref, DerefMut},
-};
+use std::ops::{Deref, DerefMut};
 
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 
 use crate::{
     id::{Id, IdOr, TypedId},
-    parser::{comma::Comma, optional::OptionalParameter, IFCParse, IFCParser},
+    parser::optional::OptionalParameter,
     prelude::*,
 };
 
@@ -25,7 +22,7 @@ use super::object::Object;
 /// (with or without underlying geometry of the topological items).
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifckernel/lexical/ifcproduct.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
 pub struct Product {
     #[inherited]
     object: Object,
@@ -152,27 +149,3 @@ impl DerefMut for Product {
         &mut self.object
     }
 }
-
-impl IFCParse for Product {
-    fn parse<'a>() -> impl IFCParser<'a, Self> {
-        winnow::seq! {
-            Product {
-                object: Object::parse(),
-                _: Comma::parse(),
-                object_placement: OptionalParameter::parse(),
-                _: Comma::parse(),
-                representation: OptionalParameter::parse(),
-            }
-        }
-    }
-}
-
-impl Display for Product {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{},{},{}",
-            self.object, self.object_placement, self.representation
-        )
-    }
-}
@@ -1,7 +1,4 @@
-use std::fmt::Display;
-
-use comma::Comma;
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 use optional::OptionalParameter;
 use string::StringPrimitive;
 
@@ -19,7 +16,7 @@ use crate::{
 /// that are not subtypes of IfcRoot, are not supposed to be independent entities.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifckernel/lexical/ifcroot.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
 pub struct Root {
     /// Assignment of a globally unique identifier within the entire software world.
     pub global_id: IfcGloballyUniqueId,
@@ -72,32 +69,6 @@ pub trait RootBuilder: Sized {
     }
 }
 
-impl IFCParse for Root {
-    fn parse<'a>() -> impl IFCParser<'a, Self> {
-        winnow::seq! {
-            Root {
-                global_id: IfcGloballyUniqueId::parse(),
-                _: Comma::parse(),
-                owner_history: OptionalParameter::parse(),
-                _: Comma::parse(),
-                name: OptionalParameter::parse(),
-                _: Comma::parse(),
-                description: OptionalParameter::parse(),
-            }
-        }
-    }
-}
-
-impl Display for Root {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{},{},{},{}",
-            self.global_id, self.owner_history, self.name, self.description
-        )
-    }
-}
-
 #[cfg(test)]
 mod test {
     use winnow::Parser;
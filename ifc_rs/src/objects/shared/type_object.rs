@@ -37,6 +37,7 @@ pub struct TypeObject {
     ///   * If one type object is applicable to many occurrence objects,
     ///     then those occurrence object names should be separate by
     ///     comma "," forming a comma separated string.
+    #[ifc(name = "ApplicableOccurrence")]
     pub applicable_occurence: OptionalParameter<Id>,
 
     /// Set list of unique property sets, that are associated with the
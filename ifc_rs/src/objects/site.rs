@@ -52,6 +52,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcsite.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcSite")]
 pub struct Site {
     #[inherited]
     spatial_element_structure: SpatialStructureElement,
@@ -1,9 +1,6 @@
-mod deserialize;
-mod serialize;
-
 use std::ops::{Deref, DerefMut};
 
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 
 use crate::{
     id::TypedId,
@@ -36,7 +33,8 @@ use super::StructureType;
 /// given at the attribute ObjectType.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcsharedbldgelements/lexical/ifcslab.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcSlab", alias = "IfcSlabStandardCase")]
 pub struct Slab {
     #[inherited]
     element: Element,
@@ -38,6 +38,7 @@ pub(crate) mod type_enum;
 /// associated IfcMaterialLayerSet; otherwise they are represented by instances
 /// of IfcSlab, or IfcSlabElementedCase.
 #[derive(IfcVerify)]
+#[ifc(name = "IfcSlabType")]
 pub struct SlabType {
     #[inherited]
     element_type: ElementType,
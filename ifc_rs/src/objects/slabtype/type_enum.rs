@@ -52,3 +52,5 @@ impl IFCParse for SlabTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(SlabTypeEnum);
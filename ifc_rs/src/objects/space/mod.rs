@@ -40,6 +40,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcspace.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcSpace")]
 pub struct Space {
     #[inherited]
     spatial_element_structure: SpatialStructureElement,
@@ -39,6 +39,7 @@ pub(crate) mod type_enum;
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcproductextension/lexical/ifcspacetype.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcSpaceType")]
 pub struct SpaceType {
     #[inherited]
     element_type: ElementType,
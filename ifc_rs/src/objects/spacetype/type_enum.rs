@@ -56,3 +56,5 @@ impl IFCParse for SpaceTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(SpaceTypeEnum);
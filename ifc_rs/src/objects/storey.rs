@@ -52,6 +52,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcbuildingstorey.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcBuildingStorey")]
 pub struct Storey {
     #[inherited]
     spatial_element_structure: SpatialStructureElement,
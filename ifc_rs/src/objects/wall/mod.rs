@@ -1,9 +1,6 @@
-mod deserialize;
-mod serialize;
-
 use std::ops::{Deref, DerefMut};
 
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 
 use crate::{
     id::{IdOr, TypedId},
@@ -19,7 +16,8 @@ use super::StructureType;
 /// A wall is however not required to be load bearing.
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcwall.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcWall", alias = "IfcWallStandardCase")]
 pub struct Wall {
     #[inherited]
     element: Element,
@@ -140,6 +138,18 @@ pub mod test {
         assert_eq!(example, str_wall);
     }
 
+    #[test]
+    fn wall_standard_case_is_read_as_wall() {
+        let example = " IFCWALLSTANDARDCASE('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,#33,#25,$,$); ";
+
+        let wall: Wall = Wall::parse().parse(example).unwrap();
+
+        assert_eq!(
+            wall.to_string(),
+            "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,#33,#25,$,$);"
+        );
+    }
+
     pub fn print_wall_hierarchy(ifc: &IFC) {
         use crate::objects::wall::Wall;
 
@@ -22,6 +22,7 @@ pub(crate) mod type_enum;
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcwalltype.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcWallType")]
 pub struct WallType {
     #[inherited]
     element_type: ElementType,
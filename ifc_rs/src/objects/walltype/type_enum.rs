@@ -87,3 +87,5 @@ impl IFCParse for WallTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(WallTypeEnum);
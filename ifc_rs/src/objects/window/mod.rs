@@ -1,9 +1,6 @@
-mod deserialize;
-mod serialize;
-
 use std::ops::{Deref, DerefMut};
 
-use ifc_rs_verify_derive::IfcVerify;
+use ifc_rs_verify_derive::{IfcEntity, IfcVerify};
 
 use super::{
     shared::{
@@ -71,7 +68,8 @@ use crate::{
 ///   * the particular attributes for the panels by the  IfcWindowPanelProperties
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcwindow.htm
-#[derive(IfcVerify)]
+#[derive(IfcVerify, IfcEntity)]
+#[ifc(name = "IfcWindow", alias = "IfcWindowStandardCase")]
 pub struct Window {
     #[inherited]
     element: Element,
@@ -99,6 +97,7 @@ pub struct Window {
 
     /// Designator for the user defined partitioning type, shall only be
     /// provided, if the value of PartitioningType is set to USERDEFINED.
+    #[ifc(name = "UserDefinedPartitioningType")]
     pub user_defining_partitioning_type: OptionalParameter<StringPrimitive>,
 }
 
@@ -193,3 +192,18 @@ impl TransformableType for Window {
         self.representation.custom().cloned()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::Window;
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn parse_window_works() {
+        let data = "IFCWINDOW('0tA4DSHd50le6Ov9Yu0I9X',#2,'Window for Test Example','Description of Window',$,#88,#33,$,1000.,1000.,.WINDOW.,.SINGLE_PANEL.,$);";
+        let parsed = Window::parse().parse(data).unwrap();
+        assert_eq!(data, parsed.to_string());
+    }
+}
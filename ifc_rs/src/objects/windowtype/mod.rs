@@ -41,6 +41,7 @@ pub(crate) mod window_type_enum;
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcsharedbldgelements/lexical/ifcwindowtype.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcWindowType")]
 pub struct WindowType {
     #[inherited]
     element_type: ElementType,
@@ -85,3 +85,5 @@ impl IFCParse for WindowPartitioningTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(WindowPartitioningTypeEnum);
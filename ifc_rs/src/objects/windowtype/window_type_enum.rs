@@ -48,3 +48,5 @@ impl IFCParse for WindowTypeEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(WindowTypeEnum);
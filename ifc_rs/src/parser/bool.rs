@@ -1,9 +1,11 @@
 use std::str::FromStr;
 
+use anyhow::{bail, Error, Result};
 use winnow::combinator::{alt, delimited};
 use winnow::Parser;
 
 use crate::parser::*;
+use crate::traits::reflect::{AttributeKind, StepAttribute};
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum BoolPrimitive {
@@ -70,6 +72,23 @@ impl IFCParse for BoolPrimitive {
     }
 }
 
+impl StepAttribute for BoolPrimitive {
+    const KIND: AttributeKind = AttributeKind::Logical;
+
+    fn to_step_value(&self) -> StepValue {
+        let text = self.to_string();
+
+        StepValue::Enum(text.trim_matches('.').to_owned())
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        match value {
+            StepValue::Enum(value) => Self::from_str(&format!(".{value}.")).map_err(Error::msg),
+            value => bail!("expected logical, found `{value}`"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use winnow::Parser;
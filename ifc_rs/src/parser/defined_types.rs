@@ -0,0 +1,58 @@
+use crate::traits::reflect::AttributeKind;
+
+/// spelling of a defined type in the schema, e.g. `IFCLABEL` -> `IfcLabel`
+pub(crate) fn defined_type_name(keyword: &str) -> Option<&'static str> {
+    defined_type(keyword).map(|(name, _)| name)
+}
+
+/// kind of the values of a defined type, e.g. `IFCLABEL` -> [`AttributeKind::String`]
+pub(crate) fn defined_type_kind(keyword: &str) -> Option<AttributeKind> {
+    defined_type(keyword).map(|(_, kind)| kind)
+}
+
+fn defined_type(keyword: &str) -> Option<(&'static str, AttributeKind)> {
+    DEFINED_TYPES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(keyword))
+        .copied()
+}
+
+/// Defined types which are used as typed values in select attributes, e.g. `IFCLABEL('Wall')`,
+/// together with the kind of their underlying type. Needed to restore the spelling of the type
+/// names and to tell strings apart from numbers in formats without type information, unknown
+/// ones are kept as keyword.
+pub(crate) const DEFINED_TYPES: &[(&str, AttributeKind)] = &[
+    ("IfcAreaMeasure", AttributeKind::Real),
+    ("IfcBoolean", AttributeKind::Logical),
+    ("IfcCountMeasure", AttributeKind::Real),
+    ("IfcDate", AttributeKind::String),
+    ("IfcDateTime", AttributeKind::String),
+    ("IfcDescriptiveMeasure", AttributeKind::String),
+    ("IfcDuration", AttributeKind::String),
+    ("IfcGloballyUniqueId", AttributeKind::String),
+    ("IfcIdentifier", AttributeKind::String),
+    ("IfcInteger", AttributeKind::Integer),
+    ("IfcLabel", AttributeKind::String),
+    ("IfcLengthMeasure", AttributeKind::Real),
+    ("IfcLogical", AttributeKind::Logical),
+    ("IfcMassDensityMeasure", AttributeKind::Real),
+    ("IfcMassMeasure", AttributeKind::Real),
+    ("IfcNormalisedRatioMeasure", AttributeKind::Real),
+    ("IfcPlaneAngleMeasure", AttributeKind::Real),
+    ("IfcPositiveLengthMeasure", AttributeKind::Real),
+    ("IfcPositivePlaneAngleMeasure", AttributeKind::Real),
+    ("IfcPositiveRatioMeasure", AttributeKind::Real),
+    ("IfcPowerMeasure", AttributeKind::Real),
+    ("IfcRatioMeasure", AttributeKind::Real),
+    ("IfcReal", AttributeKind::Real),
+    ("IfcSpecificHeatCapacityMeasure", AttributeKind::Real),
+    ("IfcText", AttributeKind::String),
+    ("IfcThermalConductivityMeasure", AttributeKind::Real),
+    ("IfcThermalTransmittanceMeasure", AttributeKind::Real),
+    ("IfcThermodynamicTemperatureMeasure", AttributeKind::Real),
+    ("IfcTime", AttributeKind::String),
+    ("IfcTimeMeasure", AttributeKind::Real),
+    ("IfcTimeStamp", AttributeKind::Integer),
+    ("IfcURIReference", AttributeKind::String),
+    ("IfcVolumeMeasure", AttributeKind::Real),
+];
@@ -1,14 +1,100 @@
-use std::fmt::Display;
+use std::{fmt::Display, ops::Range};
 
-use ifc_rs_verify_derive::IfcVerify;
+use anyhow::{bail, Result};
 
-use crate::prelude::*;
+use crate::{id::Id, prelude::*};
 
-use super::IFCParse;
+use super::{IFCParse, IfcParseError};
 
-#[derive(IfcVerify)]
+/// Catch-all for entities which aren't modelled by this crate (yet). The entity is kept as raw
+/// text so it can be written back unchanged.
 pub struct Dummy {
     pub s: String,
+
+    /// Set if the entity line was malformed and skipped while parsing in lenient mode, see
+    /// [`crate::IFC::from_str_lenient`].
+    pub diagnostic: Option<IfcParseError>,
+}
+
+impl Dummy {
+    /// Dummy of the raw text whose references are replaced by `ids` in the order in which they
+    /// appear, used to import dummies which other formats keep as text.
+    pub(crate) fn with_references(s: &str, ids: &[Id]) -> Result<Self> {
+        let mut dummy = Self {
+            s: s.to_owned(),
+            diagnostic: None,
+        };
+
+        let spans = dummy.id_spans();
+        if spans.len() != ids.len() {
+            bail!(
+                "`{s}` has {} references, but {} are given",
+                spans.len(),
+                ids.len()
+            );
+        }
+
+        // replace back to front, so the remaining spans stay valid
+        for ((span, _), id) in spans.into_iter().zip(ids).rev() {
+            dummy.s.replace_range(span, &id.to_string());
+        }
+
+        Ok(dummy)
+    }
+
+    /// Positions of the entity references (e.g. `#12`) in the raw text, strings are skipped.
+    fn id_spans(&self) -> Vec<(Range<usize>, Id)> {
+        let bytes = self.s.as_bytes();
+        let mut spans = Vec::new();
+        let mut in_string = false;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\'' => in_string = !in_string,
+                b'#' if !in_string => {
+                    let digits = bytes[i + 1..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+
+                    if let Ok(id) = self.s[i + 1..i + 1 + digits].parse() {
+                        spans.push((i..i + 1 + digits, Id(id)));
+                    }
+                    i += digits;
+                }
+                _ => {}
+            }
+
+            i += 1;
+        }
+
+        spans
+    }
+}
+
+impl IfcVerify for Dummy {
+    fn visit_ids(&self, visitor: &mut dyn FnMut(&'static str, Id)) {
+        for (_, id) in self.id_spans() {
+            visitor("s", id);
+        }
+    }
+
+    fn visit_ids_mut(&mut self, visitor: &mut dyn FnMut(&'static str, &mut Id)) {
+        // replace back to front, so the remaining spans stay valid
+        for (span, mut id) in self.id_spans().into_iter().rev() {
+            let old = id;
+            visitor("s", &mut id);
+
+            if id != old {
+                self.s.replace_range(span, &id.to_string());
+            }
+        }
+    }
+
+    fn keyword(&self) -> &str {
+        self.s.split('(').next().unwrap_or_default().trim()
+    }
 }
 
 impl IfcType for Dummy {}
@@ -18,11 +104,33 @@ impl IFCParse for Dummy {
     where
         Self: Sized,
     {
-        use super::p_space_or_comment_surrounded;
-        use winnow::{combinator::repeat_till, token::any, Parser};
+        use crate::reader::split_statements;
+        use winnow::{
+            combinator::fail,
+            error::{StrContext, StrContextValue},
+            Parser,
+        };
+
+        // the statement ends at the first `;` which isn't part of a string or comment
+        (move |input: &mut &'a str| {
+            let statement = split_statements(input)
+                .next()
+                .filter(|statement| statement.ends_with(';'));
+
+            match statement {
+                Some(statement) => {
+                    let s = statement[..statement.len() - 1].trim_end().to_owned();
+                    *input = &input[statement.len()..];
 
-        repeat_till(.., any, p_space_or_comment_surrounded(";"))
-            .map(|(s, _): (String, _)| Self { s })
+                    Ok(Self {
+                        s,
+                        diagnostic: None,
+                    })
+                }
+                None => fail.parse_next(input),
+            }
+        })
+        .context(StrContext::Expected(StrContextValue::CharLiteral(';')))
     }
 }
 
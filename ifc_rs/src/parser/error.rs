@@ -0,0 +1,259 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+use winnow::{
+    combinator::{opt, preceded, terminated},
+    error::{ContextError, ErrMode, ParseError, StrContext},
+    token::take_while,
+    Parser,
+};
+
+use crate::{id::Id, meta::datamap::DanglingReference};
+
+use super::{p_space_or_comment, p_space_or_comment_surrounded, IFCParse};
+
+/// Error which is returned if an IFC file couldn't be parsed.
+///
+/// Besides the raw byte offset, it carries enough information to locate the broken statement in
+/// the source file without a debugger: line and column, the `#id` of the entity instance (if
+/// the failure happened inside the DATA section), the entity keyword and a list of tokens the
+/// parser expected at that position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IfcParseError {
+    /// byte offset into the input at which parsing failed
+    pub offset: usize,
+    /// 1-based line number of the failure position
+    pub line: usize,
+    /// 1-based column (in characters) of the failure position
+    pub column: usize,
+    /// id of the entity instance in which parsing failed, e.g. `#123`
+    pub entity_id: Option<Id>,
+    /// keyword of the statement in which parsing failed, e.g. `IFCWALL` or `FILE_NAME`
+    pub keyword: Option<String>,
+    /// descriptions of what the parser expected at the failure position
+    pub expected: Vec<String>,
+    /// labels of the parsers which were active when parsing failed, innermost first
+    pub context: Vec<String>,
+}
+
+impl IfcParseError {
+    pub(crate) fn new(input: &str, error: ParseError<&str, ContextError>) -> Self {
+        Self::at(input, error.offset(), error.inner())
+    }
+
+    /// creates the error for a failure at `offset` into `input`
+    pub(crate) fn at(input: &str, offset: usize, error: &ContextError) -> Self {
+        let (line, column) = line_and_column(input, offset);
+        let (entity_id, keyword) = statement_at(input, offset);
+
+        let (expected, context) = error.context().partition_map(|ctx| match ctx {
+            StrContext::Expected(value) => itertools::Either::Left(value.to_string()),
+            StrContext::Label(label) => itertools::Either::Right(label.to_string()),
+            _ => itertools::Either::Right(ctx.to_string()),
+        });
+
+        Self {
+            offset,
+            line,
+            column,
+            entity_id,
+            keyword,
+            expected,
+            context,
+        }
+    }
+}
+
+impl Display for IfcParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "parsing failed at line {line}, column {column}",
+            line = self.line,
+            column = self.column
+        )?;
+
+        match (&self.entity_id, &self.keyword) {
+            (Some(id), Some(keyword)) => write!(f, " in {id} ({keyword})")?,
+            (Some(id), None) => write!(f, " in {id}")?,
+            (None, Some(keyword)) => write!(f, " in {keyword}")?,
+            (None, None) => {}
+        }
+
+        if !self.context.is_empty() {
+            write!(f, " while parsing {}", self.context.iter().join(" in "))?;
+        }
+
+        if !self.expected.is_empty() {
+            write!(f, ": expected {}", self.expected.iter().join(" or "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for IfcParseError {}
+
+/// Error which was recovered from while reading a file in lenient mode, see
+/// [`crate::IFC::from_str_lenient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecoveredError {
+    /// a malformed entity line, which was kept as [`Dummy`](super::dummy::Dummy) if its id could
+    /// be read and skipped otherwise
+    Parse(IfcParseError),
+    /// a reference to an entity which doesn't exist in the file
+    DanglingReference(DanglingReference),
+    /// a reference to an entity of a type which isn't allowed for the attribute
+    InvalidReference {
+        /// id of the entity which holds the reference
+        entity: Id,
+        /// description of the type mismatch
+        message: String,
+    },
+}
+
+impl RecoveredError {
+    /// the parse error, if this is a malformed entity line
+    pub fn as_parse_error(&self) -> Option<&IfcParseError> {
+        match self {
+            RecoveredError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Display for RecoveredError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecoveredError::Parse(error) => write!(f, "{error}"),
+            RecoveredError::DanglingReference(reference) => write!(f, "{reference}"),
+            RecoveredError::InvalidReference { entity, message } => {
+                write!(f, "{entity} has an invalid reference: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RecoveredError {}
+
+impl From<IfcParseError> for RecoveredError {
+    fn from(error: IfcParseError) -> Self {
+        RecoveredError::Parse(error)
+    }
+}
+
+impl From<DanglingReference> for RecoveredError {
+    fn from(reference: DanglingReference) -> Self {
+        RecoveredError::DanglingReference(reference)
+    }
+}
+
+fn line_and_column(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+
+    (line, column)
+}
+
+/// Finds the statement which contains the given offset and extracts its id and keyword.
+///
+/// Statements in STEP files are separated by `;`, so we go back to the last one before the
+/// failure and try to read `#id= KEYWORD` (DATA section) or `KEYWORD` (HEADER section) from there.
+fn statement_at(input: &str, offset: usize) -> (Option<Id>, Option<String>) {
+    let start = input[..offset].rfind(';').map(|i| i + 1).unwrap_or(0);
+    let mut statement = &input[start..];
+
+    let p_keyword = take_while(1.., |c: char| {
+        c.is_ascii_alphanumeric() || c == '_' || c == '-'
+    })
+    .map(|s: &str| s.to_owned());
+
+    let result: Result<_, ErrMode<ContextError>> = preceded(
+        p_space_or_comment(),
+        (
+            opt(terminated(
+                Id::parse(),
+                p_space_or_comment_surrounded(opt("=")),
+            )),
+            opt(p_keyword),
+        ),
+    )
+    .parse_next(&mut statement);
+
+    result.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::IfcParseError;
+    use crate::{id::Id, IFC};
+
+    const HEADER: &str = r#"ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+"#;
+
+    fn parse_error(s: &str) -> IfcParseError {
+        let err = IFC::from_str(s).err().expect("parsing should fail");
+        err.downcast::<IfcParseError>()
+            .expect("error should be an IfcParseError")
+    }
+
+    #[test]
+    fn reports_entity_of_broken_line() {
+        let file = format!(
+            "{HEADER}DATA;
+#1= IFCCARTESIANPOINT((0.,0.,0.));
+#2 IFCDIRECTION((1.,0.,0.));
+ENDSEC;
+END-ISO-10303-21;
+"
+        );
+
+        let err = parse_error(&file);
+
+        assert_eq!(err.line, 9);
+        assert_eq!(err.column, 4);
+        assert_eq!(err.entity_id, Some(Id(2)));
+        assert_eq!(err.keyword.as_deref(), Some("IFCDIRECTION"));
+        assert!(err.expected.contains(&"`=`".to_string()), "{err}");
+    }
+
+    #[test]
+    fn reports_unterminated_entity() {
+        let file = format!(
+            "{HEADER}DATA;
+#1= IFCCARTESIANPOINT((0.,0.,0.))
+"
+        );
+
+        let err = parse_error(&file);
+
+        assert_eq!(err.entity_id, Some(Id(1)));
+        assert_eq!(err.keyword.as_deref(), Some("IFCCARTESIANPOINT"));
+    }
+
+    #[test]
+    fn reports_broken_header() {
+        let file = r#"ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC1'));
+ENDSEC;
+"#;
+
+        let err = parse_error(file);
+
+        assert_eq!(err.line, 5);
+        assert_eq!(err.entity_id, None);
+        assert_eq!(err.keyword.as_deref(), Some("FILE_SCHEMA"));
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    id::Id, meta::datamap::DataMap, parser::unknown_entity::UnknownEntity, prelude::*,
+    traits::reflect::EntityType,
+};
+
+/// All entities of a model in their generic representation, which the exports to IFC-JSON and
+/// ifcXML write.
+///
+/// [`Dummy`] entities are exported with positional attributes if their text can be read as a
+/// generic entity. Otherwise their STEP text is kept in `raw` and the attributes of their entity
+/// are the entities the text references, which the formats write next to the text, so that they
+/// can restore the references when the ids change on import.
+pub(crate) struct ExportedEntities {
+    pub entities: BTreeMap<Id, UnknownEntity>,
+    /// schema definitions of the entities which this crate models
+    pub entity_types: HashMap<Id, &'static EntityType>,
+    /// text of the dummies which can't be read as generic entity
+    pub raw: HashMap<Id, String>,
+    /// ids of all dummies, in the order of their ids
+    pub dummies: Vec<Id>,
+}
+
+impl ExportedEntities {
+    /// `format` is the name of the format in errors, e.g. `IFC-JSON`
+    pub(crate) fn new(data: &DataMap, format: &str) -> Result<Self> {
+        let mut exported = Self {
+            entities: BTreeMap::new(),
+            entity_types: HashMap::new(),
+            raw: HashMap::new(),
+            dummies: Vec::new(),
+        };
+
+        for (id, entity) in data.iter() {
+            if let Some(entity_type) = entity.entity_type() {
+                exported.entity_types.insert(*id, entity_type);
+            }
+
+            let entity = match entity.downcast_ref::<Dummy>() {
+                Some(dummy) => {
+                    exported.dummies.push(*id);
+
+                    UnknownEntity::from_dummy(dummy).unwrap_or_else(|| {
+                        let mut ids = Vec::new();
+                        dummy.visit_ids(&mut |_, id| ids.push(StepValue::Id(id)));
+                        exported.raw.insert(*id, dummy.s.clone());
+
+                        UnknownEntity::new(dummy.keyword(), ids)
+                    })
+                }
+                None => UnknownEntity::from_entity(entity.as_ref())
+                    .with_context(|| format!("failed to export entity {id} to {format}"))?,
+            };
+
+            exported.entities.insert(*id, entity);
+        }
+
+        Ok(exported)
+    }
+}
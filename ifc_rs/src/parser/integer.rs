@@ -1,9 +1,11 @@
 use std::fmt::Display;
 
+use anyhow::{bail, Result};
 use winnow::ascii::dec_int;
 use winnow::Parser;
 
-use crate::parser::{IFCParse, IFCParser};
+use crate::parser::{IFCParse, IFCParser, StepValue};
+use crate::traits::reflect::{AttributeKind, StepAttribute};
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct IntegerPrimitive(pub i64);
@@ -23,6 +25,35 @@ impl Display for IntegerPrimitive {
     }
 }
 
+impl StepAttribute for IntegerPrimitive {
+    const KIND: AttributeKind = AttributeKind::Integer;
+
+    fn to_step_value(&self) -> StepValue {
+        StepValue::Integer(self.0)
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        match value {
+            StepValue::Integer(integer) => Ok(Self(*integer)),
+            value => bail!("expected integer, found `{value}`"),
+        }
+    }
+}
+
+impl StepAttribute for i32 {
+    const KIND: AttributeKind = AttributeKind::Integer;
+
+    fn to_step_value(&self) -> StepValue {
+        StepValue::Integer((*self).into())
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        let IntegerPrimitive(integer) = IntegerPrimitive::from_step_value(value)?;
+
+        Ok(integer.try_into()?)
+    }
+}
+
 impl From<i64> for IntegerPrimitive {
     fn from(value: i64) -> Self {
         Self(value)
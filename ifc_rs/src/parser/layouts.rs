@@ -0,0 +1,375 @@
+use std::{collections::HashMap, fmt::Display, mem, sync::LazyLock};
+
+use crate::{
+    meta::header::schema::FileSchema,
+    parser::{unknown_entity::UnknownEntity, StepValue},
+    prelude::*,
+};
+
+/// Attribute layouts of the entities which differ between IFC2X3 and IFC4 as
+/// `(keyword, IFC2X3 layout, IFC4 layout)`. IFC4X3 uses the IFC4 layouts.
+///
+/// This table is shared by the parser, which checks the entities of a file against the layouts of
+/// its schema and reads IFC2X3 entities into the IFC4 layout of this crate, and the conversion
+/// between schemas.
+///
+/// Attributes with the same meaning use the same name in both layouts, even if the schemas name
+/// them differently (e.g. `ShapeType` of `IfcRoof` in IFC2X3 is listed as `PredefinedType`).
+/// Attributes of different types keep their own names, e.g. `InteriorOrExteriorSpace` of
+/// `IfcSpace` in IFC2X3 isn't the `PredefinedType` of IFC4.
+/// Names starting with `!` are required in that layout, but optional in the other one. They are
+/// set to a default when the attributes are rearranged. `IsVentilated` of `IfcMaterialLayer` is
+/// marked in the IFC4 layout, since this crate requires it and reads missing flags as solid
+/// layers.
+pub(crate) const IFC2X3_LAYOUTS: &[(&str, &str, &str)] = &[
+    (
+        "IFCBEAM",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCBUILDING",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation \
+         LongName !CompositionType ElevationOfRefHeight ElevationOfTerrain BuildingAddress",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation \
+         LongName CompositionType ElevationOfRefHeight ElevationOfTerrain BuildingAddress",
+    ),
+    (
+        "IFCBUILDINGELEMENTPROXY",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         CompositionType",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCBUILDINGSTOREY",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation \
+         LongName !CompositionType Elevation",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation \
+         LongName CompositionType Elevation",
+    ),
+    (
+        "IFCCOLUMN",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCCURTAINWALL",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCDOOR",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         OverallHeight OverallWidth",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         OverallHeight OverallWidth PredefinedType OperationType UserDefinedOperationType",
+    ),
+    ("IFCMATERIAL", "Name", "Name Description Category"),
+    (
+        "IFCMATERIALLAYER",
+        "Material LayerThickness IsVentilated",
+        "Material LayerThickness !IsVentilated Name Description Category Priority",
+    ),
+    (
+        "IFCMATERIALLAYERSET",
+        "MaterialLayers LayerSetName",
+        "MaterialLayers LayerSetName Description",
+    ),
+    (
+        "IFCMATERIALLAYERSETUSAGE",
+        "ForLayerSet LayerSetDirection DirectionSense OffsetFromReferenceLine",
+        "ForLayerSet LayerSetDirection DirectionSense OffsetFromReferenceLine ReferenceExtent",
+    ),
+    (
+        "IFCMEMBER",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCOPENINGELEMENT",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCPLATE",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCQUANTITYAREA",
+        "Name Description Unit Value",
+        "Name Description Unit Value Formula",
+    ),
+    (
+        "IFCQUANTITYCOUNT",
+        "Name Description Unit Value",
+        "Name Description Unit Value Formula",
+    ),
+    (
+        "IFCQUANTITYLENGTH",
+        "Name Description Unit Value",
+        "Name Description Unit Value Formula",
+    ),
+    (
+        "IFCQUANTITYTIME",
+        "Name Description Unit Value",
+        "Name Description Unit Value Formula",
+    ),
+    (
+        "IFCQUANTITYVOLUME",
+        "Name Description Unit Value",
+        "Name Description Unit Value Formula",
+    ),
+    (
+        "IFCQUANTITYWEIGHT",
+        "Name Description Unit Value",
+        "Name Description Unit Value Formula",
+    ),
+    (
+        "IFCRAMP",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         !PredefinedType",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCRAMPFLIGHT",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCROOF",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         !PredefinedType",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCSITE",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation \
+         LongName !CompositionType RefLatitude RefLongitude RefElevation LandTitleNumber \
+         SiteAddress",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation \
+         LongName CompositionType RefLatitude RefLongitude RefElevation LandTitleNumber \
+         SiteAddress",
+    ),
+    (
+        "IFCSLAB",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCSPACE",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation \
+         LongName !CompositionType !InteriorOrExteriorSpace ElevationWithFlooring",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation \
+         LongName CompositionType PredefinedType ElevationWithFlooring",
+    ),
+    (
+        "IFCSPACETYPE",
+        "GlobalId OwnerHistory Name Description ApplicableOccurrence HasPropertySets \
+         RepresentationMaps Tag ElementType PredefinedType",
+        "GlobalId OwnerHistory Name Description ApplicableOccurrence HasPropertySets \
+         RepresentationMaps Tag ElementType PredefinedType LongName",
+    ),
+    (
+        "IFCSTAIR",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         !PredefinedType",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCSTAIRFLIGHT",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         NumberOfRisers NumberOfTreads RiserHeight TreadLength",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         NumberOfRisers NumberOfTreads RiserHeight TreadLength PredefinedType",
+    ),
+    (
+        "IFCWALL",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCWALLSTANDARDCASE",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         PredefinedType",
+    ),
+    (
+        "IFCWINDOW",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         OverallHeight OverallWidth",
+        "GlobalId OwnerHistory Name Description ObjectType ObjectPlacement Representation Tag \
+         OverallHeight OverallWidth PredefinedType PartitioningType \
+         UserDefinedPartitioningType",
+    ),
+];
+
+/// Entities which were replaced by their supertype in the given schema, mapped to the keyword of
+/// the supertype.
+pub(crate) fn replacement(schema: FileSchema, keyword: &str) -> Option<&'static str> {
+    let replacement = match keyword {
+        "IFCBEAMSTANDARDCASE" => "IFCBEAM",
+        "IFCCOLUMNSTANDARDCASE" => "IFCCOLUMN",
+        "IFCDOORSTANDARDCASE" => "IFCDOOR",
+        "IFCMEMBERSTANDARDCASE" => "IFCMEMBER",
+        "IFCOPENINGSTANDARDCASE" => "IFCOPENINGELEMENT",
+        "IFCPLATESTANDARDCASE" => "IFCPLATE",
+        "IFCSLABSTANDARDCASE" | "IFCSLABELEMENTEDCASE" => "IFCSLAB",
+        "IFCWINDOWSTANDARDCASE" => "IFCWINDOW",
+        "IFCWALLELEMENTEDCASE" => "IFCWALL",
+        // the only standard case which already existed in IFC2X3
+        "IFCWALLSTANDARDCASE" if schema == FileSchema::IFC4X3_ADD2 => "IFCWALL",
+        _ => return None,
+    };
+
+    match schema {
+        FileSchema::IFC2X3 | FileSchema::IFC4X3_ADD2 => Some(replacement),
+        FileSchema::IFC4 => None,
+    }
+}
+
+/// whether entities with the given keyword can't be represented in the schema at all
+pub(crate) fn is_unavailable(schema: FileSchema, keyword: &str) -> bool {
+    match schema {
+        FileSchema::IFC2X3 => matches!(
+            keyword,
+            "IFCCARTESIANPOINTLIST2D"
+                | "IFCCARTESIANPOINTLIST3D"
+                | "IFCDOORTYPE"
+                | "IFCINDEXEDPOLYCURVE"
+                | "IFCINDEXEDPOLYGONALFACE"
+                | "IFCINDEXEDPOLYGONALFACEWITHVOIDS"
+                | "IFCMATERIALCONSTITUENT"
+                | "IFCMATERIALCONSTITUENTSET"
+                | "IFCMATERIALPROFILE"
+                | "IFCMATERIALPROFILESET"
+                | "IFCMATERIALPROFILESETUSAGE"
+                | "IFCMATERIALPROPERTIES"
+                | "IFCPOLYGONALFACESET"
+                | "IFCRELDECLARES"
+                | "IFCROOFTYPE"
+                | "IFCSHADINGDEVICE"
+                | "IFCTRIANGULATEDFACESET"
+                | "IFCWINDOWTYPE"
+        ),
+        FileSchema::IFC4 => false,
+        FileSchema::IFC4X3_ADD2 => matches!(keyword, "IFCDOORSTYLE" | "IFCWINDOWSTYLE"),
+    }
+}
+
+static LAYOUTS: LazyLock<HashMap<&'static str, (&'static str, &'static str)>> =
+    LazyLock::new(|| {
+        IFC2X3_LAYOUTS
+            .iter()
+            .map(|(keyword, ifc2x3, ifc4)| (*keyword, (*ifc2x3, *ifc4)))
+            .collect()
+    });
+
+/// attribute layout of the entity in the given schema, if it differs between the schemas
+pub(crate) fn layout(schema: FileSchema, keyword: &str) -> Option<&'static str> {
+    LAYOUTS.get(keyword).map(|(ifc2x3, ifc4)| match schema {
+        FileSchema::IFC2X3 => *ifc2x3,
+        FileSchema::IFC4 | FileSchema::IFC4X3_ADD2 => *ifc4,
+    })
+}
+
+/// names of the attributes in the layout, without the marks of required attributes
+pub(crate) fn attribute_names(layout: &str) -> impl Iterator<Item = &str> {
+    layout
+        .split_whitespace()
+        .map(|name| name.trim_start_matches('!'))
+}
+
+/// Rearranges the attributes from the `source` layout to the `target` layout by their names.
+/// Returns the attributes which don't exist in the target layout, in the order of the source
+/// layout.
+///
+/// `args` has to match the source layout.
+pub(crate) fn rearrange(
+    args: &mut Vec<StepValue>,
+    source: &'static str,
+    target: &'static str,
+) -> Vec<(&'static str, StepValue)> {
+    let names = attribute_names(source).collect::<Vec<_>>();
+    let mut values = names
+        .iter()
+        .copied()
+        .zip(mem::take(args))
+        .collect::<HashMap<_, _>>();
+
+    *args = target
+        .split_whitespace()
+        .map(|name| {
+            let (required, name) = match name.strip_prefix('!') {
+                Some(name) => (true, name),
+                None => (false, name),
+            };
+
+            match values.remove(name) {
+                Some(StepValue::Omitted) | None if required => required_default(name),
+                Some(value) => value,
+                None => StepValue::Omitted,
+            }
+        })
+        .collect();
+
+    names
+        .into_iter()
+        .filter_map(|name| Some((name, values.remove(name)?)))
+        .collect()
+}
+
+/// value of attributes which are required in the target schema, but weren't set
+fn required_default(name: &str) -> StepValue {
+    match name {
+        "CompositionType" => StepValue::Enum("ELEMENT".to_owned()),
+        "IsVentilated" => StepValue::Enum("F".to_owned()),
+        _ => StepValue::Enum("NOTDEFINED".to_owned()),
+    }
+}
+
+/// The entity in the attribute layout of `schema`, if the layout of its type differs from the IFC4
+/// layout which the types of this crate use. [`UnknownEntity`] is already in the layout of the
+/// file.
+pub(crate) fn in_schema(entity: &dyn IfcType, schema: FileSchema) -> Option<UnknownEntity> {
+    if entity.downcast_ref::<UnknownEntity>().is_some() {
+        return None;
+    }
+
+    let keyword = entity.keyword();
+    let (target, source) = (layout(schema, keyword)?, layout(FileSchema::IFC4, keyword)?);
+    if target == source {
+        return None;
+    }
+
+    let mut entity = UnknownEntity::from_entity(entity).ok()?;
+    rearrange(&mut entity.args, source, target);
+
+    Some(entity)
+}
+
+/// Writes an entity in the attribute layout of a schema, see [`in_schema`].
+pub(crate) struct InSchema<'a>(pub &'a dyn IfcType, pub Option<FileSchema>);
+
+impl Display for InSchema<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.1.and_then(|schema| in_schema(self.0, schema)) {
+            Some(entity) => write!(f, "{entity}"),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
@@ -1,12 +1,16 @@
 use std::{fmt::Display, slice::Iter};
 
+use anyhow::{bail, Result};
 use itertools::Itertools;
 use winnow::{
     combinator::{opt, preceded, repeat_till, terminated},
     Parser,
 };
 
-use crate::parser::IFCParse;
+use crate::{
+    parser::{IFCParse, StepValue},
+    traits::reflect::{AttributeKind, StepAttribute},
+};
 
 use super::p_space_or_comment_surrounded;
 
@@ -46,3 +50,22 @@ impl<T: Display> Display for IfcList<T> {
         )
     }
 }
+
+impl<T: StepAttribute> StepAttribute for IfcList<T> {
+    const KIND: AttributeKind = T::KIND;
+
+    fn to_step_value(&self) -> StepValue {
+        StepValue::List(self.iter().map(T::to_step_value).collect())
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        match value {
+            StepValue::List(values) => values
+                .iter()
+                .map(T::from_step_value)
+                .collect::<Result<_>>()
+                .map(Self),
+            value => bail!("expected list, found `{value}`"),
+        }
+    }
+}
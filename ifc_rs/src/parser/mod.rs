@@ -1,14 +1,21 @@
 pub(crate) mod bool;
 pub(crate) mod comma;
+pub(crate) mod defined_types;
 pub(crate) mod dummy;
+pub(crate) mod error;
+pub(crate) mod export;
 pub(crate) mod geometry;
 pub(crate) mod integer;
+pub(crate) mod layouts;
 pub(crate) mod list;
 pub(crate) mod optional;
 pub(crate) mod place_holder;
 pub(crate) mod real;
+pub(crate) mod registry;
+pub(crate) mod step_value;
 pub(crate) mod string;
 pub(crate) mod timestamp;
+pub(crate) mod unknown_entity;
 
 use std::fmt::Display;
 
@@ -16,12 +23,17 @@ use optional::OptionalParameter;
 use winnow::ascii::*;
 use winnow::combinator::*;
 use winnow::token::*;
-use winnow::{error::EmptyError, Parser};
+use winnow::{error::ContextError, Parser};
 
 use crate::prelude::*;
 
-pub trait IFCParser<'a, T>: Parser<&'a str, T, EmptyError> {}
-impl<'a, T, P: Parser<&'a str, T, EmptyError>> IFCParser<'a, T> for P {}
+pub use error::{IfcParseError, RecoveredError};
+pub use registry::{EntityParseFn, EntityRegistry};
+pub use step_value::StepValue;
+pub use unknown_entity::UnknownEntity;
+
+pub trait IFCParser<'a, T>: Parser<&'a str, T, ContextError> {}
+impl<'a, T, P: Parser<&'a str, T, ContextError>> IFCParser<'a, T> for P {}
 
 pub trait IFCParse: Display {
     fn parse<'a>() -> impl IFCParser<'a, Self>
@@ -55,8 +67,16 @@ pub(crate) fn p_word_until<'a>(end: char) -> impl IFCParser<'a, String> {
     take_while(.., move |c: char| c != end).map(|x: &str| x.to_owned())
 }
 
+/// Contents of a string literal without the surrounding quotes. The contents are kept as written,
+/// so escaped quotes (`''`) and control directives still have to be decoded.
 pub(crate) fn p_quote_word<'a>() -> impl IFCParser<'a, String> {
-    delimited("'", p_word_until('\''), "'")
+    let p_content = repeat(
+        ..,
+        alt(("''".void(), take_while(1.., |c: char| c != '\'').void())),
+    )
+    .map(|()| ());
+
+    delimited("'", p_content.take(), "'").map(ToOwned::to_owned)
 }
 
 pub(crate) fn p_comment<'a>() -> impl IFCParser<'a, ()> {
@@ -1,8 +1,12 @@
 use std::fmt::Display;
 
+use anyhow::Result;
 use winnow::{combinator::alt, Parser};
 
-use crate::parser::{IFCParse, IFCParser};
+use crate::{
+    parser::{IFCParse, IFCParser, StepValue},
+    traits::reflect::{AttributeKind, StepAttribute},
+};
 
 use super::place_holder::{Inherited, Omitted};
 
@@ -84,3 +88,23 @@ impl<T: IFCParse> Display for OptionalParameter<T> {
         }
     }
 }
+
+impl<T: StepAttribute + IFCParse> StepAttribute for OptionalParameter<T> {
+    const KIND: AttributeKind = T::KIND;
+
+    fn to_step_value(&self) -> StepValue {
+        match self {
+            OptionalParameter::Omitted(_) => StepValue::Omitted,
+            OptionalParameter::Inherited(_) => StepValue::Inherited,
+            OptionalParameter::Custom(value) => value.to_step_value(),
+        }
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        Ok(match value {
+            StepValue::Omitted => Self::omitted(),
+            StepValue::Inherited => Self::inherited(),
+            value => Self::Custom(T::from_step_value(value)?),
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 use std::ops::Deref;
 
+use anyhow::{bail, Context, Result};
 use bevy_math::{DVec2, DVec3};
 use winnow::ascii::float;
 use winnow::Parser;
@@ -8,6 +9,7 @@ use winnow::Parser;
 use crate::parser::geometry::{p_vec2, p_vec3};
 use crate::parser::{IFCParse, IFCParser};
 use crate::prelude::*;
+use crate::traits::reflect::{AttributeKind, StepAttribute};
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct RealPrimitive(pub f64);
@@ -42,6 +44,29 @@ impl Display for RealPrimitive {
     }
 }
 
+impl StepAttribute for RealPrimitive {
+    const KIND: AttributeKind = AttributeKind::Real;
+
+    fn to_step_value(&self) -> StepValue {
+        StepValue::Real(format_real_primitive(self.0))
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        real(value).map(Self)
+    }
+}
+
+/// reals may also be written as integers
+fn real(value: &StepValue) -> Result<f64> {
+    match value {
+        StepValue::Real(real) => real
+            .parse()
+            .with_context(|| format!("invalid real `{real}`")),
+        StepValue::Integer(integer) => Ok(*integer as f64),
+        value => bail!("expected real, found `{value}`"),
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct IfcDVec2(pub(crate) DVec2);
 
@@ -78,6 +103,20 @@ impl From<DVec2> for IfcDVec2 {
     }
 }
 
+impl StepAttribute for IfcDVec2 {
+    const KIND: AttributeKind = AttributeKind::Real;
+
+    fn to_step_value(&self) -> StepValue {
+        reals(&self.0.to_array())
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        let [x, y] = from_reals(value)?;
+
+        Ok(Self(DVec2::new(x, y)))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct IfcDVec3(pub(crate) DVec3);
 
@@ -115,32 +154,183 @@ impl From<DVec3> for IfcDVec3 {
     }
 }
 
+impl StepAttribute for IfcDVec3 {
+    const KIND: AttributeKind = AttributeKind::Real;
+
+    fn to_step_value(&self) -> StepValue {
+        reals(&self.0.to_array())
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        let [x, y, z] = from_reals(value)?;
+
+        Ok(Self(DVec3::new(x, y, z)))
+    }
+}
+
+fn reals(coordinates: &[f64]) -> StepValue {
+    StepValue::List(
+        coordinates
+            .iter()
+            .map(|coordinate| StepValue::Real(format_real_primitive(*coordinate)))
+            .collect(),
+    )
+}
+
+fn from_reals<const N: usize>(value: &StepValue) -> Result<[f64; N]> {
+    match value {
+        StepValue::List(values) if values.len() == N => {
+            let mut coordinates = [0.0; N];
+            for (coordinate, value) in coordinates.iter_mut().zip(values) {
+                *coordinate = real(value)?;
+            }
+
+            Ok(coordinates)
+        }
+        value => bail!("expected list of {N} reals, found `{value}`"),
+    }
+}
+
+/// How real numbers are written, see [`IFC::write_to_with`](crate::IFC::write_to_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RealFormat {
+    /// representation which parses back to exactly the same value, e.g. `0.1` or
+    /// `6.12303176911189E-17`
+    #[default]
+    Shortest,
+    /// fixed number of decimal places, e.g. `1.500` for `Fixed(3)`
+    Fixed(usize),
+}
+
+impl RealFormat {
+    /// formats the real as valid STEP real in this format
+    pub fn format(self, d: f64) -> String {
+        match self {
+            RealFormat::Shortest => format_shortest(d),
+            RealFormat::Fixed(precision) => with_decimal_point(format!("{d:.precision$}")),
+        }
+    }
+
+    /// writes all reals of the value in this format, also the ones in lists and typed values
+    pub(crate) fn reformat(self, value: &mut StepValue) {
+        match value {
+            StepValue::Real(real) => {
+                if let Ok(d) = real.parse::<f64>() {
+                    *real = self.format(d);
+                }
+            }
+            StepValue::Typed(_, value) => self.reformat(value),
+            StepValue::List(values) => values.iter_mut().for_each(|value| self.reformat(value)),
+            _ => {}
+        }
+    }
+}
+
+/// formats the real as valid STEP real, which parses back to exactly the same value
 pub(crate) fn format_real_primitive(d: f64) -> String {
-    // might need tuning 10 decimals allowed
-    let is_scientific = d
-        .fract()
-        .to_string()
-        .chars()
-        .filter(|c| c.is_ascii_digit() && *c != '0')
-        .count()
-        > 10;
-
-    let fmt = if is_scientific {
-        format_sci_double
-    } else {
-        format_non_sci_double
-    };
+    RealFormat::Shortest.format(d)
+}
+
+fn format_shortest(d: f64) -> String {
+    let shortest = format!("{d:E}");
+    let digits = shortest
+        .split('E')
+        .next()
+        .unwrap_or_default()
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .count();
 
-    fmt(d)
+    // reals written with full precision and tiny reals are written in scientific notation with 15
+    // significant digits, like most exporters do
+    let is_scientific = d.is_finite() && (digits > 12 || (d.abs() < 1e-4 && digits > 1));
+
+    if is_scientific {
+        let formatted = format!("{d:.14E}");
+
+        match formatted.parse::<f64>() {
+            Ok(parsed) if parsed == d => formatted,
+            _ => shortest,
+        }
+    } else {
+        with_decimal_point(d.to_string())
+    }
 }
 
-pub(crate) fn format_sci_double(d: f64) -> String {
-    format!("{0:.1$E}", d, 14)
+/// adds the decimal point which STEP requires for reals, e.g. `1.` instead of `1`
+fn with_decimal_point(mut formatted: String) -> String {
+    if formatted.bytes().all(|b| b.is_ascii_digit() || b == b'-') {
+        formatted.push('.');
+    }
+
+    formatted
 }
 
-pub(crate) fn format_non_sci_double(d: f64) -> String {
-    format!(
-        "{d}{opt_p}",
-        opt_p = (d.fract() == 0.0).then_some(".").unwrap_or_default()
-    )
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{format_real_primitive, RealFormat, RealPrimitive};
+    use crate::parser::{IFCParse, StepValue};
+
+    #[test]
+    fn writes_valid_step_reals() {
+        let cases = [
+            (0.0, "0."),
+            (-0.0, "-0."),
+            (1.0, "1."),
+            (-250.0, "-250."),
+            (0.1, "0.1"),
+            (0.0812909142679, "0.0812909142679"),
+            (1e-5, "0.00001"),
+            (0.199999999999868, "1.99999999999868E-1"),
+            (6.12303176911189E-17, "6.12303176911189E-17"),
+            (-5.06639974418E-8, "-5.06639974418000E-8"),
+            (0.0174532925199, "0.0174532925199"),
+            (1.0 / 3.0, "3.333333333333333E-1"),
+            (1e20, "100000000000000000000."),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(format_real_primitive(value), expected);
+        }
+    }
+
+    #[test]
+    fn reals_round_trip_exactly() {
+        let values = [
+            0.1 + 0.2,
+            1.0 / 3.0,
+            std::f64::consts::PI * 1e-9,
+            6.12303176911189E-17,
+            1.00000000000000E-5,
+            -123456.789012345,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+        ];
+
+        for value in values {
+            let written = RealPrimitive(value).to_string();
+            let parsed = RealPrimitive::parse().parse(&written).unwrap();
+            assert_eq!(parsed.0.to_bits(), value.to_bits(), "{written}");
+        }
+    }
+
+    #[test]
+    fn writes_fixed_precision() {
+        let written = [1.0, 0.12345, -2.5].map(|value| RealFormat::Fixed(3).format(value));
+        assert_eq!(written, ["1.000", "0.123", "-2.500"]);
+        assert_eq!(RealFormat::Fixed(0).format(2.0), "2.");
+
+        let mut value = StepValue::List(vec![
+            StepValue::Real("0.126".to_owned()),
+            StepValue::Typed(
+                "IFCLENGTHMEASURE".to_owned(),
+                Box::new(StepValue::Real("1.E-1".to_owned())),
+            ),
+            StepValue::Integer(3),
+        ]);
+        RealFormat::Fixed(2).reformat(&mut value);
+        assert_eq!(value.to_string(), "(0.13,IFCLENGTHMEASURE(0.10),3)");
+    }
 }
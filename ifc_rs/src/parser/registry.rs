@@ -0,0 +1,581 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use winnow::{
+    error::{AddContext, ContextError, ErrMode, StrContext, StrContextValue},
+    stream::Stream,
+    token::take_while,
+    Parser,
+};
+
+use crate::{
+    geometry::Geometry,
+    materials::Materials,
+    meta::header::schema::{FileSchema, FileSchemas},
+    objects::Objects,
+    prelude::*,
+    properties::Properties,
+    relations::Relation,
+    traits::reflect::EntityType,
+    units::Units,
+};
+
+use super::{dummy::Dummy, layouts, unknown_entity::UnknownEntity, IFCParse};
+
+/// Parser for a single entity, starting at its keyword, e.g. `IFCWALL(...)`.
+pub type EntityParseFn =
+    for<'a> fn(&mut &'a str) -> Result<Box<dyn IfcType>, ErrMode<ContextError>>;
+
+static GLOBAL: LazyLock<RwLock<GlobalRegistry>> = LazyLock::new(|| {
+    RwLock::new(GlobalRegistry {
+        registry: Arc::new(EntityRegistry::with_builtin()),
+        schemas: HashMap::new(),
+    })
+});
+
+/// The global registry together with its copies for the schemas of the files which were parsed,
+/// so they aren't copied again for every file.
+struct GlobalRegistry {
+    registry: Arc<EntityRegistry>,
+    schemas: HashMap<FileSchema, Arc<EntityRegistry>>,
+}
+
+/// Maps entity keywords like `IFCWALL` to the parsers of the rust types which represent them.
+///
+/// The DATA section is parsed by reading the keyword of each entity once and dispatching to the
+/// registered parsers. If there are none or all of them fail, the entity is kept as
+/// [`UnknownEntity`] or, if even that isn't possible, as [`Dummy`](super::dummy::Dummy).
+///
+/// Crates building on top of this one can add their own entity types to the global registry with
+/// [`EntityRegistry::register_global`].
+///
+/// The parsers registered with [`EntityRegistry::register`] read the attribute layout of IFC4 and
+/// IFC4X3. When the registry is set up for a schema with [`EntityRegistry::with_schema`], entities
+/// whose layout differs between the schemas are checked against the layout of that schema, and
+/// IFC2X3 entities are rearranged into the IFC4 layout before they are parsed. Entities which
+/// aren't part of the schema are rejected. Custom layouts can be added with
+/// [`EntityRegistry::register_schema_fn`].
+#[derive(Clone, Default)]
+pub struct EntityRegistry {
+    parsers: HashMap<String, Vec<EntityParseFn>>,
+    entity_types: HashMap<String, &'static EntityType>,
+    layouts: HashMap<FileSchema, HashMap<String, SchemaLayout>>,
+    schema: Option<FileSchema>,
+}
+
+/// How an entity is represented in a specific schema.
+#[derive(Clone)]
+enum SchemaLayout {
+    /// the entity has a different attribute layout in the schema
+    Parsers(Vec<EntityParseFn>),
+    /// the entity isn't part of the schema
+    Unavailable,
+}
+
+impl EntityRegistry {
+    /// creates a registry with all entity types which are implemented by this crate
+    pub fn with_builtin() -> Self {
+        let mut registry = Self::default();
+
+        Objects::register(&mut registry);
+        Geometry::register(&mut registry);
+        Relation::register(&mut registry);
+        Units::register(&mut registry);
+        Materials::register(&mut registry);
+        Properties::register(&mut registry);
+
+        registry
+    }
+
+    /// snapshot of the registry which is used for parsing
+    pub fn global() -> Arc<Self> {
+        GLOBAL.read().unwrap().registry.clone()
+    }
+
+    /// registers `T` in the global registry, see [`EntityRegistry::register`]
+    ///
+    /// Parsers which are already running keep using the registry they started with.
+    pub fn register_global<T: IFCParse + IfcType + 'static>(keyword: impl Into<String>) {
+        let mut global = GLOBAL.write().unwrap();
+        Arc::make_mut(&mut global.registry).register::<T>(keyword);
+        global.schemas.clear();
+    }
+
+    /// registers `T` as parser for entities with the given keyword
+    ///
+    /// A keyword may have multiple parsers (e.g. `IFCCARTESIANPOINT` is either 2D or 3D), which
+    /// are tried in the order of registration.
+    pub fn register<T: IFCParse + IfcType + 'static>(&mut self, keyword: impl Into<String>) {
+        let keyword = keyword.into();
+
+        if let Some(entity_type) = T::static_entity_type() {
+            self.entity_types.insert(keyword.clone(), entity_type);
+        }
+
+        self.register_fn(keyword, parse_entity::<T>);
+    }
+
+    /// registers a custom parser for entities with the given keyword, see
+    /// [`EntityRegistry::register`]
+    pub fn register_fn(&mut self, keyword: impl Into<String>, parser: EntityParseFn) {
+        self.parsers.entry(keyword.into()).or_default().push(parser);
+    }
+
+    /// registers a parser for the attribute layout which entities with the given keyword have in
+    /// `schema`
+    ///
+    /// When parsing files of that schema, these parsers replace the ones registered with
+    /// [`EntityRegistry::register`]. Since the layout of the entity is known, entities which don't
+    /// match it are reported as error instead of being kept as [`UnknownEntity`].
+    pub fn register_schema_fn(
+        &mut self,
+        schema: FileSchema,
+        keyword: impl Into<String>,
+        parser: EntityParseFn,
+    ) {
+        let layout = self
+            .layouts
+            .entry(schema)
+            .or_default()
+            .entry(keyword.into())
+            .or_insert_with(|| SchemaLayout::Parsers(Vec::new()));
+
+        match layout {
+            SchemaLayout::Parsers(parsers) => parsers.push(parser),
+            SchemaLayout::Unavailable => *layout = SchemaLayout::Parsers(vec![parser]),
+        }
+    }
+
+    /// marks entities with the given keyword as not being part of `schema`, so files of that
+    /// schema which contain them fail to parse
+    pub fn register_unavailable(&mut self, schema: FileSchema, keyword: impl Into<String>) {
+        self.layouts
+            .entry(schema)
+            .or_default()
+            .insert(keyword.into(), SchemaLayout::Unavailable);
+    }
+
+    /// copy of the registry which parses the attribute layouts of the given schema, see
+    /// [`EntityRegistry::register_schema_fn`]
+    pub fn with_schema(&self, schema: FileSchema) -> Self {
+        Self {
+            schema: Some(schema),
+            ..self.clone()
+        }
+    }
+
+    /// the schema whose attribute layouts are parsed, if it was set with
+    /// [`EntityRegistry::with_schema`]
+    pub fn schema(&self) -> Option<FileSchema> {
+        self.schema
+    }
+
+    /// snapshot of the global registry, set up for the schema a file declares in its header
+    pub(crate) fn for_file(schemas: &FileSchemas) -> Arc<Self> {
+        let Some(schema) = schemas.0.first() else {
+            return Self::global();
+        };
+
+        if let Some(registry) = GLOBAL.read().unwrap().schemas.get(schema) {
+            return registry.clone();
+        }
+
+        let mut global = GLOBAL.write().unwrap();
+        let GlobalRegistry { registry, schemas } = &mut *global;
+        schemas
+            .entry(*schema)
+            .or_insert_with(|| Arc::new(registry.with_schema(*schema)))
+            .clone()
+    }
+
+    /// whether there are parsers for the given keyword
+    pub fn contains(&self, keyword: &str) -> bool {
+        self.parsers.contains_key(keyword)
+    }
+
+    /// schema definition of the type which is registered for the given keyword
+    pub fn entity_type(&self, keyword: &str) -> Option<&'static EntityType> {
+        self.entity_types.get(keyword).copied()
+    }
+
+    /// all keywords which have parsers registered
+    pub fn keywords(&self) -> impl Iterator<Item = &str> {
+        self.parsers.keys().map(String::as_str)
+    }
+
+    /// Parses a single entity, starting at its keyword.
+    pub(crate) fn parse_entity(
+        &self,
+        input: &mut &str,
+    ) -> Result<Box<dyn IfcType>, ErrMode<ContextError>> {
+        let start = input.checkpoint();
+
+        let keyword: Result<&str, ErrMode<ContextError>> =
+            take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '_').parse_next(input);
+        let keyword = keyword.ok();
+
+        if let Some((schema, keyword)) = self.schema.zip(keyword) {
+            if let Some(layout) = self.layouts.get(&schema).and_then(|l| l.get(keyword)) {
+                return parse_schema_layout(input, &start, schema, layout);
+            }
+
+            if layouts::is_unavailable(schema, keyword)
+                || layouts::replacement(schema, keyword).is_some()
+            {
+                return parse_schema_layout(input, &start, schema, &SchemaLayout::Unavailable);
+            }
+
+            if let Some(layout) = layouts::layout(schema, keyword) {
+                return self.parse_table_layout(input, &start, schema, keyword, layout);
+            }
+        }
+
+        input.reset(&start);
+        self.parse_default(input)
+    }
+
+    /// Parses an entity with the parsers of its keyword, falling back to [`UnknownEntity`] and
+    /// [`Dummy`].
+    fn parse_default(&self, input: &mut &str) -> Result<Box<dyn IfcType>, ErrMode<ContextError>> {
+        let start = input.checkpoint();
+
+        let keyword: Result<&str, ErrMode<ContextError>> =
+            take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '_').parse_next(input);
+        let keyword = keyword.ok();
+
+        let parsers = keyword
+            .and_then(|keyword| self.parsers.get(keyword))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        for parser in parsers {
+            input.reset(&start);
+
+            match parser(input) {
+                Ok(entity) => return Ok(entity),
+                Err(ErrMode::Backtrack(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        // keep the attributes of entities we don't know
+        input.reset(&start);
+        if let Ok(entity) = UnknownEntity::parse().parse_next(input) {
+            return Ok(Box::new(entity));
+        }
+
+        // catch all fallback
+        input.reset(&start);
+        Dummy::parse_any().parse_next(input)
+    }
+}
+
+impl EntityRegistry {
+    /// Parses an entity whose layout differs between the schemas, see [`layouts::IFC2X3_LAYOUTS`].
+    /// Entities which don't have the attributes of the schema are rejected, as the file doesn't
+    /// match its schema.
+    fn parse_table_layout<'a>(
+        &self,
+        input: &mut &'a str,
+        start: &<&'a str as Stream>::Checkpoint,
+        schema: FileSchema,
+        keyword: &str,
+        layout: &'static str,
+    ) -> Result<Box<dyn IfcType>, ErrMode<ContextError>> {
+        let attributes = layouts::attribute_names(layout).count();
+        let ifc4_layout = layouts::layout(FileSchema::IFC4, keyword).unwrap_or(layout);
+
+        // the parsers of the crate read the layout of the file already, only the fallbacks have to
+        // be checked against it
+        if layouts::attribute_names(layout).eq(layouts::attribute_names(ifc4_layout)) {
+            input.reset(start);
+            let entity = self.parse_default(input)?;
+
+            let matches_layout = match entity.downcast_ref::<UnknownEntity>() {
+                Some(unknown) => unknown.args.len() == attributes,
+                None => entity.downcast_ref::<Dummy>().is_none(),
+            };
+            if matches_layout {
+                return Ok(entity);
+            }
+
+            input.reset(start);
+            return parse_schema_layout(input, start, schema, &SchemaLayout::Parsers(Vec::new()));
+        }
+
+        input.reset(start);
+        let entity = UnknownEntity::parse()
+            .parse_next(input)
+            .ok()
+            .filter(|entity| entity.args.len() == attributes);
+
+        let Some(entity) = entity else {
+            input.reset(start);
+            return parse_schema_layout(input, start, schema, &SchemaLayout::Parsers(Vec::new()));
+        };
+
+        // the types of this crate use the IFC4 layout, entities with attributes which only exist
+        // in the layout of the file keep that layout
+        let mut args = entity.args.clone();
+        let dropped = layouts::rearrange(&mut args, layout, ifc4_layout);
+        if dropped
+            .iter()
+            .any(|(_, value)| *value != StepValue::Omitted)
+        {
+            return Ok(Box::new(entity));
+        }
+        let text = UnknownEntity::new(entity.keyword.clone(), args).to_string();
+
+        let mut rearranged = text.as_str();
+        match self.parse_default(&mut rearranged) {
+            Ok(parsed)
+                if rearranged.is_empty() && parsed.downcast_ref::<UnknownEntity>().is_none() =>
+            {
+                Ok(parsed)
+            }
+            // unknown entities keep the layout of the file
+            _ => Ok(Box::new(entity)),
+        }
+    }
+}
+
+fn parse_entity<T: IFCParse + IfcType + 'static>(
+    input: &mut &str,
+) -> Result<Box<dyn IfcType>, ErrMode<ContextError>> {
+    T::parse_any().parse_next(input)
+}
+
+/// Parses an entity whose layout in the schema of the file is known. In contrast to the
+/// other entities, there is no fallback if this fails, as the file doesn't match its schema.
+fn parse_schema_layout<'a>(
+    input: &mut &'a str,
+    start: &<&'a str as Stream>::Checkpoint,
+    schema: FileSchema,
+    layout: &SchemaLayout,
+) -> Result<Box<dyn IfcType>, ErrMode<ContextError>> {
+    let (expected, label) = match schema {
+        FileSchema::IFC2X3 => (
+            "entity which is part of the IFC2X3 schema",
+            "attributes of the IFC2X3 schema",
+        ),
+        FileSchema::IFC4 => (
+            "entity which is part of the IFC4 schema",
+            "attributes of the IFC4 schema",
+        ),
+        FileSchema::IFC4X3_ADD2 => (
+            "entity which is part of the IFC4X3_ADD2 schema",
+            "attributes of the IFC4X3_ADD2 schema",
+        ),
+    };
+
+    let mut error = ContextError::new();
+
+    match layout {
+        SchemaLayout::Unavailable => {
+            input.reset(start);
+            error = error.add_context(
+                input,
+                start,
+                StrContext::Expected(StrContextValue::Description(expected)),
+            );
+        }
+        SchemaLayout::Parsers(parsers) => {
+            for parser in parsers {
+                input.reset(start);
+
+                match parser(input) {
+                    Ok(entity) => return Ok(entity),
+                    Err(ErrMode::Backtrack(err)) => error = err,
+                    Err(err) => return Err(err),
+                }
+            }
+
+            error = error.add_context(input, start, StrContext::Label(label));
+        }
+    }
+
+    Err(ErrMode::Backtrack(error))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fmt::Display, sync::Arc};
+
+    use ifc_rs_verify_derive::IfcVerify;
+    use winnow::{combinator::delimited, Parser};
+
+    use super::EntityRegistry;
+    use crate::{
+        geometry::point::{Point2D, Point3D},
+        meta::header::schema::{FileSchema, FileSchemas},
+        parser::{
+            dummy::Dummy, string::StringPrimitive, unknown_entity::UnknownEntity, IFCParse,
+            IFCParser,
+        },
+        prelude::*,
+    };
+
+    #[derive(IfcVerify)]
+    #[ifc(name = "IfcCustomEntity")]
+    struct CustomEntity(#[ifc(name = "Name")] StringPrimitive);
+
+    impl IFCParse for CustomEntity {
+        fn parse<'a>() -> impl IFCParser<'a, Self> {
+            delimited("IFCCUSTOMENTITY(", StringPrimitive::parse(), ");").map(Self)
+        }
+    }
+
+    impl Display for CustomEntity {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "IFCCUSTOMENTITY({});", self.0)
+        }
+    }
+
+    impl IfcType for CustomEntity {}
+
+    #[test]
+    fn dispatches_by_keyword() {
+        let registry = EntityRegistry::with_builtin();
+
+        let point = registry
+            .parse_entity(&mut "IFCCARTESIANPOINT((1.,2.));")
+            .unwrap();
+        assert!(point.downcast_ref::<Point2D>().is_some());
+
+        let point = registry
+            .parse_entity(&mut "IFCCARTESIANPOINT((1.,2.,3.));")
+            .unwrap();
+        assert!(point.downcast_ref::<Point3D>().is_some());
+
+        let unknown = registry
+            .parse_entity(&mut "IFCPOLYLOOP((#1,#2,#3));")
+            .unwrap();
+        assert!(unknown.downcast_ref::<UnknownEntity>().is_some());
+
+        let unknown = registry
+            .parse_entity(&mut "IFCPOLYLOOP( (#1,#2,#3) );")
+            .unwrap();
+        assert_eq!(
+            unknown.downcast_ref::<UnknownEntity>().unwrap().args.len(),
+            1
+        );
+        assert_eq!(unknown.to_string(), "IFCPOLYLOOP( (#1,#2,#3) );");
+
+        let unknown = registry
+            .parse_entity(&mut "IFCPOLYLOOP((#1,#2,#3);")
+            .unwrap();
+        assert!(unknown.downcast_ref::<Dummy>().is_some());
+    }
+
+    #[test]
+    fn external_entities_can_be_registered() {
+        let data = "IFCCUSTOMENTITY('custom');";
+
+        let builtin = EntityRegistry::with_builtin();
+        assert!(builtin
+            .parse_entity(&mut &*data)
+            .unwrap()
+            .downcast_ref::<UnknownEntity>()
+            .is_some());
+
+        // the registries for the schemas of files are copied once
+        let schemas = FileSchemas(vec![FileSchema::IFC2X3]);
+        let ifc2x3 = EntityRegistry::for_file(&schemas);
+        assert!(Arc::ptr_eq(&ifc2x3, &EntityRegistry::for_file(&schemas)));
+        assert_eq!(ifc2x3.schema(), Some(FileSchema::IFC2X3));
+
+        EntityRegistry::register_global::<CustomEntity>("IFCCUSTOMENTITY");
+        assert!(EntityRegistry::for_file(&schemas).contains("IFCCUSTOMENTITY"));
+        assert!(!ifc2x3.contains("IFCCUSTOMENTITY"));
+
+        let entity = EntityRegistry::global().parse_entity(&mut &*data).unwrap();
+        assert_eq!(
+            entity.downcast_ref::<CustomEntity>().unwrap().0.value,
+            "custom"
+        );
+        assert_eq!(
+            entity.attribute("Name").unwrap(),
+            StepValue::String("custom".into())
+        );
+        assert_eq!(
+            EntityRegistry::global()
+                .entity_type("IFCCUSTOMENTITY")
+                .unwrap()
+                .name,
+            "IfcCustomEntity"
+        );
+        assert_eq!(entity.to_string(), data);
+    }
+
+    #[test]
+    fn parses_layouts_of_schema() {
+        let data = "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,'Wall',$,$,#3,#4,$);";
+
+        let registry = EntityRegistry::with_builtin();
+        assert!(registry
+            .parse_entity(&mut &*data)
+            .unwrap()
+            .downcast_ref::<UnknownEntity>()
+            .is_some());
+
+        let registry = registry.with_schema(FileSchema::IFC2X3);
+        assert_eq!(registry.schema(), Some(FileSchema::IFC2X3));
+
+        let wall = registry.parse_entity(&mut &*data).unwrap();
+        let wall = wall.downcast_ref::<Wall>().unwrap();
+        assert!(wall.predefined_type.is_omitted());
+
+        let storey = registry
+            .parse_entity(&mut "IFCBUILDINGSTOREY('0DWgwt6o1FOx7466fPk$jl',#2,'Storey',$,$,#3,$,$,.ELEMENT.,0.);")
+            .unwrap();
+        assert!(storey.downcast_ref::<Storey>().is_some());
+
+        // entities with the same layout in all schemas still use the default parsers
+        let point = registry
+            .parse_entity(&mut "IFCCARTESIANPOINT((1.,2.));")
+            .unwrap();
+        assert!(point.downcast_ref::<Point2D>().is_some());
+    }
+
+    #[test]
+    fn rejects_entities_which_dont_match_schema() {
+        let registry = EntityRegistry::with_builtin().with_schema(FileSchema::IFC2X3);
+
+        assert!(registry
+            .parse_entity(
+                &mut "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,'Wall',$,$,#3,#4,$,.SOLIDWALL.);"
+            )
+            .is_err());
+        assert!(registry
+            .parse_entity(&mut "IFCINDEXEDPOLYCURVE(#1,$,.F.);")
+            .is_err());
+        assert!(registry
+            .parse_entity(
+                &mut "IFCSLABSTANDARDCASE('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,#3,#4,$,.FLOOR.);"
+            )
+            .is_err());
+
+        // entities which aren't modelled by this crate are checked as well
+        assert!(registry
+            .parse_entity(&mut "IFCBEAM('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,#3,#4,$);")
+            .unwrap()
+            .downcast_ref::<UnknownEntity>()
+            .is_some());
+        assert!(registry
+            .parse_entity(&mut "IFCBEAM('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,#3,#4,$,.BEAM.);")
+            .is_err());
+
+        // the IFC2X3 layout is rejected in IFC4 files
+        let registry = registry.with_schema(FileSchema::IFC4);
+        assert!(registry
+            .parse_entity(&mut "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,'Wall',$,$,#3,#4,$);")
+            .is_err());
+        assert!(registry
+            .parse_entity(&mut "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,'Wall',$,$,#3,#4,$,$);")
+            .unwrap()
+            .downcast_ref::<Wall>()
+            .is_some());
+    }
+}
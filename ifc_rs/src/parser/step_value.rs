@@ -0,0 +1,231 @@
+use std::{fmt::Display, iter};
+
+use itertools::Itertools;
+use winnow::{
+    ascii::{digit0, digit1},
+    combinator::{alt, delimited, opt, repeat, separated, terminated},
+    error::{ContextError, ErrMode},
+    token::{none_of, one_of, take_while},
+    Parser,
+};
+
+use crate::{
+    id::Id,
+    traits::reflect::{AttributeKind, StepAttribute},
+};
+
+use super::{p_space_or_comment, p_space_or_comment_surrounded, IFCParse, IFCParser};
+
+/// Generic attribute value of an entity instance as defined by the STEP physical file format
+/// (ISO 10303-21). Used for entities which aren't modelled by this crate, see
+/// [`UnknownEntity`](super::unknown_entity::UnknownEntity).
+///
+/// Values are kept exactly as they were written, so they serialize back to the same text. Only
+/// whitespace and comments between the tokens are dropped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepValue {
+    /// `$`, the value isn't provided
+    Omitted,
+    /// `*`, the value is derived from a supertype
+    Inherited,
+    /// reference to another entity instance, e.g. `#12`
+    Id(Id),
+    /// e.g. `42`
+    Integer(i64),
+    /// real number exactly as written, e.g. `1.00000000000000E-5`
+    Real(String),
+    /// contents of a string literal without the surrounding quotes, still STEP encoded
+    String(String),
+    /// enumeration value without the surrounding dots, e.g. `NOTDEFINED` for `.NOTDEFINED.`
+    Enum(String),
+    /// contents of a binary literal without the surrounding quotes, e.g. `0FF` for `"0FF"`
+    Binary(String),
+    /// typed value, e.g. `IFCLABEL('Wall')`
+    Typed(String, Box<StepValue>),
+    /// aggregate of values, e.g. `(#1,#2,#3)`
+    List(Vec<StepValue>),
+}
+
+impl StepValue {
+    /// all entity instances which are referenced by this value, including nested ones
+    pub fn ids(&self) -> Box<dyn Iterator<Item = Id> + '_> {
+        match self {
+            StepValue::Id(id) => Box::new(iter::once(*id)),
+            StepValue::Typed(_, value) => value.ids(),
+            StepValue::List(values) => Box::new(values.iter().flat_map(StepValue::ids)),
+            _ => Box::new(iter::empty()),
+        }
+    }
+
+    /// mutable access to all entity instance references of this value, including nested ones
+    pub fn ids_mut(&mut self) -> Box<dyn Iterator<Item = &mut Id> + '_> {
+        match self {
+            StepValue::Id(id) => Box::new(iter::once(id)),
+            StepValue::Typed(_, value) => value.ids_mut(),
+            StepValue::List(values) => Box::new(values.iter_mut().flat_map(StepValue::ids_mut)),
+            _ => Box::new(iter::empty()),
+        }
+    }
+}
+
+pub(crate) fn p_keyword<'a>() -> impl IFCParser<'a, String> {
+    (
+        one_of(|c: char| c.is_ascii_alphabetic() || c == '_'),
+        take_while(.., |c: char| c.is_ascii_alphanumeric() || c == '_'),
+    )
+        .take()
+        .map(ToOwned::to_owned)
+}
+
+/// Parses a parenthesized list of values, whitespace and comments are allowed between all tokens.
+pub(crate) fn p_values<'a>() -> impl IFCParser<'a, Vec<StepValue>> {
+    delimited(
+        ("(", p_space_or_comment()),
+        separated(
+            ..,
+            terminated(p_value, p_space_or_comment()),
+            (",", p_space_or_comment()),
+        ),
+        ")",
+    )
+}
+
+fn p_value(input: &mut &str) -> Result<StepValue, ErrMode<ContextError>> {
+    let p_sign = || opt(one_of(['+', '-']));
+    let p_exponent = (one_of(['E', 'e']), p_sign(), digit1);
+    let p_real = (p_sign(), digit1, ".", digit0, opt(p_exponent)).take();
+    let p_integer = (p_sign(), digit1).take().try_map(str::parse);
+
+    let p_string = delimited(
+        "'",
+        repeat(.., alt(("''".void(), none_of('\'').void()))).map(|()| ()),
+        "'",
+    )
+    .take();
+    let p_enum = delimited(
+        ".",
+        take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '_'),
+        ".",
+    );
+    let p_binary = delimited('"', take_while(.., |c: char| c.is_ascii_hexdigit()), '"');
+    let p_typed = (
+        p_keyword(),
+        delimited(
+            (p_space_or_comment(), "("),
+            p_space_or_comment_surrounded(p_value),
+            ")",
+        ),
+    );
+
+    alt((
+        "$".value(StepValue::Omitted),
+        "*".value(StepValue::Inherited),
+        Id::parse().map(StepValue::Id),
+        p_real.map(|real: &str| StepValue::Real(real.to_owned())),
+        p_integer.map(StepValue::Integer),
+        p_string.map(|string: &str| StepValue::String(string[1..string.len() - 1].to_owned())),
+        p_enum.map(|value: &str| StepValue::Enum(value.to_owned())),
+        p_binary.map(|value: &str| StepValue::Binary(value.to_owned())),
+        p_typed.map(|(keyword, value)| StepValue::Typed(keyword, Box::new(value))),
+        p_values().map(StepValue::List),
+    ))
+    .parse_next(input)
+}
+
+impl IFCParse for StepValue {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        p_value
+    }
+}
+
+impl Display for StepValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepValue::Omitted => write!(f, "$"),
+            StepValue::Inherited => write!(f, "*"),
+            StepValue::Id(id) => write!(f, "{id}"),
+            StepValue::Integer(integer) => write!(f, "{integer}"),
+            StepValue::Real(real) => write!(f, "{real}"),
+            StepValue::String(string) => write!(f, "'{string}'"),
+            StepValue::Enum(value) => write!(f, ".{value}."),
+            StepValue::Binary(value) => write!(f, "\"{value}\""),
+            StepValue::Typed(keyword, value) => write!(f, "{keyword}({value})"),
+            StepValue::List(values) => write!(f, "({})", values.iter().join(",")),
+        }
+    }
+}
+
+impl StepAttribute for StepValue {
+    const KIND: AttributeKind = AttributeKind::Select;
+
+    fn to_step_value(&self) -> StepValue {
+        self.clone()
+    }
+
+    fn from_step_value(value: &StepValue) -> anyhow::Result<Self> {
+        Ok(value.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::StepValue;
+    use crate::{id::Id, parser::IFCParse};
+
+    #[test]
+    fn parse_values() {
+        let cases = [
+            ("$", StepValue::Omitted),
+            ("*", StepValue::Inherited),
+            ("#42", StepValue::Id(Id(42))),
+            ("-3", StepValue::Integer(-3)),
+            (
+                "6.12303176911189E-17",
+                StepValue::Real("6.12303176911189E-17".into()),
+            ),
+            ("0.", StepValue::Real("0.".into())),
+            ("'it''s'", StepValue::String("it''s".into())),
+            (
+                "'\\X2\\00E4\\X0\\'",
+                StepValue::String("\\X2\\00E4\\X0\\".into()),
+            ),
+            (".NOTDEFINED.", StepValue::Enum("NOTDEFINED".into())),
+            ("\"0FF\"", StepValue::Binary("0FF".into())),
+            (
+                "IFCLABEL('Wall')",
+                StepValue::Typed(
+                    "IFCLABEL".into(),
+                    Box::new(StepValue::String("Wall".into())),
+                ),
+            ),
+            ("()", StepValue::List(vec![])),
+        ];
+
+        for (data, expected) in cases {
+            let value = StepValue::parse().parse(data).unwrap();
+            assert_eq!(value, expected);
+            assert_eq!(value.to_string(), data);
+        }
+    }
+
+    #[test]
+    fn parse_values_with_whitespace_and_comments() {
+        let data = "( (#1 ,$ ) , IFCREAL ( 1. ) /* comment */,\n\t.T. )";
+        let value = StepValue::parse().parse(data).unwrap();
+
+        assert_eq!(value.to_string(), "((#1,$),IFCREAL(1.),.T.)");
+    }
+
+    #[test]
+    fn nested_ids() {
+        let data = "((#1,$),IFCREAL(1.),(#2,(#3)),.T.)";
+        let mut value = StepValue::parse().parse(data).unwrap();
+
+        assert_eq!(value.ids().collect::<Vec<_>>(), [Id(1), Id(2), Id(3)]);
+
+        value.ids_mut().for_each(|id| id.0 += 10);
+        assert_eq!(value.to_string(), "((#11,$),IFCREAL(1.),(#12,(#13)),.T.)");
+    }
+}
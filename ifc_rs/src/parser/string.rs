@@ -1,49 +1,261 @@
-use std::fmt::Display;
+use std::fmt::{Display, Write};
 
+use anyhow::{bail, Context, Result};
 use winnow::Parser;
 
-use crate::parser::{p_quote_word, IFCParse, IFCParser};
+use crate::{
+    parser::{p_quote_word, IFCParse, IFCParser, StepValue},
+    traits::reflect::{AttributeKind, StepAttribute},
+};
 
-// IFC doesn't support UTF8 in all versions and uses a special encoding for things like
-// german umlaute. The easiest thing to use it `\X2\<unicode sequence>\X0\`
+// IFC doesn't support UTF8 in all versions. Strings are restricted to printable ASCII and
+// everything else is written with control directives like `\X2\00E4\X0\` instead.
 //
 // https://technical.buildingsmart.org/resources/ifcimplementationguidance/string-encoding/
-const UMLAUTE_MAP: [(&str, &str); 7] = [
-    ("Ä", r#"\X2\00C4\X0\"#),
-    ("ä", r#"\X2\00E4\X0\"#),
-    ("Ö", r#"\X2\00D6\X0\"#),
-    ("ö", r#"\X2\00F6\X0\"#),
-    ("Ü", r#"\X2\00DC\X0\"#),
-    ("ü", r#"\X2\00FC\X0\"#),
-    ("ß", r#"\X2\00DF\X0\"#),
+
+/// Upper halves (`0xA0..=0xFF`) of ISO 8859-2 to ISO 8859-9, selected with `\PB\` to `\PI\`.
+/// ISO 8859-1 (`\PA\`) maps directly to unicode. Unassigned positions are `U+FFFD`.
+const CODE_PAGES: [&str; 8] = [
+    // ISO 8859-2
+    "\u{a0}Ą˘Ł¤ĽŚ§¨ŠŞŤŹ\u{ad}ŽŻ°ą˛ł´ľśˇ¸šşťź˝žż\
+     ŔÁÂĂÄĹĆÇČÉĘËĚÍÎĎĐŃŇÓÔŐÖ×ŘŮÚŰÜÝŢß\
+     ŕáâăäĺćçčéęëěíîďđńňóôőö÷řůúűüýţ˙",
+    // ISO 8859-3
+    "\u{a0}Ħ˘£¤\u{fffd}Ĥ§¨İŞĞĴ\u{ad}\u{fffd}Ż°ħ²³´µĥ·¸ışğĵ½\u{fffd}ż\
+     ÀÁÂ\u{fffd}ÄĊĈÇÈÉÊËÌÍÎÏ\u{fffd}ÑÒÓÔĠÖ×ĜÙÚÛÜŬŜß\
+     àáâ\u{fffd}äċĉçèéêëìíîï\u{fffd}ñòóôġö÷ĝùúûüŭŝ˙",
+    // ISO 8859-4
+    "\u{a0}ĄĸŖ¤ĨĻ§¨ŠĒĢŦ\u{ad}Ž¯°ą˛ŗ´ĩļˇ¸šēģŧŊžŋ\
+     ĀÁÂÃÄÅÆĮČÉĘËĖÍÎĪĐŅŌĶÔÕÖ×ØŲÚÛÜŨŪß\
+     āáâãäåæįčéęëėíîīđņōķôõö÷øųúûüũū˙",
+    // ISO 8859-5
+    "\u{a0}ЁЂЃЄЅІЇЈЉЊЋЌ\u{ad}ЎЏАБВГДЕЖЗИЙКЛМНОП\
+     РСТУФХЦЧШЩЪЫЬЭЮЯабвгдежзийклмноп\
+     рстуфхцчшщъыьэюя\u{2116}ёђѓєѕіїјљњћќ§ўџ",
+    // ISO 8859-6
+    "\u{a0}\u{fffd}\u{fffd}\u{fffd}¤\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{60c}\u{ad}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{61b}\u{fffd}\u{fffd}\u{fffd}\u{61f}\
+     \u{fffd}\u{621}\u{622}\u{623}\u{624}\u{625}\u{626}\u{627}\u{628}\u{629}\u{62a}\u{62b}\u{62c}\u{62d}\u{62e}\u{62f}\u{630}\u{631}\u{632}\u{633}\u{634}\u{635}\u{636}\u{637}\u{638}\u{639}\u{63a}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\
+     \u{640}\u{641}\u{642}\u{643}\u{644}\u{645}\u{646}\u{647}\u{648}\u{649}\u{64a}\u{64b}\u{64c}\u{64d}\u{64e}\u{64f}\u{650}\u{651}\u{652}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}",
+    // ISO 8859-7
+    "\u{a0}\u{2018}\u{2019}£\u{20ac}\u{20af}¦§¨©ͺ«¬\u{ad}\u{fffd}\u{2015}°±²³΄΅Ά·ΈΉΊ»Ό½ΎΏ\
+     ΐΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠΡ\u{fffd}ΣΤΥΦΧΨΩΪΫάέήί\
+     ΰαβγδεζηθικλμνξοπρςστυφχψωϊϋόύώ\u{fffd}",
+    // ISO 8859-8
+    "\u{a0}\u{fffd}¢£¤¥¦§¨©×«¬\u{ad}®¯°±²³´µ¶·¸¹÷»¼½¾\u{fffd}\
+     \u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{fffd}\u{2017}\
+     \u{5d0}\u{5d1}\u{5d2}\u{5d3}\u{5d4}\u{5d5}\u{5d6}\u{5d7}\u{5d8}\u{5d9}\u{5da}\u{5db}\u{5dc}\u{5dd}\u{5de}\u{5df}\u{5e0}\u{5e1}\u{5e2}\u{5e3}\u{5e4}\u{5e5}\u{5e6}\u{5e7}\u{5e8}\u{5e9}\u{5ea}\u{fffd}\u{fffd}\u{200e}\u{200f}\u{fffd}",
+    // ISO 8859-9
+    "\u{a0}¡¢£¤¥¦§¨©ª«¬\u{ad}®¯°±²³´µ¶·¸¹º»¼½¾¿\
+     ÀÁÂÃÄÅÆÇÈÉÊËÌÍÎÏĞÑÒÓÔÕÖ×ØÙÚÛÜİŞß\
+     àáâãäåæçèéêëìíîïğñòóôõö÷øùúûüışÿ",
 ];
+/// Encodes a string for a STEP string literal (without the surrounding quotes).
+///
+/// Printable ASCII is kept, `'` and `\` are escaped and everything else is written as
+/// `\X2\...\X0\` (basic multilingual plane) or `\X4\...\X0\` (all other planes).
+pub(crate) fn encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => encoded.push_str("''"),
+            '\\' => encoded.push_str(r"\\"),
+            ' '..='~' => encoded.push(c),
+            _ => {
+                let wide = is_wide(c);
+                encoded.push_str(if wide { r"\X4\" } else { r"\X2\" });
+
+                // consecutive characters share one directive
+                let mut next = Some(c);
+                while let Some(c) = next {
+                    if wide {
+                        write!(encoded, "{:08X}", c as u32).unwrap();
+                    } else {
+                        write!(encoded, "{:04X}", c as u32).unwrap();
+                    }
+
+                    next =
+                        chars.next_if(|&c| !c.is_ascii_graphic() && c != ' ' && is_wide(c) == wide);
+                }
+
+                encoded.push_str(r"\X0\");
+            }
+        }
+    }
+
+    encoded
+}
 
-fn translate_encode(inp: impl AsRef<str>) -> String {
-    let mut value = inp.as_ref().to_owned();
-    for (uft8, encoded) in UMLAUTE_MAP {
-        value = value.replace(uft8, encoded);
+/// whether the character is outside of the basic multilingual plane
+fn is_wide(c: char) -> bool {
+    c as u32 > 0xFFFF
+}
+
+/// Decodes the contents of a STEP string literal (without the surrounding quotes), see
+/// [`encode`]. Characters which aren't part of a control directive are kept as they are, so
+/// plain UTF8 written by newer exporters works as well.
+///
+/// Returns `None` if a control directive is malformed.
+pub(crate) fn decode(raw: &str) -> Option<String> {
+    let mut decoded = String::with_capacity(raw.len());
+    // `None` is ISO 8859-1, the default
+    let mut code_page = None;
+    let mut rest = raw;
+
+    while let Some(c) = rest.chars().next() {
+        rest = match c {
+            '\'' => {
+                decoded.push('\'');
+                rest.strip_prefix("''")?
+            }
+            '\\' => decode_directive(rest, &mut code_page, &mut decoded)?,
+            _ => {
+                decoded.push(c);
+                &rest[c.len_utf8()..]
+            }
+        };
     }
-    value
+
+    Some(decoded)
 }
 
-fn translate_decode(inp: impl AsRef<str>) -> String {
-    let mut value = inp.as_ref().to_owned();
-    for (uft8, encoded) in UMLAUTE_MAP {
-        value = value.replace(encoded, uft8);
+/// Decodes the control directive at the start of `input` and returns the remaining input.
+fn decode_directive<'a>(
+    input: &'a str,
+    code_page: &mut Option<&'static str>,
+    decoded: &mut String,
+) -> Option<&'a str> {
+    if let Some(rest) = input.strip_prefix(r"\\") {
+        decoded.push('\\');
+        Some(rest)
+    } else if let Some(rest) = input.strip_prefix(r"\S\") {
+        // character of the upper half of the current code page, offset by 0x80
+        let c = rest.chars().next().filter(|c| (' '..='~').contains(c))?;
+        decoded.push(upper_half(*code_page, c as u32 + 0x80));
+        Some(&rest[1..])
+    } else if let Some(rest) = input.strip_prefix(r"\P") {
+        let (page, rest) = rest.split_at_checked(1)?;
+        let page = page.chars().next().filter(|c| ('A'..='I').contains(c))?;
+        *code_page = (page != 'A').then(|| CODE_PAGES[page as usize - 'B' as usize]);
+        rest.strip_prefix('\\')
+    } else if let Some(rest) = input.strip_prefix(r"\X2\") {
+        let (hex, rest) = rest.split_once(r"\X0\")?;
+        let units = hex_values(hex, 4)?.map(|unit| unit as u16);
+        for c in char::decode_utf16(units) {
+            decoded.push(c.ok()?);
+        }
+        Some(rest)
+    } else if let Some(rest) = input.strip_prefix(r"\X4\") {
+        let (hex, rest) = rest.split_once(r"\X0\")?;
+        for value in hex_values(hex, 8)? {
+            decoded.push(char::from_u32(value)?);
+        }
+        Some(rest)
+    } else if let Some(rest) = input.strip_prefix(r"\X\") {
+        let (hex, rest) = rest.split_at_checked(2)?;
+        decoded.push(char::from(hex_values(hex, 2)?.next()? as u8));
+        Some(rest)
+    } else {
+        None
     }
-    value
+}
+
+/// character of the code page at the given position in `0x80..=0xFF`
+fn upper_half(code_page: Option<&str>, position: u32) -> char {
+    match code_page {
+        Some(code_page) if position >= 0xA0 => code_page
+            .chars()
+            .nth(position as usize - 0xA0)
+            .unwrap_or(char::REPLACEMENT_CHARACTER),
+        _ => char::from_u32(position).unwrap_or(char::REPLACEMENT_CHARACTER),
+    }
+}
+
+/// splits the hex digits into values of `width` digits each
+fn hex_values(hex: &str, width: usize) -> Option<impl Iterator<Item = u32> + '_> {
+    let values = hex.as_bytes().chunks_exact(width);
+    if !values.remainder().is_empty() || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(values.map(|digits| {
+        digits.iter().fold(0, |value, &digit| {
+            value * 16 + char::from(digit).to_digit(16).unwrap()
+        })
+    }))
 }
 
 /// A label is the term by which something may be referred to.
 /// It is a string which represents the human-interpretable name of something and shall have a natural-language meaning.
 ///
 /// https://standards.buildingsmart.org/IFC/RELEASE/IFC2x3/TC1/HTML/ifcmeasureresource/lexical/ifclabel.htm
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct StringPrimitive(pub String);
+///
+/// The string is kept as it was written, e.g. `\X\FC` instead of `\X2\00FC\X0\`, and written
+/// back like that as long as the decoded text doesn't change.
+#[derive(Clone)]
+pub struct StringPrimitive {
+    /// the decoded text
+    pub value: String,
+    /// contents of the literal it was read from, if that isn't the encoding of `value`
+    raw: Option<Box<str>>,
+}
+
+impl StringPrimitive {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            raw: None,
+        }
+    }
+
+    fn from_raw(raw: &str) -> Option<Self> {
+        let value = decode(raw)?;
+        let raw = (encode(&value) != raw).then(|| raw.into());
+
+        Some(Self { value, raw })
+    }
+
+    /// contents of the string literal, in the form it was read from if the text is unchanged
+    fn encoded(&self) -> String {
+        match &self.raw {
+            Some(raw) if decode(raw).is_some_and(|decoded| decoded == self.value) => {
+                raw.to_string()
+            }
+            _ => encode(&self.value),
+        }
+    }
+}
 
 impl<S: AsRef<str>> From<S> for StringPrimitive {
     fn from(value: S) -> Self {
-        Self(translate_decode(value))
+        Self::new(value.as_ref())
+    }
+}
+
+impl std::fmt::Debug for StringPrimitive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StringPrimitive").field(&self.value).finish()
+    }
+}
+
+impl PartialEq for StringPrimitive {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for StringPrimitive {}
+
+impl PartialOrd for StringPrimitive {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StringPrimitive {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value.cmp(&other.value)
     }
 }
 
@@ -52,12 +264,125 @@ impl IFCParse for StringPrimitive {
     where
         Self: Sized,
     {
-        p_quote_word().map(Self::from)
+        p_quote_word().verify_map(|raw| Self::from_raw(&raw))
     }
 }
 
 impl Display for StringPrimitive {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "'{label}'", label = translate_encode(self.0.as_str()))
+        write!(f, "'{label}'", label = self.encoded())
+    }
+}
+
+impl StepAttribute for StringPrimitive {
+    const KIND: AttributeKind = AttributeKind::String;
+
+    fn to_step_value(&self) -> StepValue {
+        StepValue::String(self.encoded())
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        match value {
+            StepValue::String(raw) => {
+                Self::from_raw(raw).with_context(|| format!("invalid string `{raw}`"))
+            }
+            value => bail!("expected string, found `{value}`"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use super::{decode, encode, StringPrimitive};
+    use crate::parser::IFCParse;
+
+    #[test]
+    fn unicode_round_trips() {
+        for value in [
+            "Wand",
+            "O'Brien",
+            r"C:\Projects\Haus",
+            "Außenwand",
+            "Fenêtre coulissante",
+            "Ściana zewnętrzna",
+            "外墙 外壁",
+            "Stützen 🏗️ line\nbreak",
+            "",
+        ] {
+            let encoded = encode(value);
+            assert!(
+                encoded.chars().all(|c| c.is_ascii_graphic() || c == ' '),
+                "{encoded}"
+            );
+            assert_eq!(decode(&encoded).as_deref(), Some(value));
+
+            let written = StringPrimitive::from(value).to_string();
+            let parsed = StringPrimitive::parse().parse(&written).unwrap();
+            assert_eq!(parsed.value, value);
+        }
+    }
+
+    #[test]
+    fn encodes_like_other_exporters() {
+        assert_eq!(encode("Außenwand"), r"Au\X2\00DF\X0\enwand");
+        assert_eq!(encode("Öl"), r"\X2\00D6\X0\l");
+        assert_eq!(encode("外墙"), r"\X2\59165899\X0\");
+        assert_eq!(encode("🏗"), r"\X4\0001F3D7\X0\");
+        assert_eq!(encode(r"it's a\b"), r"it''s a\\b");
+    }
+
+    #[test]
+    fn decodes_control_directives() {
+        let cases = [
+            ("O''Brien", "O'Brien"),
+            (r"a\\b", r"a\b"),
+            (r"\S\Dquivalent", "Äquivalent"),
+            (r"\X\C4quivalent", "Äquivalent"),
+            (r"\PE\\S\F", "Ц"),
+            (r"\PB\\S\1\PA\\S\1", "ą±"),
+            (r"\X2\00C400D6\X0\", "ÄÖ"),
+            (r"\X2\D83CDFD7\X0\", "🏗"),
+            (r"\X4\0001F3D7\X0\", "🏗"),
+            ("Straße", "Straße"),
+        ];
+
+        for (raw, expected) in cases {
+            assert_eq!(decode(raw).as_deref(), Some(expected), "{raw}");
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_directives() {
+        for raw in [
+            r"\X2\00C\X0\",
+            r"\X2\00C4",
+            r"\X4\0011FFFF\X0\",
+            r"\X2\D83C\X0\",
+            r"\Q\",
+            r"\PZ\",
+            r"\X\G1",
+            "a'b",
+        ] {
+            assert_eq!(decode(raw), None, "{raw}");
+        }
+    }
+
+    #[test]
+    fn keeps_the_input_form_of_unchanged_strings() {
+        let mut parsed = StringPrimitive::parse().parse(r"'T\X\FCr'").unwrap();
+        assert_eq!(parsed.value, "Tür");
+        assert_eq!(parsed.to_string(), r"'T\X\FCr'");
+
+        parsed.value.push('e');
+        assert_eq!(parsed.to_string(), r"'T\X2\00FC\X0\re'");
+    }
+
+    #[test]
+    fn parses_escaped_quotes() {
+        let parsed = StringPrimitive::parse().parse("'O''Brien'").unwrap();
+        assert_eq!(parsed.value, "O'Brien");
+        assert_eq!(parsed.to_string(), "'O''Brien'");
     }
 }
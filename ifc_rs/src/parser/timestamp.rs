@@ -1,10 +1,12 @@
 use std::fmt::Display;
 
+use anyhow::{Context, Result};
 use chrono::Utc;
 use winnow::ascii::dec_int;
 use winnow::Parser;
 
-use crate::parser::{IFCParse, IFCParser};
+use crate::parser::{integer::IntegerPrimitive, IFCParse, IFCParser, StepValue};
+use crate::traits::reflect::{AttributeKind, StepAttribute};
 
 /// An indication of date and time by measuring the number of seconds which
 /// have elapsed since the beginning of the year 1970.
@@ -33,3 +35,19 @@ impl Display for IfcTimestamp {
         write!(f, "{}", self.0.timestamp())
     }
 }
+
+impl StepAttribute for IfcTimestamp {
+    const KIND: AttributeKind = AttributeKind::Integer;
+
+    fn to_step_value(&self) -> StepValue {
+        StepValue::Integer(self.0.timestamp())
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        let IntegerPrimitive(timestamp) = IntegerPrimitive::from_step_value(value)?;
+
+        chrono::DateTime::from_timestamp(timestamp, 0)
+            .map(Self)
+            .with_context(|| format!("invalid timestamp `{timestamp}`"))
+    }
+}
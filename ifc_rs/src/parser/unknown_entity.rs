@@ -0,0 +1,217 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use winnow::{
+    combinator::{preceded, terminated},
+    Parser,
+};
+
+use crate::{id::Id, prelude::*};
+
+use super::{
+    p_space_or_comment,
+    step_value::{p_keyword, p_values, StepValue},
+    EntityRegistry, IFCParse, IFCParser,
+};
+
+/// Entity which isn't modelled by this crate (yet), e.g. `IFCPOLYLOOP((#1,#2,#3));`.
+///
+/// In contrast to [`Dummy`](super::dummy::Dummy), the attributes are parsed into generic
+/// [`StepValue`]s, so references to other entities can still be traversed. If the entity was
+/// written differently than this crate would write it, e.g. with whitespace or comments between
+/// the attributes, the source text is kept and written back as long as the attributes don't
+/// change.
+#[derive(Debug, Clone)]
+pub struct UnknownEntity {
+    /// keyword of the entity, e.g. `IFCPOLYLOOP`
+    pub keyword: String,
+    /// attributes of the entity in the order in which they were written
+    pub args: Vec<StepValue>,
+
+    source: Option<Box<SourceText>>,
+}
+
+/// Text of an entity as it was parsed, together with the attributes at that time.
+#[derive(Debug, Clone)]
+struct SourceText {
+    text: String,
+    args: Vec<StepValue>,
+}
+
+impl UnknownEntity {
+    /// creates the entity from its keyword and attributes
+    pub fn new(keyword: impl Into<String>, args: Vec<StepValue>) -> Self {
+        Self {
+            keyword: keyword.into(),
+            args,
+            source: None,
+        }
+    }
+
+    /// all entity instances which are referenced by the attributes of this entity
+    pub fn ids(&self) -> impl Iterator<Item = Id> + '_ {
+        self.args.iter().flat_map(StepValue::ids)
+    }
+
+    /// mutable access to all entity instance references in the attributes of this entity
+    pub fn ids_mut(&mut self) -> impl Iterator<Item = &mut Id> + '_ {
+        self.args.iter_mut().flat_map(StepValue::ids_mut)
+    }
+
+    /// Generic representation of any entity, used to convert entities into other file formats.
+    /// This fails for [`Dummy`] entities, since their attributes couldn't be parsed.
+    pub(crate) fn from_entity(entity: &dyn IfcType) -> Result<Self> {
+        if let Some(unknown) = entity.downcast_ref::<UnknownEntity>() {
+            return Ok(unknown.clone());
+        }
+
+        if let Some(dummy) = entity.downcast_ref::<Dummy>() {
+            bail!("can't convert unparsed entity `{dummy}`");
+        }
+
+        Ok(Self::new(entity.keyword(), entity.attribute_values()))
+    }
+
+    /// Reads the text of a [`Dummy`] as generic entity, which fails if its attributes are
+    /// malformed.
+    pub(crate) fn from_dummy(dummy: &Dummy) -> Option<Self> {
+        let text = dummy.to_string();
+        let entity = Self::parse().parse(text.as_str()).ok()?;
+
+        Some(entity)
+    }
+
+    /// Parses the entity into the type which is registered for its keyword, this is the
+    /// counterpart to [`UnknownEntity::from_entity`].
+    pub(crate) fn into_entity(self, registry: &EntityRegistry) -> Result<Box<dyn IfcType>> {
+        let text = self.to_string();
+        let mut input = text.as_str();
+
+        registry
+            .parse_entity(&mut input)
+            .ok()
+            .filter(|_| input.is_empty())
+            .with_context(|| format!("invalid entity `{text}`"))
+    }
+}
+
+impl PartialEq for UnknownEntity {
+    fn eq(&self, other: &Self) -> bool {
+        self.keyword == other.keyword && self.args == other.args
+    }
+}
+
+impl IFCParse for UnknownEntity {
+    fn parse<'a>() -> impl IFCParser<'a, Self> {
+        terminated(
+            (p_keyword(), preceded(p_space_or_comment(), p_values())),
+            preceded(p_space_or_comment(), ";"),
+        )
+        .with_taken()
+        .map(|((keyword, args), text): (_, &str)| {
+            let mut entity = Self::new(keyword, args);
+
+            if entity.to_string() != text {
+                entity.source = Some(Box::new(SourceText {
+                    text: text.to_owned(),
+                    args: entity.args.clone(),
+                }));
+            }
+
+            entity
+        })
+    }
+}
+
+impl Display for UnknownEntity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            Some(source) if source.args == self.args => write!(f, "{}", source.text),
+            _ => write!(f, "{}({});", self.keyword, self.args.iter().join(",")),
+        }
+    }
+}
+
+impl IfcVerify for UnknownEntity {
+    fn visit_ids(&self, visitor: &mut dyn FnMut(&'static str, Id)) {
+        self.ids().for_each(|id| visitor("args", id));
+    }
+
+    fn visit_ids_mut(&mut self, visitor: &mut dyn FnMut(&'static str, &mut Id)) {
+        self.ids_mut().for_each(|id| visitor("args", id));
+    }
+
+    fn keyword(&self) -> &str {
+        &self.keyword
+    }
+
+    fn attribute_values(&self) -> Vec<StepValue> {
+        self.args.clone()
+    }
+
+    fn set_attribute_value(&mut self, index: usize, value: &StepValue) -> Result<()> {
+        let arg = self
+            .args
+            .get_mut(index)
+            .with_context(|| format!("{} has no attribute {index}", self.keyword))?;
+        *arg = value.clone();
+
+        Ok(())
+    }
+}
+
+impl IfcType for UnknownEntity {}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, str::FromStr};
+
+    use winnow::Parser;
+
+    use super::UnknownEntity;
+    use crate::{id::Id, parser::IFCParse, prelude::*};
+
+    #[test]
+    fn parse_unknown_entity() {
+        let data = "IFCFACETEDBREP(#1234);";
+        let entity = UnknownEntity::parse().parse(data).unwrap();
+
+        assert_eq!(entity.keyword, "IFCFACETEDBREP");
+        assert_eq!(entity.ids().collect::<Vec<_>>(), [Id(1234)]);
+        assert_eq!(entity.to_string(), data);
+    }
+
+    #[test]
+    fn keeps_source_text_until_attributes_change() {
+        let data = "IFCPOLYLOOP( (#1, #2,#3) /* loop */ );";
+        let mut entity = UnknownEntity::parse().parse(data).unwrap();
+
+        assert_eq!(entity.args.len(), 1);
+        assert_eq!(entity.ids().collect::<Vec<_>>(), [Id(1), Id(2), Id(3)]);
+        assert_eq!(entity.to_string(), data);
+
+        entity.ids_mut().for_each(|id| id.0 += 1);
+        assert_eq!(entity.to_string(), "IFCPOLYLOOP((#2,#3,#4));");
+    }
+
+    #[test]
+    fn unknown_entities_round_trip() {
+        let data = std::fs::read_to_string("resources/AC20-FZK-Haus.ifc").unwrap();
+        let lines: HashMap<_, _> = data
+            .lines()
+            .filter_map(|line| line.split_once("= "))
+            .collect();
+
+        let ifc = IFC::from_str(&data).unwrap();
+
+        let unknown = ifc
+            .data
+            .iter()
+            .filter_map(|(id, entity)| Some((id, entity.downcast_ref::<UnknownEntity>()?)))
+            .inspect(|(id, entity)| assert_eq!(entity.to_string(), lines[id.to_string().as_str()]))
+            .count();
+
+        assert!(unknown > 6000, "only {unknown} unknown entities");
+    }
+}
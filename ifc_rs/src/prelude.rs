@@ -13,7 +13,18 @@ pub use super::{
     id::{Id, IdOr, IfcGloballyUniqueId, TypedId},
     ifc_builder::prelude::*,
     ifc_extractor::prelude::*,
+    meta::{
+        datamap::{DanglingReference, DanglingReferences, InverseReference},
+        header::{
+            description::{ModelView, ModelViewDefinition},
+            entity::{FilePopulation, HeaderEntity, SectionContext, SectionLanguage},
+            schema::FileSchema,
+        },
+    },
     IFC,
 };
 
-pub use super::parser::{dummy::Dummy, timestamp::IfcTimestamp};
+pub use super::parser::{
+    dummy::Dummy, real::RealFormat, step_value::StepValue, timestamp::IfcTimestamp,
+    unknown_entity::UnknownEntity,
+};
@@ -15,7 +15,9 @@ use super::extended_base::{ExtendedPropertyBase, ExtendedPropertyBuilder};
 ///
 /// https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/IfcMaterialProperties.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcMaterialProperties")]
 pub struct MaterialProperties {
+    #[inherited]
     base: ExtendedPropertyBase,
 
     /// Reference to the material definition to which the set of properties is assigned.
@@ -1,6 +1,4 @@
-use winnow::combinator::alt;
-
-use crate::{parser::*, prelude::*};
+use crate::parser::*;
 
 pub(crate) mod base;
 pub(crate) mod extended_base;
@@ -12,11 +10,9 @@ pub(crate) mod single_value;
 pub struct Properties;
 
 impl Properties {
-    pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
-        alt((
-            material::MaterialProperties::parse_any(),
-            set::PropertySet::parse_any(),
-            single_value::PropertySingleValue::parse_any(),
-        ))
+    pub(crate) fn register(registry: &mut EntityRegistry) {
+        registry.register::<material::MaterialProperties>("IFCMATERIALPROPERTIES");
+        registry.register::<set::PropertySet>("IFCPROPERTYSET");
+        registry.register::<single_value::PropertySingleValue>("IFCPROPERTYSINGLEVALUE");
     }
 }
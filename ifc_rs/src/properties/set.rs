@@ -30,13 +30,16 @@ use crate::{parser::*, prelude::*};
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifckernel/lexical/ifcpropertyset.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcPropertySet")]
 pub struct PropertySet {
+    #[inherited]
     root: Root,
 
     /// Contained set of properties. For property sets defined as part of the IFC Object model, the
     /// property objects within a property set are defined as part of the standard. If a property
     /// is not contained within the set of predefined properties, its value has not been set at
     /// this time.
+    #[ifc(name = "HasProperties")]
     pub properties: IfcList<TypedId<PropertySingleValue>>,
 }
 
@@ -24,10 +24,13 @@ use crate::{parser::*, prelude::*};
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/schema/ifcpropertyresource/lexical/ifcpropertysinglevalue.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcPropertySingleValue")]
 pub struct PropertySingleValue {
+    #[inherited]
     base: PropertyBase,
 
     /// Value and measure type of this property.
+    #[ifc(name = "NominalValue")]
     pub value: IfcValue,
     /// Unit for the nominal value, if not given, the default value for the measure type (given by
     /// the TYPE of nominal value) is used as defined by the global unit assignment at IfcProject.
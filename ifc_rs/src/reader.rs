@@ -0,0 +1,439 @@
+use std::{collections::VecDeque, io::BufRead, sync::Arc};
+
+use anyhow::{bail, Context, Result};
+use winnow::Parser;
+
+#[cfg(feature = "rayon")]
+use crate::meta::datamap::p_lines_parallel_till_eof;
+#[cfg(not(feature = "rayon"))]
+use crate::meta::datamap::p_lines_till_eof;
+use crate::{
+    id::Id,
+    meta::{datamap::DataMap, footer::Footer, header::Header},
+    parser::{p_space_or_comment_surrounded, EntityRegistry, IFCParse, IfcParseError},
+    prelude::*,
+};
+
+/// Amount of statement text which is collected before it gets parsed in one go. Constructing the
+/// entity parsers isn't free, so parsing each statement on its own is noticeably slower.
+#[cfg(not(feature = "rayon"))]
+const BATCH_SIZE: usize = 64 * 1024;
+/// With the `rayon` feature, the batches are split up into chunks which are parsed on multiple
+/// threads, so they have to be larger to keep the threads busy.
+#[cfg(feature = "rayon")]
+const BATCH_SIZE: usize = 1024 * 1024;
+
+/// Streaming reader for IFC STEP files.
+///
+/// In contrast to [`IFC::from_str`](std::str::FromStr::from_str), the reader never holds the
+/// whole file in memory. The header is parsed on construction and the entities of the DATA
+/// section are yielded one by one while iterating, so huge models can be filtered or indexed
+/// without materialising them. Use [`IfcReader::into_ifc`] to collect everything into an [`IFC`].
+///
+/// The entities are parsed with the attribute layouts of the schema which is declared in the
+/// header, see [`EntityRegistry::with_schema`]. With the `rayon` feature enabled, the entities are
+/// read in batches which are parsed on multiple threads.
+///
+/// Iteration stops after the first error.
+pub struct IfcReader<R: BufRead> {
+    reader: R,
+
+    header: Header,
+    footer: Option<Footer>,
+    registry: Arc<EntityRegistry>,
+
+    scanner: StatementScanner,
+    pending: VecDeque<(Id, Box<dyn IfcType>)>,
+    end_of_data: bool,
+    failed: bool,
+}
+
+impl<R: BufRead> IfcReader<R> {
+    /// Creates the reader and parses everything up to and including the `DATA;` keyword.
+    pub fn new(mut reader: R) -> Result<Self> {
+        let mut scanner = StatementScanner::default();
+
+        let mut header = String::new();
+        loop {
+            let statement = scanner
+                .next_statement(&mut reader)?
+                .context("unexpected end of file in header")?;
+            header.push_str(&statement.text);
+
+            if is_keyword(&statement.text, "ENDSEC;") {
+                break;
+            }
+        }
+
+        let header = Header::parse()
+            .parse(&header)
+            .map_err(|err| IfcParseError::new(&header, err))?;
+
+        let statement = scanner
+            .next_statement(&mut reader)?
+            .context("unexpected end of file, expected `DATA;`")?;
+        if !is_keyword(&statement.text, "DATA;") {
+            bail!(statement.error_at_start("expected `DATA;`"));
+        }
+
+        Ok(Self {
+            reader,
+            registry: EntityRegistry::for_file(&header.schema),
+            header,
+            footer: None,
+            scanner,
+            pending: VecDeque::new(),
+            end_of_data: false,
+            failed: false,
+        })
+    }
+
+    /// The header of the file, available right after construction.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// The footer of the file, available once all entities have been read.
+    pub fn footer(&self) -> Option<&Footer> {
+        self.footer.as_ref()
+    }
+
+    /// Reads all remaining entities into an [`IFC`] and verifies the id types of its entities.
+    pub fn into_ifc(mut self) -> Result<IFC> {
+        let data = DataMap::from(self.by_ref().collect::<Result<Vec<_>>>()?);
+
+        let ifc = IFC {
+            header: self.header,
+            data,
+            footer: self.footer.context("missing footer")?,
+        };
+        ifc.verify()?;
+
+        Ok(ifc)
+    }
+
+    fn next_entity(&mut self) -> Result<Option<(Id, Box<dyn IfcType>)>> {
+        if self.pending.is_empty() && !self.end_of_data {
+            self.read_batch()?;
+        }
+
+        match self.pending.pop_front() {
+            Some(entity) => Ok(Some(entity)),
+            None => {
+                self.read_footer()?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Parses the next few entity statements into `pending`.
+    fn read_batch(&mut self) -> Result<()> {
+        let mut batch = Statement {
+            text: String::new(),
+            position: self.scanner.position,
+        };
+
+        while batch.text.len() < BATCH_SIZE {
+            let statement = self
+                .scanner
+                .next_statement(&mut self.reader)?
+                .context("unexpected end of file, expected `ENDSEC;`")?;
+
+            if is_keyword(&statement.text, "ENDSEC;") {
+                self.end_of_data = true;
+                break;
+            }
+
+            batch.text.push_str(&statement.text);
+        }
+
+        #[cfg(feature = "rayon")]
+        let mut p_lines = p_lines_parallel_till_eof(self.registry.clone());
+        #[cfg(not(feature = "rayon"))]
+        let mut p_lines = p_lines_till_eof(self.registry.clone());
+
+        let entities = p_lines
+            .parse(&batch.text)
+            .map_err(|err| batch.shift(IfcParseError::new(&batch.text, err)))?;
+        self.pending.extend(entities);
+
+        Ok(())
+    }
+
+    fn read_footer(&mut self) -> Result<()> {
+        let mut footer = String::new();
+        while let Some(statement) = self.scanner.next_statement(&mut self.reader)? {
+            footer.push_str(&statement.text);
+        }
+
+        self.footer = Some(
+            Footer::parse()
+                .parse(&footer)
+                .map_err(|err| IfcParseError::new(&footer, err))?,
+        );
+
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Iterator for IfcReader<R> {
+    type Item = Result<(Id, Box<dyn IfcType>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.footer.is_some() {
+            return None;
+        }
+
+        let entity = self.next_entity().transpose();
+        self.failed = matches!(entity, Some(Err(_)));
+
+        entity
+    }
+}
+
+pub(crate) fn is_keyword(statement: &str, keyword: &'static str) -> bool {
+    p_space_or_comment_surrounded(keyword)
+        .parse(statement)
+        .is_ok()
+}
+
+/// Position of a statement within the whole input.
+#[derive(Debug, Default, Clone, Copy)]
+struct Position {
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+struct Statement {
+    text: String,
+    position: Position,
+}
+
+impl Statement {
+    /// Maps an error relative to this statement to the whole input.
+    fn shift(&self, mut error: IfcParseError) -> IfcParseError {
+        if error.line == 1 {
+            error.column += self.position.column;
+        }
+        error.offset += self.position.offset;
+        error.line += self.position.line;
+
+        error
+    }
+
+    fn error_at_start(&self, message: &str) -> String {
+        format!(
+            "parsing failed at line {line}: {message}",
+            line = self.position.line + 1
+        )
+    }
+}
+
+/// Splits the input into statements which end with a `;` outside of strings and comments. The
+/// statements keep their leading whitespace, so concatenating them yields the input again.
+pub(crate) fn split_statements(input: &str) -> impl Iterator<Item = &str> {
+    let mut scanner = StatementScanner::default();
+    let mut rest = input;
+
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut end = 0;
+        loop {
+            let chunk_end = rest[end..]
+                .find(';')
+                .map(|i| end + i + 1)
+                .unwrap_or(rest.len());
+            let done = scanner.scan(&rest.as_bytes()[end..chunk_end]);
+            end = chunk_end;
+
+            if done || end == rest.len() {
+                break;
+            }
+        }
+
+        let (statement, tail) = rest.split_at(end);
+        rest = tail;
+
+        Some(statement)
+    })
+}
+
+/// Keeps track of strings and comments across chunks to find the end of statements.
+#[derive(Default)]
+pub(crate) struct StatementScanner {
+    in_string: bool,
+    in_comment: bool,
+    position: Position,
+}
+
+impl StatementScanner {
+    /// Reads the input up to the next `;` which isn't part of a string or comment.
+    fn next_statement(&mut self, reader: &mut impl BufRead) -> Result<Option<Statement>> {
+        let mut bytes = Vec::new();
+
+        loop {
+            let start = bytes.len();
+            let read = reader.read_until(b';', &mut bytes)?;
+
+            if read == 0 || self.scan(&bytes[start..]) {
+                break;
+            }
+        }
+
+        if bytes.is_empty() {
+            return Ok(None);
+        }
+
+        let position = self.position;
+        self.advance(&bytes);
+
+        let text = String::from_utf8(bytes).context("file is not valid UTF-8")?;
+
+        Ok(Some(Statement { text, position }))
+    }
+
+    /// Scans the chunk and returns whether it ends a statement. Chunks always end with `;` or at
+    /// the end of the input, so `/*` and `*/` are never split up between two chunks.
+    pub(crate) fn scan(&mut self, chunk: &[u8]) -> bool {
+        let mut previous = 0;
+
+        for &byte in chunk {
+            match (self.in_comment, self.in_string, previous, byte) {
+                (true, _, b'*', b'/') | (false, false, b'/', b'*') => {
+                    self.in_comment = !self.in_comment;
+                    // the closing `/` of `/*/` must not end the comment right away
+                    previous = 0;
+                    continue;
+                }
+                (false, _, _, b'\'') => self.in_string = !self.in_string,
+                _ => {}
+            }
+
+            previous = byte;
+        }
+
+        !self.in_string && !self.in_comment && chunk.ends_with(b";")
+    }
+
+    /// Whether the scanned input stopped inside of a string or comment.
+    pub(crate) fn in_literal(&self) -> bool {
+        self.in_string || self.in_comment
+    }
+
+    /// Moves the position behind the given statement.
+    fn advance(&mut self, statement: &[u8]) {
+        self.position.offset += statement.len();
+
+        match statement.iter().rposition(|&b| b == b'\n') {
+            Some(last_newline) => {
+                self.position.line += statement.iter().filter(|&&b| b == b'\n').count();
+                self.position.column = char_count(&statement[last_newline + 1..]);
+            }
+            None => self.position.column += char_count(statement),
+        }
+    }
+}
+
+fn char_count(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|&&b| (b & 0xC0) != 0x80).count()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs::File, io::BufReader, str::FromStr};
+
+    use anyhow::Result;
+
+    use super::IfcReader;
+    use crate::{id::Id, parser::IfcParseError, prelude::*};
+
+    #[test]
+    fn reads_same_entities_as_parser() -> Result<()> {
+        // the second file is larger than a batch, and with `rayon` than a chunk of a batch
+        for path in [
+            "resources/wall-standard-case.ifc",
+            "resources/AC20-FZK-Haus.ifc",
+        ] {
+            let ifc = IFC::from_str(&std::fs::read_to_string(path)?)?;
+
+            let reader = IfcReader::new(BufReader::new(File::open(path)?))?;
+            assert_eq!(reader.header(), &ifc.header);
+
+            let streamed = reader.into_ifc()?;
+            assert_eq!(streamed.to_string(), ifc.to_string());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn yields_entities_one_by_one() -> Result<()> {
+        let file = "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+/* a comment; with a semicolon */
+#1= IFCCARTESIANPOINT((0.,0.,0.));
+#2= IFCORGANIZATION($,'semi;colon',$,$,$);
+ENDSEC;
+END-ISO-10303-21;
+";
+
+        let mut reader = IfcReader::new(file.as_bytes())?;
+
+        let (id, point) = reader.next().unwrap()?;
+        assert_eq!(id, Id(1));
+        assert!(point.downcast_ref::<Point3D>().is_some());
+
+        let (id, organization) = reader.next().unwrap()?;
+        assert_eq!(id, Id(2));
+        assert_eq!(
+            organization.to_string(),
+            "IFCORGANIZATION($,'semi;colon',$,$,$);"
+        );
+
+        assert!(reader.footer().is_none());
+        assert!(reader.next().is_none());
+        assert!(reader.footer().is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_position_in_whole_file() -> Result<()> {
+        let file = "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+#1= IFCCARTESIANPOINT((0.,0.,0.));
+#2 IFCDIRECTION((1.,0.,0.));
+ENDSEC;
+END-ISO-10303-21;
+";
+
+        let mut reader = IfcReader::new(file.as_bytes())?;
+
+        let err = reader
+            .find_map(Result::err)
+            .expect("second entity should fail to parse");
+        let err = err.downcast::<IfcParseError>()?;
+        assert_eq!(err.line, 9);
+        assert_eq!(err.column, 4);
+        assert_eq!(err.entity_id, Some(Id(2)));
+        assert_eq!(&file[err.offset..err.offset + 1], "I");
+
+        assert!(reader.next().is_none());
+
+        Ok(())
+    }
+}
@@ -40,9 +40,11 @@ pub enum MappedTransform<'a> {
 ///   representation being mapped. The dimensionality of the mapping source and the mapping
 ///   target has to be the same, if the mapping source is a geometric representation item.
 #[derive(IfcVerify)]
+#[ifc(name = "IfcMappedItem")]
 pub struct MappedItem {
     /// A representation map that is the source of the mapped item. It can be seen as a block (or
     /// cell or marco) definition.
+    #[ifc(name = "MappingSource")]
     pub source: IdOr<RepresentationMap>,
     // FIXME: This should be more general. The docs state that this can be anything that's
     // specializing the [IfcCartesianTransformationOperator](https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifccartesiantransformationoperator.htm)
@@ -53,6 +55,7 @@ pub struct MappedItem {
         CartesianTransformationOperator3DnonUniform,
         CartesianTransformationOperator3D
     )]
+    #[ifc(name = "MappingTarget")]
     pub target: Id,
 }
 
@@ -1,9 +1,4 @@
-use winnow::combinator::alt;
-
-use crate::{
-    parser::{IFCParse, IFCParser},
-    prelude::*,
-};
+use crate::parser::EntityRegistry;
 
 pub(crate) mod mapped_item;
 pub(crate) mod prelude;
@@ -20,18 +15,21 @@ pub(crate) mod representation_map;
 pub struct Relation;
 
 impl Relation {
-    pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
-        alt((
-            rel_aggregates::RelAggregates::parse_any(),
-            rel_associates_material::RelAssociatesMaterial::parse_any(),
-            rel_contained_in_spatial_structure::RelContainedInSpatialStructure::parse_any(),
-            rel_declares::RelDeclares::parse_any(),
-            rel_defines_by_type::RelDefinesByType::parse_any(),
-            rel_defines_by_properties::RelDefinesByProperties::parse_any(),
-            rel_voids_element::RelVoidsElement::parse_any(),
-            rel_fills_element::RelFillsElement::parse_any(),
-            mapped_item::MappedItem::parse_any(),
-            representation_map::RepresentationMap::parse_any(),
-        ))
+    pub(crate) fn register(registry: &mut EntityRegistry) {
+        registry.register::<rel_aggregates::RelAggregates>("IFCRELAGGREGATES");
+        registry
+            .register::<rel_associates_material::RelAssociatesMaterial>("IFCRELASSOCIATESMATERIAL");
+        registry.register::<rel_contained_in_spatial_structure::RelContainedInSpatialStructure>(
+            "IFCRELCONTAINEDINSPATIALSTRUCTURE",
+        );
+        registry.register::<rel_declares::RelDeclares>("IFCRELDECLARES");
+        registry.register::<rel_defines_by_type::RelDefinesByType>("IFCRELDEFINESBYTYPE");
+        registry.register::<rel_defines_by_properties::RelDefinesByProperties>(
+            "IFCRELDEFINESBYPROPERTIES",
+        );
+        registry.register::<rel_voids_element::RelVoidsElement>("IFCRELVOIDSELEMENT");
+        registry.register::<rel_fills_element::RelFillsElement>("IFCRELFILLSELEMENT");
+        registry.register::<mapped_item::MappedItem>("IFCMAPPEDITEM");
+        registry.register::<representation_map::RepresentationMap>("IFCREPRESENTATIONMAP");
     }
 }
@@ -32,13 +32,16 @@ use crate::{
 ///
 ///   HISTORY  New entity in IFC2x.
 #[derive(IfcVerify)]
+#[ifc(name = "IfcRepresentationMap")]
 pub struct RepresentationMap {
     /// An axis2 placement that defines the position about which the mapped representation is mapped.
+    #[ifc(name = "MappingOrigin")]
     pub origin: IdOr<Axis3D>,
     // FIXME: This should be more general. The docs state that this can be anything that's
     // specializing the [IfcRepresentation](https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcrepresentation.htm).
     // See issue #46
     /// A representation that is mapped to at least one mapped item.
+    #[ifc(name = "MappedRepresentation")]
     pub representation: IdOr<ShapeRepresentation>,
 }
 
@@ -0,0 +1,541 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use winnow::Parser;
+
+use crate::{
+    id::Id,
+    parser::{string::decode, unknown_entity::UnknownEntity, EntityRegistry, IFCParse},
+    prelude::*,
+};
+
+/// Reason why an entity was written differently than it was read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difference {
+    /// the entity couldn't be parsed and was kept as [`Dummy`]
+    DummyFallback,
+    /// comments in or in front of the entity were dropped
+    DroppedComment,
+    /// whitespace outside of strings was dropped
+    Whitespace,
+    /// a number was written in another notation, but with the same value, e.g. `1.0E-1` as `0.1`
+    FloatFormatting,
+    /// a string was written with another encoding of the same characters
+    StringEncoding,
+    /// a logical was written with another spelling, e.g. `.T.` as `.TRUE.`
+    Logical,
+    /// a value of an attribute which is derived in the schema was written as `*`, e.g. the
+    /// dimension of sub-contexts
+    Derived,
+    /// the entity was written with another keyword, e.g. `IFCWALLSTANDARDCASE` as `IFCWALL`
+    Keyword,
+    /// attribute values changed in any other way, e.g. a value got lost
+    Value,
+    /// the entity wasn't written at all
+    Missing,
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Difference::DummyFallback => "dummy fallback",
+            Difference::DroppedComment => "dropped comment",
+            Difference::Whitespace => "whitespace",
+            Difference::FloatFormatting => "float formatting",
+            Difference::StringEncoding => "string encoding",
+            Difference::Logical => "logical",
+            Difference::Derived => "derived",
+            Difference::Keyword => "keyword",
+            Difference::Value => "value",
+            Difference::Missing => "missing",
+        };
+
+        write!(f, "{description}")
+    }
+}
+
+/// Entity whose written text differs from the text it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityChange {
+    pub id: Id,
+    /// text of the entity in the checked file, without the id
+    pub original: String,
+    /// text the entity was written as, empty if it is [`Difference::Missing`]
+    pub written: String,
+    /// all reasons for the change, sorted and without duplicates
+    pub differences: Vec<Difference>,
+}
+
+/// Result of [`check`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoundTripReport {
+    /// number of entity instances in the checked file
+    pub entities: usize,
+    /// entities which were written differently, ordered by id
+    pub changes: Vec<EntityChange>,
+    /// entities which were kept as [`Dummy`], even if their text didn't change
+    pub fallbacks: Vec<Id>,
+    /// entities which changed again when the written file was read and written another time
+    pub unstable: Vec<Id>,
+    /// errors while reading the checked or the written file
+    pub errors: Vec<String>,
+}
+
+impl RoundTripReport {
+    /// whether every entity was written exactly as it was read
+    pub fn is_lossless(&self) -> bool {
+        self.changes.is_empty() && self.unstable.is_empty() && self.errors.is_empty()
+    }
+
+    /// number of changed entities per difference
+    pub fn summary(&self) -> BTreeMap<Difference, usize> {
+        let mut summary = BTreeMap::new();
+        for difference in self.changes.iter().flat_map(|change| &change.differences) {
+            *summary.entry(*difference).or_default() += 1;
+        }
+
+        summary
+    }
+}
+
+impl Display for RoundTripReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} entities, {} changed, {} kept as dummy, {} unstable",
+            self.entities,
+            self.changes.len(),
+            self.fallbacks.len(),
+            self.unstable.len()
+        )?;
+
+        for error in &self.errors {
+            write!(f, "\nerror: {error}")?;
+        }
+
+        for change in &self.changes {
+            let differences = change.differences.iter().map(ToString::to_string);
+            write!(
+                f,
+                "\n{}: {}\n  - {}\n  + {}",
+                change.id,
+                differences.collect::<Vec<_>>().join(", "),
+                change.original,
+                change.written
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the file, writes it again and compares the written entities one by one with the
+/// original text. The written file is read and written another time, to make sure that the
+/// output is stable.
+///
+/// The file is read leniently, so malformed entities are reported as errors and kept as
+/// [`Dummy`] instead of aborting the check.
+pub fn check(input: &str) -> RoundTripReport {
+    let mut report = RoundTripReport::default();
+
+    let ifc = match IFC::from_str_lenient(input) {
+        Ok((ifc, errors)) => {
+            report.errors.extend(errors.iter().map(ToString::to_string));
+            ifc
+        }
+        Err(err) => {
+            report.errors.push(err.to_string());
+            return report;
+        }
+    };
+
+    let original = source_entities(input);
+    report.entities = original.len();
+
+    report.fallbacks = ifc
+        .data
+        .iter()
+        .filter(|(_, entity)| entity.downcast_ref::<Dummy>().is_some())
+        .map(|(id, _)| *id)
+        .collect();
+
+    for (id, source) in &original {
+        let Some(entity) = ifc.data.try_get_untyped(*id) else {
+            report.changes.push(EntityChange {
+                id: *id,
+                original: source.text.clone(),
+                written: String::new(),
+                differences: vec![Difference::Missing],
+            });
+            continue;
+        };
+
+        let written = entity.to_string();
+        if written == source.text && !source.has_comments {
+            continue;
+        }
+
+        // the text of dummies is kept as it is, apart from surrounding whitespace
+        let is_dummy = entity.downcast_ref::<Dummy>().is_some();
+
+        let mut differences = Vec::new();
+        if is_dummy {
+            differences.push(Difference::DummyFallback);
+        }
+        if source.has_comments {
+            differences.push(Difference::DroppedComment);
+        }
+        if source.has_whitespace {
+            differences.push(Difference::Whitespace);
+        }
+        if written != source.normalized && !is_dummy {
+            compare_entities(&source.normalized, &written, &mut differences);
+        }
+
+        differences.sort();
+        differences.dedup();
+
+        report.changes.push(EntityChange {
+            id: *id,
+            original: source.text.clone(),
+            written,
+            differences,
+        });
+    }
+
+    match IFC::from_str_lenient(&ifc.to_string()) {
+        Ok((reread, errors)) => {
+            report
+                .errors
+                .extend(errors.iter().map(|err| format!("written file: {err}")));
+
+            report.unstable = ifc
+                .data
+                .iter()
+                .filter(|(id, entity)| {
+                    reread
+                        .data
+                        .try_get_untyped(**id)
+                        .is_none_or(|reread| reread.to_string() != entity.to_string())
+                })
+                .map(|(id, _)| *id)
+                .collect();
+        }
+        Err(err) => report.errors.push(format!("written file: {err}")),
+    }
+
+    report
+}
+
+/// entity instance as it is written in the checked file
+struct SourceEntity {
+    /// text after the `=`, e.g. `IFCWALL(...);`
+    text: String,
+    /// text without whitespace and comments outside of strings
+    normalized: String,
+    has_comments: bool,
+    has_whitespace: bool,
+}
+
+/// Splits the file into statements and collects the entity instances. Strings and comments are
+/// skipped while looking for the end of a statement, so they may contain `;`.
+fn source_entities(input: &str) -> BTreeMap<Id, SourceEntity> {
+    let mut entities = BTreeMap::new();
+
+    let mut raw = String::new();
+    let mut normalized = String::new();
+    let mut has_comments = false;
+    let mut has_whitespace = false;
+
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                raw.push(c);
+                normalized.push(c);
+
+                while let Some(c) = chars.next() {
+                    raw.push(c);
+                    normalized.push(c);
+
+                    // `''` is an escaped quote inside of the string
+                    if c == '\'' && chars.next_if_eq(&'\'').is_none() {
+                        break;
+                    }
+                    if c == '\'' {
+                        raw.push(c);
+                        normalized.push(c);
+                    }
+                }
+            }
+            '/' if chars.next_if_eq(&'*').is_some() => {
+                has_comments = true;
+
+                // comments in front of the statement aren't part of the entity text
+                let in_statement = !raw.trim().is_empty();
+                if in_statement {
+                    raw.push_str("/*");
+                }
+
+                let mut previous = None;
+                for c in chars.by_ref() {
+                    if in_statement {
+                        raw.push(c);
+                    }
+                    if previous == Some('*') && c == '/' {
+                        break;
+                    }
+                    previous = Some(c);
+                }
+            }
+            ';' => {
+                raw.push(c);
+                normalized.push(c);
+
+                let statement = raw.trim();
+                let is_instance = statement.starts_with('#');
+                if let (true, Some((id, text))) = (is_instance, statement.split_once('=')) {
+                    let id = id.trim().trim_start_matches('#').parse();
+                    let normalized = normalized.split_once('=').map(|(_, text)| text);
+
+                    if let (Ok(id), Some(normalized)) = (id, normalized) {
+                        entities.insert(
+                            Id(id),
+                            SourceEntity {
+                                text: text.trim().to_owned(),
+                                normalized: normalized.to_owned(),
+                                has_comments,
+                                has_whitespace,
+                            },
+                        );
+                    }
+                }
+
+                raw.clear();
+                normalized.clear();
+                has_comments = false;
+                has_whitespace = false;
+            }
+            c if c.is_whitespace() => {
+                raw.push(c);
+                // whitespace between the id, the `=` and the keyword is never kept
+                has_whitespace |= normalized.contains('=') && !normalized.ends_with('=');
+            }
+            c => {
+                raw.push(c);
+                normalized.push(c);
+            }
+        }
+    }
+
+    entities
+}
+
+/// compares the attribute values of both entities, which are written without whitespace
+fn compare_entities(original: &str, written: &str, differences: &mut Vec<Difference>) {
+    let (Ok(original), Ok(written)) = (
+        UnknownEntity::parse().parse(original),
+        UnknownEntity::parse().parse(written),
+    ) else {
+        differences.push(Difference::Value);
+        return;
+    };
+
+    if original.keyword != written.keyword {
+        differences.push(Difference::Keyword);
+    }
+
+    if original.args.len() != written.args.len() {
+        differences.push(Difference::Value);
+        return;
+    }
+
+    let entity_type = EntityRegistry::global().entity_type(&written.keyword);
+
+    for (index, (original, written)) in original.args.iter().zip(&written.args).enumerate() {
+        // only attributes which the schema derives are written as `*` on purpose
+        let derived = entity_type
+            .and_then(|entity_type| entity_type.attributes.get(index))
+            .is_some_and(|attribute| attribute.derived);
+
+        if derived && original != written && *written == StepValue::Inherited {
+            differences.push(Difference::Derived);
+        } else {
+            compare_values(original, written, differences);
+        }
+    }
+}
+
+fn compare_values(original: &StepValue, written: &StepValue, differences: &mut Vec<Difference>) {
+    fn number(value: &StepValue) -> Option<f64> {
+        match value {
+            StepValue::Integer(integer) => Some(*integer as f64),
+            StepValue::Real(real) => real.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// value of booleans and logicals in their short or long form, `Some(None)` for `.U.`
+    fn logical(value: &str) -> Option<Option<bool>> {
+        match value {
+            "T" | "TRUE" => Some(Some(true)),
+            "F" | "FALSE" => Some(Some(false)),
+            "U" | "UNKNOWN" => Some(None),
+            _ => None,
+        }
+    }
+
+    if original == written {
+        return;
+    }
+
+    let difference = match (original, written) {
+        (StepValue::String(original), StepValue::String(written)) => {
+            match (decode(original), decode(written)) {
+                (Some(original), Some(written)) if original == written => {
+                    Difference::StringEncoding
+                }
+                _ => Difference::Value,
+            }
+        }
+        (StepValue::Enum(original), StepValue::Enum(written))
+            if logical(original).is_some() && logical(original) == logical(written) =>
+        {
+            Difference::Logical
+        }
+        (StepValue::Typed(original_type, original), StepValue::Typed(written_type, written))
+            if original_type == written_type =>
+        {
+            return compare_values(original, written, differences);
+        }
+        (StepValue::List(original), StepValue::List(written))
+            if original.len() == written.len() =>
+        {
+            for (original, written) in original.iter().zip(written) {
+                compare_values(original, written, differences);
+            }
+            return;
+        }
+        (original, written) => match (number(original), number(written)) {
+            (Some(original), Some(written)) if original == written => Difference::FloatFormatting,
+            _ => Difference::Value,
+        },
+    };
+
+    differences.push(difference);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check, compare_entities, Difference, EntityChange};
+    use crate::id::Id;
+
+    fn file(data: &str) -> String {
+        format!(
+            "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+{data}
+ENDSEC;
+END-ISO-10303-21;
+"
+        )
+    }
+
+    #[test]
+    fn unchanged_files_are_lossless() {
+        let report = check(&file(
+            "#1= IFCCARTESIANPOINT((0.,1.,2.));
+#2= IFCDIRECTION((0.,0.,1.));
+#3= IFCAXIS2PLACEMENT3D(#1,#2,$);",
+        ));
+
+        assert!(report.is_lossless(), "{report}");
+        assert_eq!(report.entities, 3);
+    }
+
+    #[test]
+    fn reports_why_entities_changed() {
+        let report = check(&file(
+            "#1= IFCCARTESIANPOINT((0.0,1.0E1,2.));
+#2= IFCDIRECTION( (0.,0.,1.) );
+/* placement */
+#3= IFCAXIS2PLACEMENT3D(#1,#2,$);
+#4= IFCPROPERTYSINGLEVALUE('T\\X\\FCr',$,IFCBOOLEAN(.T.),$);",
+        ));
+
+        assert_eq!(
+            report.changes,
+            [
+                EntityChange {
+                    id: Id(1),
+                    original: "IFCCARTESIANPOINT((0.0,1.0E1,2.));".to_owned(),
+                    written: "IFCCARTESIANPOINT((0.,10.,2.));".to_owned(),
+                    differences: vec![Difference::FloatFormatting],
+                },
+                EntityChange {
+                    id: Id(2),
+                    original: "IFCDIRECTION( (0.,0.,1.) );".to_owned(),
+                    written: "IFCDIRECTION((0.,0.,1.));".to_owned(),
+                    differences: vec![Difference::Whitespace],
+                },
+                EntityChange {
+                    id: Id(3),
+                    original: "IFCAXIS2PLACEMENT3D(#1,#2,$);".to_owned(),
+                    written: "IFCAXIS2PLACEMENT3D(#1,#2,$);".to_owned(),
+                    differences: vec![Difference::DroppedComment],
+                },
+                EntityChange {
+                    id: Id(4),
+                    original: "IFCPROPERTYSINGLEVALUE('T\\X\\FCr',$,IFCBOOLEAN(.T.),$);".to_owned(),
+                    // strings are kept as they were written
+                    written: "IFCPROPERTYSINGLEVALUE('T\\X\\FCr',$,IFCBOOLEAN(.TRUE.),$);"
+                        .to_owned(),
+                    differences: vec![Difference::Logical],
+                },
+            ]
+        );
+        assert!(report.unstable.is_empty());
+        assert!(report.errors.is_empty());
+    }
+
+    #[test]
+    fn only_derived_attributes_may_be_written_as_inherited() {
+        let mut differences = Vec::new();
+        compare_entities(
+            "IFCGEOMETRICREPRESENTATIONSUBCONTEXT('Axis','Model',3,$,$,$,#1,$,.MODEL_VIEW.,$);",
+            "IFCGEOMETRICREPRESENTATIONSUBCONTEXT('Axis','Model',*,*,*,*,#1,$,.MODEL_VIEW.,$);",
+            &mut differences,
+        );
+        assert_eq!(differences, [Difference::Derived; 4]);
+
+        let mut differences = Vec::new();
+        compare_entities(
+            "IFCAXIS2PLACEMENT3D(#1,#2,$);",
+            "IFCAXIS2PLACEMENT3D(#1,*,$);",
+            &mut differences,
+        );
+        assert_eq!(differences, [Difference::Value]);
+    }
+
+    #[test]
+    fn reports_dummy_fallbacks() {
+        let report = check(&file(
+            "#1= IFCCARTESIANPOINT((0.,1.,2.));
+#2= IFCDIRECTION((0.,0.,1.)) garbage;
+#3= IFCDIRECTION((0.,0.,1.) ;",
+        ));
+
+        // unknown syntax is kept as it is
+        assert_eq!(report.fallbacks, [Id(2), Id(3)]);
+        assert!(report.errors.is_empty());
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(
+            report.changes[0].differences,
+            [Difference::DummyFallback, Difference::Whitespace]
+        );
+    }
+}
@@ -1,21 +1,71 @@
 use std::{any::Any, fmt::Display};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use downcast_rs::{self, impl_downcast, Downcast};
 
-use crate::prelude::*;
+use crate::{
+    prelude::*,
+    traits::reflect::{Attribute, EntityType},
+};
 
 /// Trait which is mostly automatically implemented via the `ifc_rs_verify_derive` crate. It is used
-/// to verify that objects referencing other objects by ID are of a type that fits the purpose. For
-/// more information, please visit the docs of the `ifc_rs_verify_derive` crate
+/// to verify that objects referencing other objects by ID are of a type that fits the purpose and
+/// gives generic access to their attributes, see [`Reflect`](crate::traits::reflect::Reflect).
+/// For more information, please visit the docs of the `ifc_rs_verify_derive` crate
 pub trait IfcVerify: Any + Send + Sync + 'static {
     fn verify_id_types(&self, _ifc: &IFC) -> Result<()> {
         Ok(())
     }
 
+    /// Calls the visitor with the field name and id of every entity referenced by this object.
+    fn visit_ids(&self, _visitor: &mut dyn FnMut(&'static str, Id)) {}
+
+    /// Like [`IfcVerify::visit_ids`], but allows to change the referenced ids.
+    fn visit_ids_mut(&mut self, _visitor: &mut dyn FnMut(&'static str, &mut Id)) {}
+
     fn type_name(&self) -> &str {
         std::any::type_name::<Self>()
     }
+
+    /// Attributes of the entity in the order in which they are written, including the ones of its
+    /// supertypes.
+    fn attribute_types() -> Vec<Attribute>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+
+    /// Schema definition of entities which can be instantiated, `None` for abstract supertypes.
+    fn static_entity_type() -> Option<&'static EntityType>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// see [`IfcVerify::static_entity_type`], also `None` for entities which aren't modelled by
+    /// this crate
+    fn entity_type(&self) -> Option<&'static EntityType> {
+        None
+    }
+
+    /// STEP keyword of the entity, e.g. `IFCWALL`
+    fn keyword(&self) -> &str {
+        self.entity_type()
+            .map_or_else(|| self.type_name(), |entity_type| &entity_type.keyword)
+    }
+
+    /// values of all attributes in the order of [`IfcVerify::attribute_types`]
+    fn attribute_values(&self) -> Vec<StepValue> {
+        Vec::new()
+    }
+
+    /// Sets the attribute at the given position. Fails without changing the object if the value
+    /// doesn't fit the attribute.
+    fn set_attribute_value(&mut self, index: usize, _value: &StepValue) -> Result<()> {
+        bail!("{} has no attribute {index}", self.keyword())
+    }
 }
 
 /// Trait which marks all types that are IFC Objects in a sense that they can be used in the
@@ -1,5 +1,7 @@
 pub mod ifc_mapped_type;
 pub mod ifc_type;
 pub(crate) mod prelude;
+pub mod references;
+pub mod reflect;
 pub(crate) mod relative_placement;
 pub mod sub_types;
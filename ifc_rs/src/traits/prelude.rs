@@ -1,4 +1,6 @@
 pub use super::ifc_mapped_type::IfcMappedType;
 pub use super::ifc_type::{IfcType, IfcVerify};
+pub use super::references::References;
+pub use super::reflect::{AttributeValue, Reflect};
 pub use super::relative_placement::RelativePlacement;
 pub use super::sub_types::*;
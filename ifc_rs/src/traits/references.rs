@@ -0,0 +1,108 @@
+use crate::prelude::*;
+
+/// Access to the ids of all entities referenced by an entity, including the ones inside of lists,
+/// optional parameters and inherited attributes.
+///
+/// This is built on [`IfcVerify::visit_ids`] and [`IfcVerify::visit_ids_mut`], which the derive
+/// generates for all entities of the crate. Unparsed entities ([`Dummy`], [`UnknownEntity`])
+/// report the ids written in their text.
+pub trait References {
+    /// all referenced ids in the order in which they are written, lists may contain an id
+    /// multiple times
+    fn referenced_ids(&self) -> impl Iterator<Item = Id>;
+
+    /// replaces every referenced id with the one returned by `f`
+    fn remap_ids(&mut self, f: impl FnMut(Id) -> Id);
+}
+
+impl References for dyn IfcType {
+    fn referenced_ids(&self) -> impl Iterator<Item = Id> {
+        let mut ids = Vec::new();
+        self.visit_ids(&mut |_, id| ids.push(id));
+
+        ids.into_iter()
+    }
+
+    fn remap_ids(&mut self, mut f: impl FnMut(Id) -> Id) {
+        self.visit_ids_mut(&mut |_, id| *id = f(*id));
+    }
+}
+
+impl<T: IfcType> References for T {
+    fn referenced_ids(&self) -> impl Iterator<Item = Id> {
+        (self as &dyn IfcType).referenced_ids()
+    }
+
+    fn remap_ids(&mut self, f: impl FnMut(Id) -> Id) {
+        (self as &mut dyn IfcType).remap_ids(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+
+    use winnow::Parser;
+
+    use super::References;
+    use crate::{id::Id, parser::IFCParse, prelude::*};
+
+    /// ids written in the STEP text of an entity
+    fn written_ids(entity: &dyn IfcType) -> BTreeSet<Id> {
+        let text = entity.to_string();
+
+        text.split('#')
+            .skip(1)
+            .filter_map(|rest| {
+                let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+                digits.parse().ok().map(Id)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_and_remaps_references() {
+        let mut wall = Wall::parse()
+            .parse("IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,$,$,$,#33,#25,$,#37);")
+            .unwrap();
+
+        assert_eq!(
+            wall.referenced_ids().collect::<Vec<_>>(),
+            [Id(2), Id(33), Id(25), Id(37)]
+        );
+
+        wall.remap_ids(|id| Id(id.0 + 100));
+        assert_eq!(
+            wall.to_string(),
+            "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#102,$,$,$,#133,#125,$,#137);"
+        );
+    }
+
+    #[test]
+    fn references_cover_all_written_ids() {
+        for file in [
+            "resources/AC20-FZK-Haus.ifc",
+            "resources/wall-standard-case.ifc",
+        ] {
+            let mut ifc = IFC::from_file(file).unwrap();
+
+            for (id, entity) in ifc.data.iter() {
+                assert_eq!(
+                    entity.referenced_ids().collect::<BTreeSet<_>>(),
+                    written_ids(&**entity),
+                    "{id} = {entity}"
+                );
+            }
+
+            let ids: Vec<_> = ifc.data.iter().map(|(id, _)| *id).collect();
+            for id in ids {
+                let entity = ifc.data.try_get_untyped_mut(id).unwrap();
+                let expected: BTreeSet<_> =
+                    written_ids(entity).iter().map(|id| Id(id.0 * 2)).collect();
+
+                entity.remap_ids(|id| Id(id.0 * 2));
+                assert_eq!(written_ids(entity), expected, "{id} = {entity}");
+            }
+        }
+    }
+}
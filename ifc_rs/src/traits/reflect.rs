@@ -0,0 +1,347 @@
+use anyhow::{Context, Result};
+
+use crate::prelude::*;
+
+/// How the values of an attribute are written to STEP files. Aggregates have the kind of their
+/// items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeKind {
+    /// string literal, e.g. `'Wall'`
+    String,
+    /// e.g. `42`
+    Integer,
+    /// e.g. `0.5`
+    Real,
+    /// `.T.`, `.F.` or `.U.`
+    Logical,
+    /// enumeration value, e.g. `.NOTDEFINED.`
+    Enumeration,
+    /// reference to another entity, e.g. `#12`
+    Reference,
+    /// select of typed values like `IFCLABEL('Wall')` or values of any kind
+    Select,
+}
+
+/// An attribute in the schema definition of an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attribute {
+    /// name of the attribute in the schema, e.g. `GlobalId`
+    pub name: &'static str,
+    pub kind: AttributeKind,
+    /// derived attributes are always written as `*` and left out in other formats
+    pub derived: bool,
+}
+
+/// Schema definition of an entity, generated from its struct fields by the `IfcVerify` derive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityType {
+    /// name of the entity in the schema, e.g. `IfcWall`
+    pub name: &'static str,
+    /// STEP keyword of the entity, e.g. `IFCWALL`
+    pub keyword: String,
+    /// attributes in the order in which they are written, including the ones of supertypes
+    pub attributes: Vec<Attribute>,
+}
+
+impl EntityType {
+    pub fn new(name: &'static str, attributes: Vec<Attribute>) -> Self {
+        Self {
+            name,
+            keyword: name.to_uppercase(),
+            attributes,
+        }
+    }
+
+    /// rooted entities are identified by their first attribute
+    pub fn has_global_id(&self) -> bool {
+        self.attributes
+            .first()
+            .is_some_and(|attribute| attribute.name == "GlobalId")
+    }
+
+    /// position of the attribute with the given name
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.attributes
+            .iter()
+            .position(|attribute| attribute.name == name)
+    }
+}
+
+/// Conversion of attribute values from and to their generic [`StepValue`] representation, which
+/// the `IfcVerify` derive uses for the reflection of struct fields.
+pub trait StepAttribute: Sized {
+    const KIND: AttributeKind;
+
+    fn to_step_value(&self) -> StepValue;
+
+    fn from_step_value(value: &StepValue) -> Result<Self>;
+}
+
+/// Implements [`StepAttribute`] for enumerations whose strum representation is the STEP notation,
+/// e.g. `.NOTDEFINED.` or `'Body'` for enumerations of labels.
+macro_rules! step_enumeration {
+    ($ty:ty) => {
+        impl $crate::traits::reflect::StepAttribute for $ty {
+            const KIND: $crate::traits::reflect::AttributeKind =
+                $crate::traits::reflect::AttributeKind::Enumeration;
+
+            fn to_step_value(&self) -> $crate::parser::StepValue {
+                let text = self.to_string();
+                let value = text.trim_matches('.').to_owned();
+
+                $crate::parser::StepValue::Enum(value)
+            }
+
+            fn from_step_value(value: &$crate::parser::StepValue) -> anyhow::Result<Self> {
+                use anyhow::Context;
+
+                match value {
+                    $crate::parser::StepValue::Enum(value) => {
+                        <Self as std::str::FromStr>::from_str(&format!(".{value}."))
+                            .ok()
+                            .with_context(|| {
+                                format!("`{value}` isn't a value of {}", stringify!($ty))
+                            })
+                    }
+                    value => anyhow::bail!("expected enumeration value, found `{value}`"),
+                }
+            }
+        }
+    };
+    ($ty:ty, String) => {
+        impl $crate::traits::reflect::StepAttribute for $ty {
+            const KIND: $crate::traits::reflect::AttributeKind =
+                $crate::traits::reflect::AttributeKind::String;
+
+            fn to_step_value(&self) -> $crate::parser::StepValue {
+                let text = self.to_string();
+                let value = text.trim_matches('\'').to_owned();
+
+                $crate::parser::StepValue::String(value)
+            }
+
+            fn from_step_value(value: &$crate::parser::StepValue) -> anyhow::Result<Self> {
+                use anyhow::Context;
+
+                match value {
+                    $crate::parser::StepValue::String(value) => {
+                        <Self as std::str::FromStr>::from_str(&format!("'{value}'"))
+                            .ok()
+                            .with_context(|| {
+                                format!("`{value}` isn't a value of {}", stringify!($ty))
+                            })
+                    }
+                    value => anyhow::bail!("expected string, found `{value}`"),
+                }
+            }
+        }
+    };
+}
+pub(crate) use step_enumeration;
+
+/// An attribute of an entity together with its name in the schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeValue {
+    /// name of the attribute in the schema, e.g. `GlobalId`
+    pub name: &'static str,
+    /// value as it's written to STEP files, derived attributes are [`StepValue::Inherited`]
+    pub value: StepValue,
+}
+
+/// Generic access to the attributes of any entity, in the order and with the names of the schema.
+///
+/// Attributes of supertypes are included, so e.g. `GlobalId` and `Name` are available on every
+/// subtype of `IfcRoot`. This is built on [`IfcVerify::attribute_values`] and
+/// [`IfcVerify::set_attribute_value`], which the derive generates from the struct fields.
+/// Entities which aren't modelled by this crate ([`UnknownEntity`], [`Dummy`]) have no attribute
+/// names.
+pub trait Reflect {
+    /// all attributes of the entity in the order in which they are written
+    fn attributes(&self) -> Vec<AttributeValue>;
+
+    /// value of the attribute with the given name
+    fn attribute(&self, name: &str) -> Result<StepValue>;
+
+    /// Sets the attribute with the given name. Fails without changing the entity if the value
+    /// doesn't fit the attribute.
+    fn set_attribute(&mut self, name: &str, value: StepValue) -> Result<()>;
+}
+
+impl Reflect for dyn IfcType {
+    fn attributes(&self) -> Vec<AttributeValue> {
+        let Some(entity_type) = self.entity_type() else {
+            return Vec::new();
+        };
+
+        entity_type
+            .attributes
+            .iter()
+            .zip(self.attribute_values())
+            .map(|(attribute, value)| AttributeValue {
+                name: attribute.name,
+                value,
+            })
+            .collect()
+    }
+
+    fn attribute(&self, name: &str) -> Result<StepValue> {
+        let index = self.attribute_position(name)?;
+
+        Ok(self.attribute_values().swap_remove(index))
+    }
+
+    fn set_attribute(&mut self, name: &str, value: StepValue) -> Result<()> {
+        let index = self.attribute_position(name)?;
+
+        self.set_attribute_value(index, &value)
+    }
+}
+
+impl dyn IfcType {
+    fn attribute_position(&self, name: &str) -> Result<usize> {
+        self.entity_type()
+            .and_then(|entity_type| entity_type.position(name))
+            .with_context(|| format!("{} has no attribute {name}", self.keyword()))
+    }
+}
+
+impl<T: IfcType> Reflect for T {
+    fn attributes(&self) -> Vec<AttributeValue> {
+        (self as &dyn IfcType).attributes()
+    }
+
+    fn attribute(&self, name: &str) -> Result<StepValue> {
+        (self as &dyn IfcType).attribute(name)
+    }
+
+    fn set_attribute(&mut self, name: &str, value: StepValue) -> Result<()> {
+        (self as &mut dyn IfcType).set_attribute(name, value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use winnow::Parser;
+
+    use super::{AttributeValue, Reflect};
+    use crate::{id::Id, parser::IFCParse, prelude::*};
+
+    const WALL: &str = "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,'Wall',$,$,#33,#25,$,$);";
+
+    #[test]
+    fn reflects_inherited_attributes() {
+        let wall = Wall::parse().parse(WALL).unwrap();
+
+        assert_eq!(wall.keyword(), "IFCWALL");
+        assert_eq!(
+            wall.attribute("GlobalId").unwrap(),
+            StepValue::String("0DWgwt6o1FOx7466fPk$jl".into())
+        );
+        assert_eq!(
+            wall.attribute("ObjectPlacement").unwrap(),
+            StepValue::Id(Id(33))
+        );
+
+        let attributes = wall.attributes();
+        assert_eq!(
+            attributes.iter().map(|a| a.name).collect::<Vec<_>>(),
+            [
+                "GlobalId",
+                "OwnerHistory",
+                "Name",
+                "Description",
+                "ObjectType",
+                "ObjectPlacement",
+                "Representation",
+                "Tag",
+                "PredefinedType"
+            ]
+        );
+        assert_eq!(
+            attributes[2],
+            AttributeValue {
+                name: "Name",
+                value: StepValue::String("Wall".into())
+            }
+        );
+
+        assert!(wall.attribute("LayerThickness").is_err());
+    }
+
+    #[test]
+    fn sets_attributes_by_name() {
+        let mut wall = Wall::parse().parse(WALL).unwrap();
+
+        wall.set_attribute("Name", StepValue::String("Outer wall".into()))
+            .unwrap();
+        wall.set_attribute("Tag", StepValue::String("W-01".into()))
+            .unwrap();
+
+        assert_eq!(wall.name.custom().unwrap().value, "Outer wall");
+        assert_eq!(
+            wall.to_string(),
+            "IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,'Outer wall',$,$,#33,#25,'W-01',$);"
+        );
+
+        // values which don't fit the attribute leave the entity unchanged
+        assert!(wall
+            .set_attribute("Name", StepValue::Real("1.".into()))
+            .is_err());
+        assert!(wall.set_attribute("Height", StepValue::Omitted).is_err());
+        assert_eq!(wall.name.custom().unwrap().value, "Outer wall");
+    }
+
+    #[test]
+    fn reflects_entities_of_any_type() {
+        let mut ifc = IFC::from_file("resources/wall-standard-case.ifc").unwrap();
+
+        let ids: Vec<_> = ifc.data.iter().map(|(id, _)| *id).collect();
+        for id in ids {
+            let entity = ifc.data.try_get_untyped_mut(id).unwrap();
+            let attributes = entity.attributes();
+
+            if attributes.first().is_some_and(|a| a.name == "GlobalId") {
+                entity
+                    .set_attribute("Name", StepValue::String("renamed".into()))
+                    .unwrap();
+                assert_eq!(
+                    entity.attribute("Name").unwrap(),
+                    StepValue::String("renamed".into())
+                );
+            }
+        }
+
+        let written = ifc.to_string();
+        assert!(written.contains("IFCWALL('0DWgwt6o1FOx7466fPk$jl',#2,'renamed'"));
+        IFC::from_str(&written).unwrap();
+    }
+
+    #[test]
+    fn reflected_values_match_written_entities() {
+        for file in [
+            "resources/wall-standard-case.ifc",
+            "resources/AC20-FZK-Haus.ifc",
+        ] {
+            let ifc = IFC::from_file(file).unwrap();
+
+            for (id, entity) in ifc.data.iter() {
+                let Some(entity_type) = entity.entity_type() else {
+                    continue;
+                };
+
+                let text = entity.to_string();
+                let written = UnknownEntity::parse().parse(&text).unwrap();
+
+                assert_eq!(entity.keyword(), written.keyword, "{id} {text}");
+                assert_eq!(
+                    entity_type.attributes.len(),
+                    written.args.len(),
+                    "{id} {text}"
+                );
+                assert_eq!(entity.attribute_values(), written.args, "{id} {text}");
+            }
+        }
+    }
+}
@@ -16,6 +16,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcunitassignment.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcUnitAssignment")]
 pub struct UnitAssigment {
     /// Units to be included within a unit assignment.
     #[ifc_types(SiUnit, ConversionBasedUnit, DerivedUnit, MonetaryUnit)]
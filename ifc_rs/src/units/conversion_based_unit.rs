@@ -21,6 +21,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcconversionbasedunit.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcConversionBasedUnit")]
 pub struct ConversionBasedUnit {
     #[inherited]
     named_unit: NamedUnit,
@@ -221,6 +222,8 @@ impl IFCParse for ConversionUnitName {
     }
 }
 
+crate::traits::reflect::step_enumeration!(ConversionUnitName, String);
+
 #[cfg(test)]
 mod test {
     use winnow::Parser;
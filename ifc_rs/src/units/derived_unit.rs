@@ -13,6 +13,7 @@ use super::{
 
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcderivedunit.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcDerivedUnit")]
 pub struct DerivedUnit {
     /// The group of units and their exponents that define the derived unit.
     pub elements: IfcList<TypedId<DerivedUnitElement>>,
@@ -11,6 +11,7 @@ use super::{integer::IntegerPrimitive, IFCParse, IFCParser};
 
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcderivedunitelement.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcDerivedUnitElement")]
 pub struct DerivedUnitElement {
     /// The fixed quantity which is used as the mathematical factor.
     pub unit: TypedId<SiUnit>,
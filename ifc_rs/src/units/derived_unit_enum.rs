@@ -182,3 +182,5 @@ impl IFCParse for DerivedUnitEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(DerivedUnitEnum);
@@ -29,20 +29,28 @@ type ExponentType = i32;
 /// A velocity of 2 millimetres per second has a length exponent of 1 and a time exponent of -1.
 /// The remaining exponents are equal to 0.
 #[derive(IfcVerify)]
+#[ifc(name = "IfcDimensionalExponents")]
 pub struct DimensionalExponents {
     /// The power of the length base quantity.
+    #[ifc(name = "LengthExponent")]
     pub length: ExponentType,
     /// The power of the mass base quantity.
+    #[ifc(name = "MassExponent")]
     pub mass: ExponentType,
     /// The power of the time base quantity.
+    #[ifc(name = "TimeExponent")]
     pub time: ExponentType,
     /// The power of the electric current base quantity.
+    #[ifc(name = "ElectricCurrentExponent")]
     pub electric_current: ExponentType,
     /// The power of the thermodynamic temperature base quantity.
+    #[ifc(name = "ThermodynamicTemperatureExponent")]
     pub thermodynamic_temperature: ExponentType,
     /// The power of the amount of substance base quantity.
+    #[ifc(name = "AmountOfSubstanceExponent")]
     pub amount_of_substance: ExponentType,
     /// The power of the luminous intensity base quantity.
+    #[ifc(name = "LuminousIntensityExponent")]
     pub luminouse_intensity: ExponentType,
 }
 
@@ -16,11 +16,14 @@ use super::{IFCParse, IFCParser};
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmeasurewithunit.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcMeasureWithUnit")]
 pub struct MeasureWithUnit {
     /// The value of the physical quantity when expressed in the specified units.
+    #[ifc(name = "ValueComponent")]
     pub value: IdOr<PlaneAngleMeasure>,
 
     /// The unit in which the physical quantity is expressed.
+    #[ifc(name = "UnitComponent")]
     pub unit: TypedId<SiUnit>,
 }
 
@@ -56,7 +59,6 @@ mod test {
 
     use super::MeasureWithUnit;
 
-    #[ignore = "float representation doesn't match'"]
     #[test]
     fn measure_with_unit_round_trip() {
         let example = "IFCMEASUREWITHUNIT(IFCPLANEANGLEMEASURE(0.0174532925199),#16);";
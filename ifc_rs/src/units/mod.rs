@@ -14,25 +14,22 @@ pub(crate) mod shared;
 pub(crate) mod si_unit;
 pub(crate) mod unit_enum;
 
-use crate::{parser::*, traits::prelude::IfcType};
-use winnow::combinator::alt;
+use crate::parser::*;
 
-/// accumulator parser for all unit types into an opaque `dyn IfcType`
+/// accumulator for the registration of all unit types in the [`EntityRegistry`]
 pub struct Units;
 
 impl Units {
-    /// parser for any unit type into an opaque `dyn IfcType`
-    pub fn parse<'a>() -> impl IFCParser<'a, Box<dyn IfcType>> {
-        alt((
-            assignment::UnitAssigment::parse_any(),
-            conversion_based_unit::ConversionBasedUnit::parse_any(),
-            si_unit::SiUnit::parse_any(),
-            dimensional_exponents::DimensionalExponents::parse_any(),
-            measure_with_unit::MeasureWithUnit::parse_any(),
-            measure::Measures::parse(),
-            derived_unit::DerivedUnit::parse_any(),
-            derived_unit_element::DerivedUnitElement::parse_any(),
-            monetary_unit::MonetaryUnit::parse_any(),
-        ))
+    /// registers all unit types with the keywords of their entities
+    pub(crate) fn register(registry: &mut EntityRegistry) {
+        registry.register::<assignment::UnitAssigment>("IFCUNITASSIGNMENT");
+        registry.register::<conversion_based_unit::ConversionBasedUnit>("IFCCONVERSIONBASEDUNIT");
+        registry.register::<si_unit::SiUnit>("IFCSIUNIT");
+        registry.register::<dimensional_exponents::DimensionalExponents>("IFCDIMENSIONALEXPONENTS");
+        registry.register::<measure_with_unit::MeasureWithUnit>("IFCMEASUREWITHUNIT");
+        registry.register::<measure::PlaneAngleMeasure>("IFCPLANEANGLEMEASURE");
+        registry.register::<derived_unit::DerivedUnit>("IFCDERIVEDUNIT");
+        registry.register::<derived_unit_element::DerivedUnitElement>("IFCDERIVEDUNITELEMENT");
+        registry.register::<monetary_unit::MonetaryUnit>("IFCMONETARYUNIT");
     }
 }
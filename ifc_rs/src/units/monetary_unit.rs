@@ -9,6 +9,7 @@ use crate::{prelude::*, units::p_space_or_comment_surrounded};
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcmonetaryunit.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcMonetaryUnit")]
 pub struct MonetaryUnit {
     /// Code or name of the currency. Permissible values are the three-letter
     /// alphabetic currency codes as per ISO 4217, for example CNY, EUR, GBP, JPY, USD.
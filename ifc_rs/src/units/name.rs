@@ -146,3 +146,5 @@ impl IFCParse for IfcUnitName {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(IfcUnitName);
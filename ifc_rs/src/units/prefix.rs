@@ -84,3 +84,5 @@ impl IFCParse for IfcPrefix {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(IfcPrefix);
@@ -16,6 +16,7 @@ use crate::{
 ///
 /// https://standards.buildingsmart.org/IFC/DEV/IFC4_2/FINAL/HTML/link/ifcsiunit.htm
 #[derive(IfcVerify)]
+#[ifc(name = "IfcSIUnit", derived = "Dimensions")]
 pub struct SiUnit {
     #[inherited]
     named_unit: NamedUnit,
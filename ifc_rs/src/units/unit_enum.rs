@@ -142,3 +142,5 @@ impl IFCParse for IfcUnitEnum {
         )
     }
 }
+
+crate::traits::reflect::step_enumeration!(IfcUnitEnum);
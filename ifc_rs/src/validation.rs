@@ -0,0 +1,500 @@
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    fmt::Display,
+};
+
+use bevy_math::{DVec2, DVec3};
+
+use crate::{
+    id::{Id, TypedId},
+    prelude::*,
+    traits::reflect::Reflect,
+};
+
+/// Kind of schema rule which was violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RuleKind {
+    /// a WHERE rule of the entity, or of a concept which is only described informally
+    Where,
+    /// an aggregate attribute has fewer elements than its declared lower bound
+    Cardinality,
+    /// a UNIQUE rule, the value is used by several entities
+    Uniqueness,
+}
+
+impl Display for RuleKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            RuleKind::Where => "where rule",
+            RuleKind::Cardinality => "cardinality",
+            RuleKind::Uniqueness => "uniqueness",
+        };
+
+        write!(f, "{description}")
+    }
+}
+
+/// Rule of the schema which an entity doesn't satisfy, see [`validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// id of the entity which violates the rule
+    pub entity: Id,
+    /// keyword of the entity which violates the rule, e.g. `IFCDIRECTION`
+    pub keyword: String,
+    pub kind: RuleKind,
+    /// name of the rule as `Entity.Label`, e.g. `IfcDirection.MagnitudeGreaterZero`.
+    /// Cardinalities are named after their attribute, e.g. `IfcPolyline.Points`
+    pub rule: &'static str,
+    /// what exactly is wrong with the entity
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{entity} ({keyword}) violates {kind} {rule}: {message}",
+            entity = self.entity,
+            keyword = self.keyword,
+            kind = self.kind,
+            rule = self.rule,
+            message = self.message,
+        )
+    }
+}
+
+/// Checks the rules of the schema which go beyond the types of the referenced entities.
+///
+/// These are
+/// - WHERE rules of the entities, e.g. `IfcDirection.MagnitudeGreaterZero`
+/// - lower bounds of aggregates, e.g. at least one related object of an `IfcRelAggregates`
+/// - the uniqueness of the `GlobalId` of all rooted entities (`IfcRoot.UR1`)
+/// - the layer set direction of walls, slabs and roofs with an `IfcMaterialLayerSetUsage`,
+///   which the IFC4 documentation only describes informally
+///
+/// References to missing entities or entities of the wrong type are already rejected when
+/// reading a model, see [`DataMap::validate_references`](crate::meta::datamap::DataMap).
+/// The violations are ordered by the id of the entity.
+pub fn validate(ifc: &IFC) -> Vec<Violation> {
+    let mut validator = Validator {
+        ifc,
+        violations: Vec::new(),
+    };
+
+    for (id, entity) in ifc.data.iter() {
+        validator.check_entity(*id, &**entity);
+    }
+    validator.check_global_ids();
+    validator.check_layer_set_usages();
+
+    let mut violations = validator.violations;
+    violations.sort_by_key(|violation| violation.entity);
+    violations
+}
+
+struct Validator<'a> {
+    ifc: &'a IFC,
+    violations: Vec<Violation>,
+}
+
+impl Validator<'_> {
+    fn check_entity(&mut self, id: Id, entity: &dyn IfcType) {
+        if let Some(direction) = entity.downcast_ref::<Direction2D>() {
+            self.magnitude_greater_zero(id, direction.0 .0 == DVec2::ZERO);
+        } else if let Some(direction) = entity.downcast_ref::<Direction3D>() {
+            self.magnitude_greater_zero(id, direction.0 .0 == DVec3::ZERO);
+        } else if let Some(polyline) = entity.downcast_ref::<PolyLine>() {
+            self.at_least(
+                id,
+                "IfcPolyline.Points",
+                polyline.points.0.len(),
+                2,
+                "points",
+            );
+            self.same_dimension(id, &polyline.points.0);
+        } else if let Some(aggregates) = entity.downcast_ref::<RelAggregates>() {
+            let related = &aggregates.related_objects.0;
+            self.at_least(
+                id,
+                "IfcRelAggregates.RelatedObjects",
+                related.len(),
+                1,
+                "objects",
+            );
+
+            if related.contains(&aggregates.relating_object) {
+                self.push(
+                    id,
+                    RuleKind::Where,
+                    "IfcRelAggregates.NoSelfReference",
+                    format!("{} aggregates itself", aggregates.relating_object),
+                );
+            }
+        } else if let Some(contained) = entity.downcast_ref::<RelContainedInSpatialStructure>() {
+            self.at_least(
+                id,
+                "IfcRelContainedInSpatialStructure.RelatedElements",
+                contained.related_elements.0.len(),
+                1,
+                "elements",
+            );
+        } else if let Some(defines) = entity.downcast_ref::<RelDefinesByProperties>() {
+            self.at_least(
+                id,
+                "IfcRelDefinesByProperties.RelatedObjects",
+                defines.related_objects.0.len(),
+                1,
+                "objects",
+            );
+        } else if let Some(defines) = entity.downcast_ref::<RelDefinesByType>() {
+            self.at_least(
+                id,
+                "IfcRelDefinesByType.RelatedObjects",
+                defines.related_objects.0.len(),
+                1,
+                "objects",
+            );
+        } else if let Some(declares) = entity.downcast_ref::<RelDeclares>() {
+            self.at_least(
+                id,
+                "IfcRelDeclares.RelatedDefinitions",
+                declares.related_definitions.0.len(),
+                1,
+                "definitions",
+            );
+        } else if let Some(associates) = entity.downcast_ref::<RelAssociatesMaterial>() {
+            self.at_least(
+                id,
+                "IfcRelAssociates.RelatedObjects",
+                associates.related_objects.0.len(),
+                1,
+                "objects",
+            );
+        } else if let Some(layer_set) = entity.downcast_ref::<MaterialLayerSet>() {
+            self.at_least(
+                id,
+                "IfcMaterialLayerSet.MaterialLayers",
+                layer_set.material_layers.0.len(),
+                1,
+                "layers",
+            );
+        } else if let Some(representation) = entity.downcast_ref::<ShapeRepresentation>() {
+            self.at_least(
+                id,
+                "IfcRepresentation.Items",
+                representation.items.0.len(),
+                1,
+                "items",
+            );
+        } else if let Some(shape) = entity.downcast_ref::<ProductDefinitionShape>() {
+            self.at_least(
+                id,
+                "IfcProductRepresentation.Representations",
+                shape.representations.0.len(),
+                1,
+                "representations",
+            );
+        }
+    }
+
+    fn magnitude_greater_zero(&mut self, id: Id, is_zero: bool) {
+        if is_zero {
+            self.push(
+                id,
+                RuleKind::Where,
+                "IfcDirection.MagnitudeGreaterZero",
+                "all direction ratios are zero",
+            );
+        }
+    }
+
+    /// `IfcPolyline.SameDim`, all points are either 2D or 3D
+    fn same_dimension(&mut self, id: Id, points: &[Id]) {
+        let is_2d = |point: &Id| {
+            self.ifc
+                .data
+                .try_get_untyped(*point)
+                .map(|point| point.downcast_ref::<Point2D>().is_some())
+        };
+
+        let Some(first) = points.first() else {
+            return;
+        };
+
+        if let Some(other) = points.iter().find(|point| is_2d(point) != is_2d(first)) {
+            self.push(
+                id,
+                RuleKind::Where,
+                "IfcPolyline.SameDim",
+                format!("{first} and {other} have different dimensions"),
+            );
+        }
+    }
+
+    fn at_least(&mut self, id: Id, rule: &'static str, count: usize, min: usize, what: &str) {
+        if count < min {
+            self.push(
+                id,
+                RuleKind::Cardinality,
+                rule,
+                format!("needs at least {min} {what}, but has {count}"),
+            );
+        }
+    }
+
+    /// `IfcRoot.UR1`, every entity with a `GlobalId` has its own
+    fn check_global_ids(&mut self) {
+        let mut first_use = BTreeMap::new();
+
+        for (id, entity) in self.ifc.data.iter() {
+            let Ok(StepValue::String(global_id)) = (**entity).attribute("GlobalId") else {
+                continue;
+            };
+
+            match first_use.entry(global_id) {
+                Entry::Vacant(entry) => {
+                    entry.insert(*id);
+                }
+                Entry::Occupied(entry) => self.push(
+                    *id,
+                    RuleKind::Uniqueness,
+                    "IfcRoot.UR1",
+                    format!(
+                        "GlobalId '{}' is already used by {}",
+                        entry.key(),
+                        entry.get()
+                    ),
+                ),
+            }
+        }
+    }
+
+    /// Walls are layered along `AXIS2` and slabs and roofs along `AXIS3`. All layer set usages of
+    /// an element have to agree in direction and sense, otherwise the layers can't be placed.
+    fn check_layer_set_usages(&mut self) {
+        let mut usages: BTreeMap<Id, Vec<(Id, &MaterialLayerSetUsage)>> = BTreeMap::new();
+
+        for (_, associates) in self.ifc.data.find_all_of_type::<RelAssociatesMaterial>() {
+            let usage_id = TypedId::<MaterialLayerSetUsage>::new(associates.relating_material);
+            let Some(usage) = self.ifc.data.try_get(usage_id) else {
+                continue;
+            };
+
+            for element in associates.related_objects.iter() {
+                usages
+                    .entry(*element)
+                    .or_default()
+                    .push((associates.relating_material, usage));
+            }
+        }
+
+        for (element, usages) in usages {
+            let Some(entity) = self.ifc.data.try_get_untyped(element) else {
+                continue;
+            };
+
+            let expected = if entity.downcast_ref::<Wall>().is_some() {
+                Some(LayerSetDirectionEnum::Axis2)
+            } else if entity.downcast_ref::<Slab>().is_some()
+                || entity.downcast_ref::<Roof>().is_some()
+            {
+                Some(LayerSetDirectionEnum::Axis3)
+            } else {
+                None
+            };
+
+            if let Some(expected) = expected {
+                for (usage_id, usage) in &usages {
+                    if usage.layer_set_direction != expected {
+                        self.push(
+                            element,
+                            RuleKind::Where,
+                            "IfcMaterialLayerSetUsage.LayerSetDirection",
+                            format!(
+                                "is layered along {expected}, but {usage_id} uses {}",
+                                usage.layer_set_direction
+                            ),
+                        );
+                    }
+                }
+            }
+
+            let (first_id, first) = usages[0];
+            for (usage_id, usage) in &usages[1..] {
+                if usage.direction_sense != first.direction_sense
+                    || usage.layer_set_direction != first.layer_set_direction
+                {
+                    self.push(
+                        element,
+                        RuleKind::Where,
+                        "IfcMaterialLayerSetUsage.DirectionSense",
+                        format!(
+                            "{first_id} places the layers {} {}, but {usage_id} {} {}",
+                            first.layer_set_direction,
+                            first.direction_sense,
+                            usage.layer_set_direction,
+                            usage.direction_sense
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn push(&mut self, entity: Id, kind: RuleKind, rule: &'static str, message: impl Into<String>) {
+        let keyword = self
+            .ifc
+            .data
+            .try_get_untyped(entity)
+            .map(|entity| entity.keyword().to_owned())
+            .unwrap_or_default();
+
+        self.violations.push(Violation {
+            entity,
+            keyword,
+            kind,
+            rule,
+            message: message.into(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{RuleKind, Violation};
+    use crate::{id::Id, prelude::*};
+
+    fn model(data: &str) -> IFC {
+        IFC::from_str(&format!(
+            "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+{data}
+ENDSEC;
+END-ISO-10303-21;
+"
+        ))
+        .unwrap()
+    }
+
+    fn rules(ifc: &IFC) -> Vec<(Id, &'static str)> {
+        ifc.validate()
+            .into_iter()
+            .map(|violation| (violation.entity, violation.rule))
+            .collect()
+    }
+
+    #[test]
+    fn reports_where_rules() {
+        let ifc = model(
+            "#1= IFCDIRECTION((0.,0.,0.));
+#2= IFCDIRECTION((0.,0.,1.));
+#3= IFCCARTESIANPOINT((0.,0.));
+#4= IFCCARTESIANPOINT((1.,0.,0.));
+#5= IFCPOLYLINE((#3,#4));
+#6= IFCPOLYLINE((#3));",
+        );
+
+        assert_eq!(
+            ifc.validate(),
+            [
+                Violation {
+                    entity: Id(1),
+                    keyword: "IFCDIRECTION".to_owned(),
+                    kind: RuleKind::Where,
+                    rule: "IfcDirection.MagnitudeGreaterZero",
+                    message: "all direction ratios are zero".to_owned(),
+                },
+                Violation {
+                    entity: Id(5),
+                    keyword: "IFCPOLYLINE".to_owned(),
+                    kind: RuleKind::Where,
+                    rule: "IfcPolyline.SameDim",
+                    message: "#3 and #4 have different dimensions".to_owned(),
+                },
+                Violation {
+                    entity: Id(6),
+                    keyword: "IFCPOLYLINE".to_owned(),
+                    kind: RuleKind::Cardinality,
+                    rule: "IfcPolyline.Points",
+                    message: "needs at least 2 points, but has 1".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_cardinalities_and_duplicate_global_ids() {
+        let ifc = model(
+            "#1= IFCBUILDING('0DWgwt6o1FOx7466fPk$jl',$,'Building',$,$,$,$,$,.ELEMENT.,$,$,$);
+#2= IFCBUILDINGSTOREY('0DWgwt6o1FOx7466fPk$jl',$,'Storey',$,$,$,$,$,.ELEMENT.,$);
+#3= IFCRELAGGREGATES('1DWgwt6o1FOx7466fPk$jl',$,$,$,#1,());
+#4= IFCRELAGGREGATES('2DWgwt6o1FOx7466fPk$jl',$,$,$,#1,(#2));",
+        );
+
+        assert_eq!(
+            rules(&ifc),
+            [
+                (Id(2), "IfcRoot.UR1"),
+                (Id(3), "IfcRelAggregates.RelatedObjects")
+            ]
+        );
+        assert_eq!(
+            ifc.validate()[0].to_string(),
+            "#2 (IFCBUILDINGSTOREY) violates uniqueness IfcRoot.UR1: \
+             GlobalId '0DWgwt6o1FOx7466fPk$jl' is already used by #1"
+        );
+    }
+
+    #[test]
+    fn reports_inconsistent_layer_set_usages() {
+        let ifc = model(
+            "#1= IFCMATERIAL('Concrete',$,$);
+#2= IFCMATERIALLAYER(#1,0.2,$,$,$,$,$);
+#3= IFCMATERIALLAYERSET((#2),'Wall',$);
+#4= IFCMATERIALLAYERSETUSAGE(#3,.AXIS3.,.POSITIVE.,0.,$);
+#5= IFCMATERIALLAYERSETUSAGE(#3,.AXIS2.,.NEGATIVE.,0.,$);
+#6= IFCWALL('0DWgwt6o1FOx7466fPk$jl',$,$,$,$,$,$,$,$);
+#7= IFCRELASSOCIATESMATERIAL('1DWgwt6o1FOx7466fPk$jl',$,$,$,(#6),#4);
+#8= IFCRELASSOCIATESMATERIAL('2DWgwt6o1FOx7466fPk$jl',$,$,$,(#6),#5);",
+        );
+
+        let messages: Vec<_> = ifc
+            .validate()
+            .into_iter()
+            .map(|violation| (violation.entity, violation.rule, violation.message))
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                (
+                    Id(6),
+                    "IfcMaterialLayerSetUsage.LayerSetDirection",
+                    "is layered along .AXIS2., but #4 uses .AXIS3.".to_owned()
+                ),
+                (
+                    Id(6),
+                    "IfcMaterialLayerSetUsage.DirectionSense",
+                    "#4 places the layers .AXIS3. .POSITIVE., but #5 .AXIS2. .NEGATIVE.".to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn resources_are_valid() {
+        for file in [
+            "resources/wall-standard-case.ifc",
+            "resources/AC20-FZK-Haus.ifc",
+        ] {
+            let ifc = IFC::from_file(file).unwrap();
+            assert_eq!(ifc.validate(), [], "{file}");
+        }
+    }
+}
@@ -1,10 +1,14 @@
 use std::fmt::Display;
 
+use anyhow::{bail, Result};
 use winnow::combinator::alt;
 use winnow::Parser;
 
 use super::prelude::*;
-use crate::parser::{IFCParse, IFCParser};
+use crate::{
+    parser::{IFCParse, IFCParser, StepValue},
+    traits::reflect::{AttributeKind, StepAttribute},
+};
 
 /// IfcValue is a select type for selecting between more specialised select types IfcSimpleValue,
 /// IfcMeasureValue and IfcDerivedMeasureValue.
@@ -37,6 +41,43 @@ impl IFCParse for IfcValue {
     }
 }
 
+impl StepAttribute for IfcValue {
+    const KIND: AttributeKind = AttributeKind::Select;
+
+    fn to_step_value(&self) -> StepValue {
+        let (keyword, value) = match self {
+            IfcValue::Bool(v) => ("IFCBOOLEAN", v.0.to_step_value()),
+            IfcValue::Label(v) => ("IFCLABEL", v.0.to_step_value()),
+            IfcValue::Real(v) => ("IFCREAL", v.0.to_step_value()),
+            IfcValue::Identifier(v) => ("IFCIDENTIFIER", v.0.to_step_value()),
+            IfcValue::ThermalTransmittance(v) => {
+                ("IFCTHERMALTRANSMITTANCEMEASURE", v.0.to_step_value())
+            }
+        };
+
+        StepValue::Typed(keyword.to_owned(), Box::new(value))
+    }
+
+    fn from_step_value(value: &StepValue) -> Result<Self> {
+        let StepValue::Typed(keyword, value) = value else {
+            bail!("expected typed value, found `{value}`");
+        };
+
+        Ok(match keyword.to_uppercase().as_str() {
+            "IFCBOOLEAN" => Self::Bool(BoolValue(StepAttribute::from_step_value(value)?)),
+            "IFCLABEL" => Self::Label(LabelValue(StepAttribute::from_step_value(value)?)),
+            "IFCREAL" => Self::Real(RealValue(StepAttribute::from_step_value(value)?)),
+            "IFCIDENTIFIER" => {
+                Self::Identifier(IdentifierValue(StepAttribute::from_step_value(value)?))
+            }
+            "IFCTHERMALTRANSMITTANCEMEASURE" => Self::ThermalTransmittance(
+                ThermalTransmittanceValue(StepAttribute::from_step_value(value)?),
+            ),
+            _ => bail!("unsupported value type {keyword}"),
+        })
+    }
+}
+
 impl Display for IfcValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1,4 +1,3 @@
-use real::format_non_sci_double;
 use std::fmt::Display;
 use winnow::{
     combinator::{alt, delimited},
@@ -32,11 +31,7 @@ impl IFCParse for ThermalTransmittanceValue {
 
 impl Display for ThermalTransmittanceValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "IFCTHERMALTRANSMITTANCEMEASURE({})",
-            format_non_sci_double(self.0 .0)
-        )
+        write!(f, "IFCTHERMALTRANSMITTANCEMEASURE({})", self.0)
     }
 }
 
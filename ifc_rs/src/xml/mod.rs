@@ -0,0 +1,1062 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Write,
+    sync::Arc,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::DateTime;
+use itertools::Itertools;
+use quick_xml::{
+    events::{BytesDecl, BytesStart, BytesText, Event},
+    Reader, Writer,
+};
+use winnow::Parser;
+
+use crate::{
+    id::Id,
+    meta::{
+        datamap::DataMap,
+        header::{
+            details::{
+                Author, AuthorList, Authorization, FileName, Organization, OrganizationList,
+                OriginatingSystem, PreprocessorVersion, TimeStamp,
+            },
+            schema::{FileSchema, FileSchemas},
+            Header,
+        },
+    },
+    parser::{
+        defined_types::{defined_type_kind, defined_type_name},
+        export::ExportedEntities,
+        real::format_real_primitive,
+        string::{decode, encode},
+        unknown_entity::UnknownEntity,
+        EntityRegistry,
+    },
+    prelude::*,
+    traits::reflect::{AttributeKind, EntityType},
+};
+
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+const IFC4_NAMESPACE: &str = "http://www.buildingsmart-tech.org/ifcXML/IFC4/final";
+
+/// schema of a namespace, documents without namespace are read as IFC4
+fn schema_of_namespace(namespace: &str) -> FileSchema {
+    let namespace = namespace.to_uppercase();
+
+    if namespace.contains("2X3") {
+        FileSchema::IFC2X3
+    } else if namespace.contains("4_3") || namespace.contains("4X3") {
+        FileSchema::IFC4X3_ADD2
+    } else {
+        FileSchema::IFC4
+    }
+}
+
+/// name of the attributes of entities which aren't modelled by this crate
+fn positional_attribute(index: usize) -> String {
+    format!("Attribute{}", index + 1)
+}
+
+/// Writes the model as ifcXML document (ISO 10303-28), following the conventions of the IFC4
+/// ifcXML schema. Only IFC4 models are supported, since IFC2X3 uses another document structure
+/// and the entities of this crate use the IFC4 layouts, see
+/// [`convert_schema`](crate::conversion::convert_schema).
+///
+///
+/// - every entity is an element named after its type with an `id` attribute, e.g.
+///   `<IfcWall id="i31" GlobalId="..." Name="Wall">`
+/// - simple values (strings, numbers, enumerations and lists of numbers) are XML attributes
+/// - references are child elements with a `ref` attribute, e.g.
+///   `<OwnerHistory><IfcOwnerHistory ref="i6" xsi:nil="true"/></OwnerHistory>`
+/// - typed values are wrapped, e.g. `<NominalValue><IfcLabel-wrapper>..</IfcLabel-wrapper></NominalValue>`
+/// - aggregates are child elements with `cType="list"`
+///
+/// Attribute names are taken from the schema definitions of the entities this crate models, other
+/// entities use positional names like `Attribute1`.
+///
+/// [`Dummy`] entities are written as described at [`to_writer_with_dummies`].
+pub fn to_writer(ifc: &IFC, writer: impl Write) -> Result<()> {
+    to_writer_with_dummies(ifc, writer).map(|_| ())
+}
+
+/// Writes the model like [`to_writer`] and returns the ids of the [`Dummy`] entities, whose
+/// attributes couldn't be written by their schema definition. They are written with positional
+/// attributes or, if even their attributes are malformed, as STEP text in a `raw` attribute with
+/// the entities it references in a `references` list.
+pub fn to_writer_with_dummies(ifc: &IFC, writer: impl Write) -> Result<Vec<Id>> {
+    if let Some(schema) = ifc
+        .header
+        .schema
+        .0
+        .first()
+        .filter(|schema| **schema != FileSchema::IFC4)
+    {
+        bail!("ifcXML is only supported for IFC4 models, not {schema}");
+    }
+
+    let ExportedEntities {
+        entities,
+        entity_types,
+        raw,
+        dummies,
+    } = ExportedEntities::new(&ifc.data, "ifcXML")?;
+
+    let mut exporter = Exporter {
+        writer: Writer::new_with_indent(writer, b' ', 2),
+        entities: &entities,
+        entity_types: &entity_types,
+        raw: &raw,
+    };
+
+    exporter.document(&ifc.header)?;
+    exporter.writer.into_inner().flush()?;
+
+    Ok(dummies)
+}
+
+/// Exports the model as ifcXML document, see [`to_writer`].
+pub fn to_string(ifc: &IFC) -> Result<String> {
+    let mut buffer = Vec::new();
+    to_writer(ifc, &mut buffer)?;
+
+    Ok(String::from_utf8(buffer)?)
+}
+
+/// Imports a model from an IFC4 ifcXML document, see [`to_writer`]. Documents of other schemas
+/// are rejected.
+///
+/// Besides the references which are written by [`to_writer`], entities can also be nested into
+/// the attribute which references them. The entities get new STEP ids, ordered so that
+/// referenced entities come first. Elements which aren't attributes in the schema definition of
+/// the entity (e.g. inverse attributes) are ignored.
+pub fn from_str(s: &str) -> Result<IFC> {
+    let root = Element::parse_document(s)?;
+
+    if root.name != "ifcXML" {
+        bail!(
+            "expected an IFC4 ifcXML document, found root element `{}`",
+            root.name
+        );
+    }
+
+    let namespace = root.attribute("xmlns").unwrap_or_default();
+    let schema = schema_of_namespace(namespace);
+    if schema != FileSchema::IFC4 {
+        bail!("ifcXML is only supported for IFC4 documents, not {schema}");
+    }
+
+    let mut header = IFC::default().header;
+    header.schema = FileSchemas(vec![schema]);
+
+    if let Some(element) = root.children.iter().find(|child| child.name == "header") {
+        read_header(element, &mut header)?;
+    }
+
+    let entities = root
+        .children
+        .iter()
+        .filter(|child| child.name != "header")
+        .collect::<Vec<_>>();
+
+    let mut data = Importer::new(&entities).import(&entities)?;
+    data.renumber()?;
+
+    let ifc = IFC {
+        header,
+        data,
+        ..Default::default()
+    };
+    ifc.verify()?;
+
+    Ok(ifc)
+}
+
+fn decode_lossy(raw: &str) -> String {
+    decode(raw).unwrap_or_else(|| raw.to_owned())
+}
+
+/// text of simple values, following the XML schema conventions
+fn simple_text(value: &StepValue) -> Option<String> {
+    Some(match value {
+        StepValue::Integer(integer) => integer.to_string(),
+        StepValue::Real(real) => real.clone(),
+        StepValue::String(string) => decode_lossy(string),
+        StepValue::Enum(value) => match value.as_str() {
+            "T" | "TRUE" => "true".into(),
+            "F" | "FALSE" => "false".into(),
+            "U" | "UNKNOWN" => "unknown".into(),
+            value => value.to_lowercase(),
+        },
+        StepValue::Binary(binary) => binary.clone(),
+        StepValue::List(values) if is_number_list(values) => values
+            .iter()
+            .map(|value| simple_text(value).expect("numbers are simple values"))
+            .join(" "),
+        _ => return None,
+    })
+}
+
+/// lists of numbers are written space separated, single values can't be told apart from lists
+/// with one element though
+fn is_number_list(values: &[StepValue]) -> bool {
+    values.len() > 1
+        && values
+            .iter()
+            .all(|value| matches!(value, StepValue::Integer(_) | StepValue::Real(_)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextKind {
+    String,
+    Enumeration,
+    Other,
+}
+
+/// counterpart to [`simple_text`]
+fn simple_value(text: &str, kind: TextKind) -> StepValue {
+    match (kind, text) {
+        (TextKind::String, text) => StepValue::String(encode(text)),
+        (_, "true") => StepValue::Enum("T".into()),
+        (_, "false") => StepValue::Enum("F".into()),
+        (_, "unknown") => StepValue::Enum("U".into()),
+        (TextKind::Enumeration, text) => StepValue::Enum(text.to_uppercase()),
+        (TextKind::Other, text) => number(text)
+            .or_else(|| {
+                let values = text
+                    .split_whitespace()
+                    .map(number)
+                    .collect::<Option<Vec<_>>>()?;
+                (values.len() > 1).then_some(StepValue::List(values))
+            })
+            .unwrap_or_else(|| StepValue::String(encode(text))),
+    }
+}
+
+fn number(text: &str) -> Option<StepValue> {
+    if let Ok(integer) = text.parse() {
+        return Some(StepValue::Integer(integer));
+    }
+
+    // `inf` and `NaN` are valid floats but no numbers in STEP files
+    let is_real = text
+        .bytes()
+        .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'-' | b'+' | b'e' | b'E'));
+
+    is_real
+        .then(|| text.parse().ok())
+        .flatten()
+        .map(|real| StepValue::Real(format_real_primitive(real)))
+}
+
+struct Exporter<'a, W: Write> {
+    writer: Writer<W>,
+    entities: &'a BTreeMap<Id, UnknownEntity>,
+    entity_types: &'a HashMap<Id, &'static EntityType>,
+    raw: &'a HashMap<Id, String>,
+}
+
+impl<'a, W: Write> Exporter<'a, W> {
+    fn document(&mut self, header: &Header) -> Result<()> {
+        self.writer
+            .write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+        let root = BytesStart::new("ifcXML")
+            .with_attributes([("xmlns", IFC4_NAMESPACE), ("xmlns:xsi", XSI_NAMESPACE)]);
+        self.writer.write_event(Event::Start(root.borrow()))?;
+
+        self.header(header)?;
+
+        for (id, entity) in self.entities {
+            self.entity(*id, entity)?;
+        }
+
+        self.writer.write_event(Event::End(root.to_end()))?;
+
+        Ok(())
+    }
+
+    fn header(&mut self, header: &Header) -> Result<()> {
+        let name = &header.name;
+        let documentation = header
+            .description
+            .descriptions
+            .iter()
+            .map(ToString::to_string);
+
+        let elements = [
+            ("name", decode_lossy(&name.name.0)),
+            (
+                "time_stamp",
+                name.timestamp.0.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            ),
+        ]
+        .into_iter()
+        .chain(documentation.map(|view| ("documentation", view)))
+        .chain(
+            name.author
+                .iter()
+                .map(|author| ("author", decode_lossy(&author.0))),
+        )
+        .chain(
+            name.organization
+                .iter()
+                .map(|organization| ("organization", decode_lossy(&organization.0))),
+        )
+        .chain([
+            (
+                "preprocessor_version",
+                decode_lossy(&name.preprocessor_version.0),
+            ),
+            (
+                "originating_system",
+                decode_lossy(&name.originating_system.0),
+            ),
+            ("authorization", decode_lossy(&name.authorization.0)),
+        ]);
+
+        self.writer
+            .write_event(Event::Start(BytesStart::new("header")))?;
+
+        for (element, text) in elements {
+            self.text_element(element, &[], &text)?;
+        }
+
+        self.writer
+            .write_event(Event::End(BytesStart::new("header").to_end()))?;
+
+        Ok(())
+    }
+
+    fn entity(&mut self, id: Id, entity: &UnknownEntity) -> Result<()> {
+        let entity_type = self.entity_types.get(&id);
+
+        let mut element = BytesStart::new(self.type_name(id)?);
+        element.push_attribute(("id", format!("i{}", id.0).as_str()));
+
+        if let Some(raw) = self.raw.get(&id) {
+            element.push_attribute(("raw", raw.as_str()));
+            return self.raw_entity(element, &entity.args);
+        }
+
+        let mut children = Vec::new();
+
+        for (index, value) in entity.args.iter().enumerate() {
+            let Some(attribute) =
+                entity_type.and_then(|entity_type| entity_type.attributes.get(index))
+            else {
+                // without a schema the kind of values is kept by writing them as items, which
+                // includes omitted ones, so that the attributes can be counted
+                children.push((positional_attribute(index), value));
+                continue;
+            };
+            let name = attribute.name.to_owned();
+
+            match value {
+                StepValue::Omitted | StepValue::Inherited => {}
+                value => match simple_text(value) {
+                    Some(text) => element.push_attribute((name.as_str(), text.as_str())),
+                    None => children.push((name, value)),
+                },
+            }
+        }
+
+        if children.is_empty() {
+            self.writer.write_event(Event::Empty(element))?;
+            return Ok(());
+        }
+
+        self.writer.write_event(Event::Start(element.borrow()))?;
+
+        for (name, value) in children {
+            let mut attribute = BytesStart::new(name.as_str());
+
+            match value {
+                StepValue::List(values) => {
+                    attribute.push_attribute(("cType", "list"));
+                    self.writer.write_event(Event::Start(attribute.borrow()))?;
+                    for value in values {
+                        self.item(value)?;
+                    }
+                }
+                value => {
+                    self.writer.write_event(Event::Start(attribute.borrow()))?;
+                    self.item(value)?;
+                }
+            }
+
+            self.writer.write_event(Event::End(attribute.to_end()))?;
+        }
+
+        self.writer.write_event(Event::End(element.to_end()))?;
+
+        Ok(())
+    }
+
+    /// dummy which is kept as STEP text, with the entities it references as list
+    fn raw_entity(&mut self, element: BytesStart, references: &[StepValue]) -> Result<()> {
+        if references.is_empty() {
+            self.writer.write_event(Event::Empty(element))?;
+            return Ok(());
+        }
+
+        let list = BytesStart::new("references").with_attributes([("cType", "list")]);
+
+        self.writer.write_event(Event::Start(element.borrow()))?;
+        self.writer.write_event(Event::Start(list.borrow()))?;
+        for reference in references {
+            self.item(reference)?;
+        }
+        self.writer.write_event(Event::End(list.to_end()))?;
+        self.writer.write_event(Event::End(element.to_end()))?;
+
+        Ok(())
+    }
+
+    /// values within attribute elements and lists
+    fn item(&mut self, value: &StepValue) -> Result<()> {
+        match value {
+            StepValue::Id(id) => {
+                let reference = BytesStart::new(self.type_name(*id)?)
+                    .with_attributes([("ref", format!("i{}", id.0).as_str()), ("xsi:nil", "true")]);
+                self.writer.write_event(Event::Empty(reference))?;
+            }
+            StepValue::Typed(keyword, value) => {
+                let name = defined_type_name(keyword).unwrap_or(keyword);
+                let text = simple_text(value)
+                    .with_context(|| format!("can't write typed value `{keyword}({value})`"))?;
+
+                self.text_element(&format!("{name}-wrapper"), &[], &text)?;
+            }
+            StepValue::List(values) if is_number_list(values) => {
+                let text = simple_text(value).expect("numbers are simple values");
+                self.text_element("list", &[], &text)?;
+            }
+            StepValue::List(values) => {
+                self.writer
+                    .write_event(Event::Start(BytesStart::new("list")))?;
+                for value in values {
+                    self.item(value)?;
+                }
+                self.writer
+                    .write_event(Event::End(BytesStart::new("list").to_end()))?;
+            }
+            StepValue::Omitted | StepValue::Inherited => {
+                self.writer
+                    .write_event(Event::Empty(BytesStart::new("unset")))?;
+            }
+            value => {
+                let name = match value {
+                    StepValue::Integer(_) => "integer",
+                    StepValue::Real(_) => "real",
+                    StepValue::String(_) => "string",
+                    StepValue::Enum(_) => "enum",
+                    _ => "binary",
+                };
+                let text = simple_text(value).expect("simple value");
+                self.text_element(name, &[], &text)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn text_element(&mut self, name: &str, attributes: &[(&str, &str)], text: &str) -> Result<()> {
+        let element = BytesStart::new(name).with_attributes(attributes.iter().copied());
+
+        self.writer.write_event(Event::Start(element.borrow()))?;
+        self.writer.write_event(Event::Text(BytesText::new(text)))?;
+        self.writer.write_event(Event::End(element.to_end()))?;
+
+        Ok(())
+    }
+
+    fn type_name(&self, id: Id) -> Result<&'a str> {
+        let keyword = &self
+            .entities
+            .get(&id)
+            .with_context(|| format!("reference to {id} which doesn't exist"))?
+            .keyword;
+
+        Ok(match self.entity_types.get(&id) {
+            Some(entity_type) => entity_type.name,
+            None => keyword,
+        })
+    }
+}
+
+fn read_header(element: &Element, header: &mut Header) -> Result<()> {
+    let texts = |name: &'static str| {
+        element
+            .children
+            .iter()
+            .filter(move |child| child.name == name)
+            .map(|child| encode(&child.text))
+    };
+    let text = |name: &'static str| texts(name).next().unwrap_or_default();
+
+    let details = &mut header.name;
+    details.name = FileName(text("name"));
+    details.preprocessor_version = PreprocessorVersion(text("preprocessor_version"));
+    details.originating_system = OriginatingSystem(text("originating_system"));
+    details.authorization = Authorization(text("authorization"));
+
+    let authors = texts("author").map(Author).collect::<Vec<_>>();
+    if !authors.is_empty() {
+        details.author = AuthorList(authors);
+    }
+
+    let organizations = texts("organization").map(Organization).collect::<Vec<_>>();
+    if !organizations.is_empty() {
+        details.organization = OrganizationList(organizations);
+    }
+
+    if let Some(time_stamp) = element.child("time_stamp") {
+        let time_stamp = &time_stamp.text;
+        let parsed = DateTime::parse_from_rfc3339(time_stamp)
+            .or_else(|_| DateTime::parse_from_rfc3339(&format!("{time_stamp}Z")))
+            .with_context(|| format!("invalid time stamp `{time_stamp}`"))?;
+
+        details.timestamp = TimeStamp(parsed.into());
+    }
+
+    header.description.descriptions = element
+        .children
+        .iter()
+        .filter(|child| child.name == "documentation")
+        .map(|child| {
+            Header::p_view_definition()
+                .parse(child.text.as_str())
+                .map_err(|err| anyhow!("invalid view definition `{}`: {err}", child.text))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(())
+}
+
+struct Importer {
+    registry: Arc<EntityRegistry>,
+    ids: HashMap<String, Id>,
+    next_id: usize,
+    entities: Vec<(Id, UnknownEntity)>,
+    dummies: Vec<(Id, Dummy)>,
+}
+
+impl Importer {
+    fn new(elements: &[&Element]) -> Self {
+        fn collect_ids(element: &Element, ids: &mut HashMap<String, Id>) {
+            if let Some(id) = element.attribute("id") {
+                let next = Id(ids.len() + 1);
+                ids.entry(id.to_owned()).or_insert(next);
+            }
+
+            for child in &element.children {
+                collect_ids(child, ids);
+            }
+        }
+
+        // entities with ids get the first ids, so they can be referenced before they're imported
+        let mut ids = HashMap::new();
+        for element in elements {
+            collect_ids(element, &mut ids);
+        }
+
+        Self {
+            registry: EntityRegistry::global(),
+            next_id: ids.len() + 1,
+            ids,
+            entities: Vec::new(),
+            dummies: Vec::new(),
+        }
+    }
+
+    fn import(mut self, elements: &[&Element]) -> Result<DataMap> {
+        for element in elements {
+            self.entity(element)?;
+        }
+
+        let registry = self.registry;
+
+        let mut entities = self
+            .entities
+            .into_iter()
+            .map(|(id, entity)| Ok((id, entity.into_entity(&registry)?)))
+            .collect::<Result<Vec<_>>>()?;
+        entities.extend(
+            self.dummies
+                .into_iter()
+                .map(|(id, dummy)| (id, Box::new(dummy) as Box<dyn IfcType>)),
+        );
+
+        Ok(DataMap::from(entities))
+    }
+
+    fn entity(&mut self, element: &Element) -> Result<Id> {
+        let id = match element.attribute("id") {
+            Some(id) => self.ids[id],
+            None => {
+                let id = Id(self.next_id);
+                self.next_id += 1;
+                id
+            }
+        };
+
+        let keyword = element.name.to_uppercase();
+
+        if let Some(raw) = element.attribute("raw") {
+            let dummy = self.dummy(element, raw)?;
+            self.dummies.push((id, dummy));
+
+            return Ok(id);
+        }
+
+        // entities which couldn't be parsed on export are written with positional attributes, even
+        // if their type is modelled
+        let positional = positional_attribute(0);
+        let entity_type = self.registry.entity_type(&keyword).filter(|_| {
+            element.attribute(&positional).is_none() && element.child(&positional).is_none()
+        });
+
+        let args = match entity_type {
+            Some(entity_type) => entity_type
+                .attributes
+                .iter()
+                .map(|attribute| {
+                    if attribute.derived {
+                        return Ok(StepValue::Inherited);
+                    }
+
+                    let kind = match attribute.kind {
+                        AttributeKind::Enumeration | AttributeKind::Logical => {
+                            TextKind::Enumeration
+                        }
+                        // simple values are written without type information, so strings can only
+                        // be told apart from numbers by the schema
+                        AttributeKind::String => TextKind::String,
+                        _ => TextKind::Other,
+                    };
+
+                    self.attribute(element, attribute.name, kind)
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => {
+                let count = (0..)
+                    .take_while(|index| {
+                        let name = positional_attribute(*index);
+                        element.attribute(&name).is_some() || element.child(&name).is_some()
+                    })
+                    .count();
+
+                (0..count)
+                    .map(|index| {
+                        self.attribute(element, &positional_attribute(index), TextKind::Other)
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+        };
+
+        self.entities.push((id, UnknownEntity::new(keyword, args)));
+
+        Ok(id)
+    }
+
+    /// Entity which is kept as raw STEP text, the references in the text are replaced by the ids
+    /// of the imported entities in the order in which they appear.
+    fn dummy(&mut self, element: &Element, raw: &str) -> Result<Dummy> {
+        let references = match self.attribute(element, "references", TextKind::Other)? {
+            StepValue::Omitted => Vec::new(),
+            StepValue::List(references) => references,
+            value => bail!("`references` must be a list, found `{value}`"),
+        };
+        let ids = references
+            .iter()
+            .map(|reference| match reference {
+                StepValue::Id(id) => Ok(*id),
+                value => bail!("expected reference, found `{value}`"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Dummy::with_references(raw, &ids)
+    }
+
+    fn attribute(&mut self, element: &Element, name: &str, kind: TextKind) -> Result<StepValue> {
+        if let Some(text) = element.attribute(name) {
+            return Ok(simple_value(text, kind));
+        }
+
+        let Some(attribute) = element.child(name) else {
+            return Ok(StepValue::Omitted);
+        };
+
+        if attribute.attribute("ref").is_some() {
+            return self.reference(attribute);
+        }
+
+        if attribute.attribute("cType").is_some() {
+            return Ok(StepValue::List(
+                attribute
+                    .children
+                    .iter()
+                    .map(|item| self.item(item))
+                    .collect::<Result<_>>()?,
+            ));
+        }
+
+        match attribute.children.as_slice() {
+            [] => Ok(simple_value(&attribute.text, kind)),
+            [item] => self.item(item),
+            _ => bail!("attribute `{name}` has multiple values but isn't a list"),
+        }
+    }
+
+    fn item(&mut self, item: &Element) -> Result<StepValue> {
+        let text = item.text.as_str();
+
+        Ok(match item.name.as_str() {
+            "unset" => StepValue::Omitted,
+            "string" => StepValue::String(encode(text)),
+            "enum" => simple_value(text, TextKind::Enumeration),
+            "integer" | "real" => {
+                number(text).with_context(|| format!("invalid number `{text}`"))?
+            }
+            "binary" => StepValue::Binary(text.to_owned()),
+            "list" if item.children.is_empty() => StepValue::List(
+                text.split_whitespace()
+                    .map(|text| number(text).with_context(|| format!("invalid number `{text}`")))
+                    .collect::<Result<_>>()?,
+            ),
+            "list" => StepValue::List(
+                item.children
+                    .iter()
+                    .map(|item| self.item(item))
+                    .collect::<Result<_>>()?,
+            ),
+            name if name.ends_with("-wrapper") => {
+                let keyword = name.trim_end_matches("-wrapper").to_uppercase();
+                let kind = match defined_type_kind(&keyword) {
+                    Some(AttributeKind::String) => TextKind::String,
+                    _ => TextKind::Other,
+                };
+
+                StepValue::Typed(keyword, Box::new(simple_value(text, kind)))
+            }
+            _ if item.attribute("ref").is_some() => self.reference(item)?,
+            _ => StepValue::Id(self.entity(item)?),
+        })
+    }
+
+    fn reference(&self, element: &Element) -> Result<StepValue> {
+        let reference = element.attribute("ref").unwrap_or_default();
+
+        self.ids
+            .get(reference)
+            .map(|id| StepValue::Id(*id))
+            .with_context(|| format!("reference to unknown id `{reference}`"))
+    }
+}
+
+/// Minimal document tree of the XML file. Namespace prefixes are dropped, since ifcXML files
+/// only use a single namespace for the entities.
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+impl Element {
+    fn parse_document(s: &str) -> Result<Self> {
+        let mut reader = Reader::from_str(s);
+        let mut stack = vec![Element::default()];
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(start) => stack.push(Self::from_start(&start)?),
+                Event::Empty(start) => {
+                    let element = Self::from_start(&start)?;
+                    stack.last_mut().expect("root").children.push(element);
+                }
+                Event::End(_) => {
+                    let element = stack.pop().expect("balanced by reader");
+                    stack
+                        .last_mut()
+                        .context("unbalanced end tag")?
+                        .children
+                        .push(element);
+                }
+                Event::Text(text) => {
+                    let text = text.unescape()?;
+                    stack.last_mut().expect("root").text.push_str(&text);
+                }
+                Event::CData(data) => {
+                    let data = std::str::from_utf8(&data)?;
+                    stack.last_mut().expect("root").text.push_str(data);
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        let document = stack.pop().filter(|_| stack.is_empty());
+        document
+            .and_then(|document| document.children.into_iter().next())
+            .context("missing root element")
+    }
+
+    fn from_start(start: &BytesStart) -> Result<Self> {
+        let name = std::str::from_utf8(start.local_name().as_ref())?.to_owned();
+
+        let attributes = start
+            .attributes()
+            .map(|attribute| {
+                let attribute = attribute?;
+
+                let key = match attribute.key.as_namespace_binding() {
+                    // only the default namespace is relevant
+                    Some(_) if attribute.key.as_ref() != b"xmlns" => return Ok(None),
+                    _ => std::str::from_utf8(attribute.key.local_name().as_ref())?.to_owned(),
+                };
+
+                Ok(Some((key, attribute.unescape_value()?.into_owned())))
+            })
+            .filter_map(Result::transpose)
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            name,
+            attributes,
+            ..Default::default()
+        })
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use winnow::Parser;
+
+    use crate::{
+        conversion::convert_schema,
+        id::Id,
+        meta::header::schema::FileSchema,
+        parser::{unknown_entity::UnknownEntity, IFCParse},
+        prelude::*,
+    };
+
+    /// entities with derived attributes written as `$`, since they're always imported as `*`
+    fn entities(ifc: &IFC) -> Vec<(Id, UnknownEntity)> {
+        ifc.data
+            .iter()
+            .map(|(id, entity)| {
+                let text = entity.to_string();
+                let mut entity = UnknownEntity::parse().parse(&text).unwrap();
+                for arg in &mut entity.args {
+                    if *arg == StepValue::Inherited {
+                        *arg = StepValue::Omitted;
+                    }
+                }
+                (*id, entity)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn round_trips_resources() {
+        for file in [
+            "resources/wall-standard-case.ifc",
+            "resources/AC20-FZK-Haus.ifc",
+        ] {
+            let mut ifc = IFC::from_file(file).unwrap();
+            ifc.renumber().unwrap();
+
+            let xml = super::to_string(&ifc).unwrap();
+            let imported = super::from_str(&xml).unwrap();
+
+            assert_eq!(imported.header, ifc.header, "{file}");
+            assert_eq!(entities(&imported), entities(&ifc), "{file}");
+        }
+    }
+
+    #[test]
+    fn writes_ifc_xml_structure() {
+        let mut ifc = IFC::from_file("resources/wall-standard-case.ifc").unwrap();
+        ifc.renumber().unwrap();
+        let xml = super::to_string(&ifc).unwrap();
+
+        assert!(xml.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert!(
+            xml.contains(r#"<ifcXML xmlns="http://www.buildingsmart-tech.org/ifcXML/IFC4/final""#)
+        );
+        assert!(xml.contains(r#"<originating_system>IfcDoc 12.0.0.0</originating_system>"#));
+        assert!(xml.contains(r#"<IfcCartesianPoint id="i8" Coordinates="0. 0. 0."/>"#));
+        assert!(xml.contains(
+            r#"<IfcSIUnit id="i11" UnitType="lengthunit" Prefix="milli" Name="metre"/>"#
+        ));
+        assert!(xml.contains(
+            r#"<IfcWall id="i31" GlobalId="0DWgwt6o1FOx7466fPk$jl">
+    <OwnerHistory>
+      <IfcOwnerHistory ref="i6" xsi:nil="true"/>
+    </OwnerHistory>"#
+        ));
+        assert!(xml.contains(
+            r#"<Items cType="list">
+      <IfcPolyline ref="i22" xsi:nil="true"/>
+    </Items>"#
+        ));
+    }
+
+    #[test]
+    fn reads_nested_entities_and_typed_values() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ifc:ifcXML xmlns:ifc="http://www.buildingsmart-tech.org/ifcXML/IFC4/final"
+  xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <ifc:IfcPropertySet id="pset" GlobalId="0AUqzAV1vAJPzU2qjh33qX" Name="Pset_WallCommon">
+    <ifc:HasProperties ifc:cType="set">
+      <ifc:IfcPropertySingleValue Name="IsExternal">
+        <ifc:NominalValue><ifc:IfcBoolean-wrapper>true</ifc:IfcBoolean-wrapper></ifc:NominalValue>
+      </ifc:IfcPropertySingleValue>
+      <ifc:IfcPropertySingleValue Name="12">
+        <ifc:NominalValue><ifc:IfcLabel-wrapper>O'Brien &amp; Co</ifc:IfcLabel-wrapper></ifc:NominalValue>
+      </ifc:IfcPropertySingleValue>
+    </ifc:HasProperties>
+  </ifc:IfcPropertySet>
+  <ifc:IfcPolyLoop id="loop">
+    <ifc:Attribute1 ifc:cType="list">
+      <ifc:IfcCartesianPoint ref="point" xsi:nil="true"/>
+      <ifc:IfcCartesianPoint Coordinates="1 0 0"/>
+    </ifc:Attribute1>
+  </ifc:IfcPolyLoop>
+  <ifc:IfcCartesianPoint id="point" Coordinates="0. 0. 0."/>
+</ifc:ifcXML>"#;
+
+        let ifc = super::from_str(xml).unwrap();
+        let lines = ifc
+            .data
+            .iter()
+            .map(|(id, entity)| format!("{id}= {entity}"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            [
+                "#1= IFCPROPERTYSINGLEVALUE('IsExternal',$,IFCBOOLEAN(.TRUE.),$);",
+                "#2= IFCPROPERTYSINGLEVALUE('12',$,IFCLABEL('O''Brien & Co'),$);",
+                "#3= IFCPROPERTYSET('0AUqzAV1vAJPzU2qjh33qX',$,'Pset_WallCommon',$,(#1,#2));",
+                "#4= IFCCARTESIANPOINT((0.,0.,0.));",
+                "#5= IFCCARTESIANPOINT((1.,0.,0.));",
+                "#6= IFCPOLYLOOP((#4,#5));",
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_dummies_and_typed_strings() {
+        let file = r#"ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('ViewDefinition [CoordinationView]'),'2;1');
+FILE_NAME('','2019-03-24T14:01:39',(''),(''),'','','');
+FILE_SCHEMA(('IFC4'));
+ENDSEC;
+DATA;
+#1= IFCCARTESIANPOINT((0.,0.,0.));
+#2 IFCDIRECTION((1.,0.,0.));
+#3= IFCNAME('it''s;here' #1);
+#4= IFCPROPERTYSINGLEVALUE('12',$,IFCTEXT('12'),$);
+ENDSEC;
+END-ISO-10303-21;
+"#;
+        let (ifc, _) = IFC::from_str_lenient(file).unwrap();
+
+        let mut buffer = Vec::new();
+        let dummies = super::to_writer_with_dummies(&ifc, &mut buffer).unwrap();
+        assert_eq!(dummies, [Id(2), Id(3)]);
+
+        let xml = String::from_utf8(buffer).unwrap();
+        assert!(xml.contains(
+            r#"<IFCNAME id="i3" raw="IFCNAME(&apos;it&apos;&apos;s;here&apos; #1)">
+    <references cType="list">
+      <IfcCartesianPoint ref="i1" xsi:nil="true"/>
+    </references>
+  </IFCNAME>"#
+        ));
+
+        let imported = super::from_str(&xml).unwrap();
+        let lines = imported
+            .data
+            .iter()
+            .map(|(id, entity)| format!("{id}= {entity}"))
+            .collect::<Vec<_>>();
+
+        // strings are told apart from numbers by the kind of the attribute and defined type
+        assert_eq!(
+            lines,
+            [
+                "#1= IFCCARTESIANPOINT((0.,0.,0.));",
+                "#2= IFCDIRECTION((1.,0.,0.));",
+                "#3= IFCNAME('it''s;here' #1);",
+                "#4= IFCPROPERTYSINGLEVALUE('12',$,IFCTEXT('12'),$);",
+            ]
+        );
+    }
+
+    #[test]
+    fn other_schemas_fail() {
+        let mut ifc = IFC::from_file("resources/wall-standard-case.ifc").unwrap();
+        convert_schema(&mut ifc, FileSchema::IFC2X3);
+
+        let Err(err) = super::to_string(&ifc) else {
+            panic!("IFC2X3 model was written as IFC4 ifcXML");
+        };
+        assert_eq!(
+            err.to_string(),
+            "ifcXML is only supported for IFC4 models, not IFC2X3"
+        );
+
+        let xml = r#"<iso_10303_28 xmlns:ifc="http://www.iai-tech.org/ifcXML/IFC2x3/FINAL">
+  <ifc:uos id="uos_1" xmlns="http://www.iai-tech.org/ifcXML/IFC2x3/FINAL"/>
+</iso_10303_28>"#;
+        let Err(err) = super::from_str(xml) else {
+            panic!("IFC2X3 document was read");
+        };
+        assert_eq!(
+            err.to_string(),
+            "expected an IFC4 ifcXML document, found root element `iso_10303_28`"
+        );
+
+        let xml = r#"<ifcXML xmlns="https://standards.buildingsmart.org/IFC/RELEASE/IFC4_3"/>"#;
+        let Err(err) = super::from_str(xml) else {
+            panic!("IFC4X3 document was read");
+        };
+        assert_eq!(
+            err.to_string(),
+            "ifcXML is only supported for IFC4 documents, not IFC4X3_ADD2"
+        );
+    }
+
+    #[test]
+    fn unknown_references_fail() {
+        let xml = r#"<ifcXML>
+  <IfcPolyLoop id="i1">
+    <Attribute1 cType="list"><IfcCartesianPoint ref="i2" xsi:nil="true"/></Attribute1>
+  </IfcPolyLoop>
+</ifcXML>"#;
+
+        let Err(err) = super::from_str(xml) else {
+            panic!("dangling reference was imported");
+        };
+        assert_eq!(err.to_string(), "reference to unknown id `i2`");
+    }
+}
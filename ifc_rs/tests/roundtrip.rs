@@ -0,0 +1,24 @@
+use std::fs;
+
+use ifc_rs::roundtrip;
+
+#[test]
+fn resources_round_trip() -> anyhow::Result<()> {
+    let mut paths = fs::read_dir("resources")?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    paths.retain(|path| path.extension().is_some_and(|extension| extension == "ifc"));
+    paths.sort();
+
+    for path in paths {
+        let report = roundtrip::check(&fs::read_to_string(&path)?);
+
+        assert!(report.errors.is_empty(), "{}: {report}", path.display());
+        assert!(report.unstable.is_empty(), "{}: {report}", path.display());
+
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        insta::assert_debug_snapshot!(name, report.summary());
+    }
+
+    Ok(())
+}
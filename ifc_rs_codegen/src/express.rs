@@ -0,0 +1,594 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+
+/// The parts of an EXPRESS schema which are needed to generate the entity types.
+///
+/// Functions, rules, inverse attributes and WHERE rules are skipped.
+#[derive(Debug, Default)]
+pub struct Schema {
+    /// e.g. `IFC4`
+    pub name: String,
+    pub types: BTreeMap<String, TypeDeclaration>,
+    pub entities: BTreeMap<String, Entity>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeDeclaration {
+    /// e.g. `TYPE IfcLabel = STRING;`
+    Defined(TypeExpression),
+    /// e.g. `TYPE IfcWallTypeEnum = ENUMERATION OF (MOVABLE, ...);`
+    Enumeration(Vec<String>),
+    /// e.g. `TYPE IfcAxis2Placement = SELECT (IfcAxis2Placement2D, IfcAxis2Placement3D);`
+    Select(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeExpression {
+    Real,
+    Integer,
+    String,
+    Binary,
+    Boolean,
+    Logical,
+    /// a type or entity declared in the schema
+    Named(String),
+    /// `LIST`, `SET`, `ARRAY` and `BAG`, the bounds aren't kept
+    Aggregate(Box<TypeExpression>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entity {
+    pub name: String,
+    pub is_abstract: bool,
+    pub supertype: Option<String>,
+    /// explicit attributes declared by this entity, without the ones of the supertypes
+    pub attributes: Vec<Attribute>,
+    /// attributes of supertypes which are derived in this entity and written as `*`
+    pub derived: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute {
+    pub name: String,
+    pub optional: bool,
+    pub ty: TypeExpression,
+}
+
+impl Schema {
+    /// parses the source of an `.exp` file
+    pub fn parse(source: &str) -> Result<Self> {
+        Parser {
+            tokens: tokenize(source)?,
+            position: 0,
+        }
+        .schema()
+    }
+
+    /// entities which directly inherit from the given one
+    pub fn subtypes<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Entity> {
+        self.entities
+            .values()
+            .filter(move |entity| entity.supertype.as_deref() == Some(name))
+    }
+
+    /// the entity itself and all of its subtypes which can be instantiated
+    pub fn concrete_subtypes(&self, name: &str) -> Vec<&Entity> {
+        let mut concrete = Vec::new();
+        let mut pending = vec![name];
+
+        while let Some(name) = pending.pop() {
+            if let Some(entity) = self.entities.get(name) {
+                if !entity.is_abstract {
+                    concrete.push(entity);
+                }
+                pending.extend(self.subtypes(name).map(|subtype| subtype.name.as_str()));
+            }
+        }
+
+        concrete.sort_by(|a, b| a.name.cmp(&b.name));
+        concrete
+    }
+
+    /// whether any subtype of the entity writes the attribute as derived `*`
+    pub fn is_derived_in_subtype(&self, entity: &str, attribute: &str) -> bool {
+        self.subtypes(entity).any(|subtype| {
+            subtype.derived.iter().any(|derived| derived == attribute)
+                || self.is_derived_in_subtype(&subtype.name, attribute)
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Symbol(&'static str),
+    Literal(String),
+}
+
+const SYMBOLS: &[&str] = &[
+    ":=", "<>", "<=", ">=", "<*", "||", "**", ":<>:", ":=:", "(", ")", "[", "]", "{", "}", ",",
+    ";", ":", "=", "<", ">", "*", "+", "-", "/", "\\", ".", "?", "|",
+];
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if let Some(comment) = rest.strip_prefix("(*") {
+            let end = comment.find("*)").context("unterminated comment")?;
+            rest = &comment[end + 2..];
+        } else if let Some(comment) = rest.strip_prefix("--") {
+            rest = comment.find('\n').map_or("", |end| &comment[end..]);
+        } else if c == '\'' {
+            // quotes inside of strings are doubled
+            let mut end = 1;
+            loop {
+                let next = rest[end..].find('\'').context("unterminated string")? + end;
+                if rest[next + 1..].starts_with('\'') {
+                    end = next + 2;
+                } else {
+                    end = next + 1;
+                    break;
+                }
+            }
+            tokens.push(Token::Literal(rest[..end].to_owned()));
+            rest = &rest[end..];
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Ident(rest[..end].to_owned()));
+            rest = &rest[end..];
+        } else if c.is_ascii_digit() {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.'))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Literal(rest[..end].to_owned()));
+            rest = &rest[end..];
+        } else if let Some(symbol) = SYMBOLS
+            .iter()
+            .filter(|symbol| rest.starts_with(**symbol))
+            .max_by_key(|symbol| symbol.len())
+        {
+            tokens.push(Token::Symbol(symbol));
+            rest = &rest[symbol.len()..];
+        } else {
+            // e.g. `%` in binary literals, which only appear in functions
+            tokens.push(Token::Literal(c.to_string()));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<Token> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .context("unexpected end of schema")?;
+        self.position += 1;
+
+        Ok(token)
+    }
+
+    /// whether the next token is the given keyword, EXPRESS keywords are case insensitive
+    fn at_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(ident)) if ident.eq_ignore_ascii_case(keyword))
+    }
+
+    fn at_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Token::Symbol(s)) if *s == symbol)
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let at_keyword = self.at_keyword(keyword);
+        if at_keyword {
+            self.position += 1;
+        }
+
+        at_keyword
+    }
+
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let at_symbol = self.at_symbol(symbol);
+        if at_symbol {
+            self.position += 1;
+        }
+
+        at_symbol
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        if !self.eat_keyword(keyword) {
+            bail!("expected {keyword}, found {:?}", self.peek());
+        }
+
+        Ok(())
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<()> {
+        if !self.eat_symbol(symbol) {
+            bail!("expected `{symbol}`, found {:?}", self.peek());
+        }
+
+        Ok(())
+    }
+
+    fn ident(&mut self) -> Result<String> {
+        match self.next()? {
+            Token::Ident(ident) => Ok(ident),
+            token => bail!("expected identifier, found {token:?}"),
+        }
+    }
+
+    /// comma separated identifiers in brackets, e.g. `(A, B, C)`
+    fn ident_list(&mut self) -> Result<Vec<String>> {
+        self.expect_symbol("(")?;
+
+        let mut idents = vec![self.ident()?];
+        while self.eat_symbol(",") {
+            idents.push(self.ident()?);
+        }
+
+        self.expect_symbol(")")?;
+        Ok(idents)
+    }
+
+    /// skips a bracketed group including nested brackets, if there is one
+    fn skip_group(&mut self, open: &str, close: &str) -> Result<()> {
+        if !self.at_symbol(open) {
+            return Ok(());
+        }
+
+        let mut depth = 0;
+        loop {
+            match self.next()? {
+                Token::Symbol(symbol) if symbol == open => depth += 1,
+                Token::Symbol(symbol) if symbol == close => depth -= 1,
+                _ => {}
+            }
+
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// skips everything up to and including the next `;` outside of brackets
+    fn skip_statement(&mut self) -> Result<()> {
+        let mut depth = 0;
+        loop {
+            match self.next()? {
+                Token::Symbol("(" | "[") => depth += 1,
+                Token::Symbol(")" | "]") => depth -= 1,
+                Token::Symbol(";") if depth == 0 => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    /// skips everything up to and including `end;`
+    fn skip_block(&mut self, end: &str) -> Result<()> {
+        while !self.eat_keyword(end) {
+            self.next().with_context(|| format!("missing {end}"))?;
+        }
+
+        self.expect_symbol(";")
+    }
+
+    fn schema(mut self) -> Result<Schema> {
+        let mut schema = Schema::default();
+
+        self.expect_keyword("SCHEMA")?;
+        schema.name = self.ident()?;
+        self.skip_statement()?;
+
+        loop {
+            let keyword = self.ident()?;
+
+            match keyword.to_uppercase().as_str() {
+                "TYPE" => {
+                    let name = self.ident()?;
+                    let declaration = self
+                        .type_declaration()
+                        .with_context(|| format!("invalid type {name}"))?;
+                    schema.types.insert(name, declaration);
+                }
+                "ENTITY" => {
+                    let name = self.ident()?;
+                    let entity = self
+                        .entity(name.clone())
+                        .with_context(|| format!("invalid entity {name}"))?;
+                    schema.entities.insert(name, entity);
+                }
+                "FUNCTION" => self.skip_block("END_FUNCTION")?,
+                "RULE" => self.skip_block("END_RULE")?,
+                "PROCEDURE" => self.skip_block("END_PROCEDURE")?,
+                "CONSTANT" => self.skip_block("END_CONSTANT")?,
+                "SUBTYPE_CONSTRAINT" => self.skip_block("END_SUBTYPE_CONSTRAINT")?,
+                "USE" | "REFERENCE" => self.skip_statement()?,
+                "END_SCHEMA" => return Ok(schema),
+                _ => bail!("unexpected {keyword} in schema {}", schema.name),
+            }
+        }
+    }
+
+    fn type_declaration(&mut self) -> Result<TypeDeclaration> {
+        self.expect_symbol("=")?;
+
+        // IFC4X3 marks some selects as extensible
+        self.eat_keyword("EXTENSIBLE");
+        self.eat_keyword("GENERIC_ENTITY");
+
+        let declaration = if self.eat_keyword("ENUMERATION") {
+            self.expect_keyword("OF")?;
+            TypeDeclaration::Enumeration(self.ident_list()?)
+        } else if self.eat_keyword("SELECT") {
+            TypeDeclaration::Select(self.ident_list()?)
+        } else {
+            TypeDeclaration::Defined(self.type_expression()?)
+        };
+
+        self.expect_symbol(";")?;
+        self.skip_block("END_TYPE")?;
+
+        Ok(declaration)
+    }
+
+    fn type_expression(&mut self) -> Result<TypeExpression> {
+        let ident = self.ident()?;
+
+        let expression = match ident.to_uppercase().as_str() {
+            "LIST" | "SET" | "ARRAY" | "BAG" => {
+                self.skip_group("[", "]")?;
+                self.expect_keyword("OF")?;
+                self.eat_keyword("OPTIONAL");
+                self.eat_keyword("UNIQUE");
+
+                TypeExpression::Aggregate(Box::new(self.type_expression()?))
+            }
+            "REAL" | "NUMBER" => TypeExpression::Real,
+            "INTEGER" => TypeExpression::Integer,
+            "STRING" => TypeExpression::String,
+            "BINARY" => TypeExpression::Binary,
+            "BOOLEAN" => TypeExpression::Boolean,
+            "LOGICAL" => TypeExpression::Logical,
+            _ => TypeExpression::Named(ident),
+        };
+
+        // width and precision, e.g. `STRING(22) FIXED`
+        self.skip_group("(", ")")?;
+        self.eat_keyword("FIXED");
+
+        Ok(expression)
+    }
+
+    fn entity(&mut self, name: String) -> Result<Entity> {
+        let mut entity = Entity {
+            name,
+            is_abstract: false,
+            supertype: None,
+            attributes: Vec::new(),
+            derived: Vec::new(),
+        };
+
+        while !self.eat_symbol(";") {
+            if self.eat_keyword("ABSTRACT") {
+                entity.is_abstract = true;
+            } else if self.eat_keyword("SUPERTYPE") {
+                if self.eat_keyword("OF") {
+                    self.skip_group("(", ")")?;
+                }
+            } else if self.eat_keyword("SUBTYPE") {
+                self.expect_keyword("OF")?;
+                let supertypes = self.ident_list()?;
+                if supertypes.len() > 1 {
+                    bail!("multiple inheritance isn't supported");
+                }
+                entity.supertype = supertypes.into_iter().next();
+            } else {
+                bail!("unexpected {:?} in entity header", self.peek());
+            }
+        }
+
+        // explicit attributes
+        while !self.at_section() {
+            if self.eat_keyword("SELF") {
+                // redeclared attribute of a supertype with a more specific type
+                self.skip_statement()?;
+                continue;
+            }
+
+            let mut names = vec![self.ident()?];
+            while self.eat_symbol(",") {
+                names.push(self.ident()?);
+            }
+            self.expect_symbol(":")?;
+
+            let optional = self.eat_keyword("OPTIONAL");
+            let ty = self.type_expression()?;
+            self.expect_symbol(";")?;
+
+            entity
+                .attributes
+                .extend(names.into_iter().map(|name| Attribute {
+                    name,
+                    optional,
+                    ty: ty.clone(),
+                }));
+        }
+
+        loop {
+            if self.eat_keyword("END_ENTITY") {
+                self.expect_symbol(";")?;
+                return Ok(entity);
+            }
+
+            if self.eat_keyword("DERIVE") {
+                while !self.at_section() {
+                    // e.g. `SELF\IfcNamedUnit.Dimensions : IfcDimensionalExponents := ...;`
+                    if self.eat_keyword("SELF") {
+                        self.expect_symbol("\\")?;
+                        self.ident()?;
+                        self.expect_symbol(".")?;
+                        entity.derived.push(self.ident()?);
+                    }
+
+                    self.skip_statement()?;
+                }
+            } else {
+                // INVERSE, UNIQUE and WHERE
+                self.next()?;
+                while !self.at_section() {
+                    self.skip_statement()?;
+                }
+            }
+        }
+    }
+
+    /// whether the next token starts a section of the entity body or ends the entity
+    fn at_section(&self) -> bool {
+        ["DERIVE", "INVERSE", "UNIQUE", "WHERE", "END_ENTITY"]
+            .iter()
+            .any(|keyword| self.at_keyword(keyword))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Attribute, Schema, TypeDeclaration, TypeExpression};
+
+    const SCHEMA: &str = r#"
+        SCHEMA IFC4;
+
+        TYPE IfcLabel = STRING(255);
+        END_TYPE;
+
+        TYPE IfcPositiveLengthMeasure = IfcLengthMeasure;
+         WHERE
+          WR1 : SELF > 0.;
+        END_TYPE;
+
+        (* comments are (skipped) *)
+        TYPE IfcLineIndex = LIST [2:?] OF IfcPositiveInteger; -- up to the end of the line
+        END_TYPE;
+
+        TYPE IfcWallTypeEnum = ENUMERATION OF
+            (MOVABLE,
+            NOTDEFINED);
+        END_TYPE;
+
+        TYPE IfcUnit = SELECT
+            (IfcDerivedUnit,
+            IfcNamedUnit);
+        END_TYPE;
+
+        ENTITY IfcNamedUnit
+         ABSTRACT SUPERTYPE OF (ONEOF
+            (IfcContextDependentUnit
+            ,IfcSIUnit));
+            Dimensions : IfcDimensionalExponents;
+            UnitType : IfcUnitEnum;
+         WHERE
+            WR1 : IfcCorrectDimensions (SELF.UnitType, SELF.Dimensions);
+        END_ENTITY;
+
+        ENTITY IfcSIUnit
+         SUBTYPE OF (IfcNamedUnit);
+            Prefix : OPTIONAL IfcSIPrefix;
+            Name : IfcSIUnitName;
+         DERIVE
+            SELF\IfcNamedUnit.Dimensions : IfcDimensionalExponents := IfcDimensionsForSiUnit (SELF.Name);
+        END_ENTITY;
+
+        ENTITY IfcPolyline
+         SUBTYPE OF (IfcBoundedCurve);
+            Points : LIST [2:?] OF IfcCartesianPoint;
+         WHERE
+            SameDim : SIZEOF(QUERY(Temp <* Points | Temp.Dim <> Points[1].Dim)) = 0;
+        END_ENTITY;
+
+        FUNCTION IfcDimensionsForSiUnit (n : IfcSIUnitName ) : IfcDimensionalExponents;
+            CASE n OF
+                METRE : RETURN (IfcDimensionalExponents (1, 0, 0, 0, 0, 0, 0));
+            END_CASE;
+        END_FUNCTION;
+
+        END_SCHEMA;
+    "#;
+
+    #[test]
+    fn parses_types_and_entities() {
+        let schema = Schema::parse(SCHEMA).unwrap();
+
+        assert_eq!(schema.name, "IFC4");
+        assert_eq!(
+            schema.types["IfcLabel"],
+            TypeDeclaration::Defined(TypeExpression::String)
+        );
+        assert_eq!(
+            schema.types["IfcLineIndex"],
+            TypeDeclaration::Defined(TypeExpression::Aggregate(Box::new(TypeExpression::Named(
+                "IfcPositiveInteger".into()
+            ))))
+        );
+        assert_eq!(
+            schema.types["IfcWallTypeEnum"],
+            TypeDeclaration::Enumeration(vec!["MOVABLE".into(), "NOTDEFINED".into()])
+        );
+        assert_eq!(
+            schema.types["IfcUnit"],
+            TypeDeclaration::Select(vec!["IfcDerivedUnit".into(), "IfcNamedUnit".into()])
+        );
+
+        let named_unit = &schema.entities["IfcNamedUnit"];
+        assert!(named_unit.is_abstract);
+        assert_eq!(named_unit.attributes.len(), 2);
+
+        let si_unit = &schema.entities["IfcSIUnit"];
+        assert_eq!(si_unit.supertype.as_deref(), Some("IfcNamedUnit"));
+        assert_eq!(
+            si_unit.attributes[0],
+            Attribute {
+                name: "Prefix".into(),
+                optional: true,
+                ty: TypeExpression::Named("IfcSIPrefix".into())
+            }
+        );
+        assert_eq!(si_unit.derived, ["Dimensions"]);
+        assert!(schema.is_derived_in_subtype("IfcNamedUnit", "Dimensions"));
+        assert!(!schema.is_derived_in_subtype("IfcNamedUnit", "UnitType"));
+
+        assert_eq!(
+            schema.entities["IfcPolyline"].attributes[0].ty,
+            TypeExpression::Aggregate(Box::new(TypeExpression::Named("IfcCartesianPoint".into())))
+        );
+    }
+
+    #[test]
+    fn reports_invalid_schemas() {
+        let err = Schema::parse("SCHEMA IFC4; ENTITY IfcWall SUBTYPE OF IfcElement; END_ENTITY;")
+            .unwrap_err();
+
+        assert_eq!(
+            format!("{err:#}"),
+            "invalid entity IfcWall: expected `(`, found Some(Ident(\"IfcElement\"))"
+        );
+    }
+}
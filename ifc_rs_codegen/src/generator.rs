@@ -0,0 +1,574 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use anyhow::{bail, Result};
+
+use crate::express::{Entity, Schema, TypeDeclaration, TypeExpression};
+
+/// Rust representation of an attribute.
+#[derive(Debug, Clone, PartialEq)]
+enum FieldType {
+    /// a value without references, e.g. `RealPrimitive`
+    Value(String),
+    /// reference to an entity which has subtypes or is part of a select, checked against the
+    /// entities which can be instantiated
+    Id(Vec<String>),
+    /// reference to an entity without subtypes
+    TypedId(String),
+    /// selects of defined types and nested aggregates with references, which are kept as they
+    /// are written
+    StepValue(Vec<String>),
+    List(Box<FieldType>),
+}
+
+impl FieldType {
+    fn rust_type(&self) -> String {
+        match self {
+            FieldType::Value(value) => value.clone(),
+            FieldType::Id(_) => "Id".to_owned(),
+            FieldType::TypedId(entity) => format!("TypedId<{entity}>"),
+            FieldType::StepValue(_) => "StepValue".to_owned(),
+            FieldType::List(item) => format!("IfcList<{}>", item.rust_type()),
+        }
+    }
+
+    /// entities which are allowed to be referenced, used for `#[ifc_types(...)]`
+    fn ifc_types(&self) -> &[String] {
+        match self {
+            FieldType::Id(types) | FieldType::StepValue(types) => types,
+            FieldType::List(item) => item.ifc_types(),
+            FieldType::Value(_) | FieldType::TypedId(_) => &[],
+        }
+    }
+
+    fn has_references(&self) -> bool {
+        match self {
+            FieldType::Value(_) => false,
+            FieldType::List(item) => item.has_references(),
+            _ => true,
+        }
+    }
+}
+
+/// Generates the modules for all entities and enumerations of the schema, keyed by their file
+/// name. The modules are meant to be placed in a directory of `ifc_rs`, as they use the parser
+/// types and derives of the crate.
+///
+/// SELECT types don't get types of their own, as the `IfcVerify` derive only follows references
+/// in `Id`, `TypedId` and `StepValue` fields. Attributes of selects of entities are `Id`s
+/// which are checked against all entities of the select, attributes of selects which include
+/// defined types are kept as `StepValue`, e.g. `IFCLABEL('Wall')`.
+pub fn generate(schema: &Schema) -> Result<BTreeMap<String, String>> {
+    let generator = Generator { schema };
+    let mut files = BTreeMap::new();
+
+    for (name, declaration) in &schema.types {
+        if let TypeDeclaration::Enumeration(values) = declaration {
+            files.insert(file_name(name), generator.enumeration(name, values));
+        }
+    }
+
+    for entity in schema.entities.values() {
+        files.insert(file_name(&entity.name), generator.entity(entity)?);
+    }
+
+    files.insert("mod.rs".to_owned(), generator.module());
+
+    Ok(files)
+}
+
+struct Generator<'a> {
+    schema: &'a Schema,
+}
+
+impl Generator<'_> {
+    fn module(&self) -> String {
+        let mut module = format!(
+            "//! Entities of the {} schema, generated from its EXPRESS definition by \
+             `ifc_rs_codegen`.\n\
+             //!\n\
+             //! Don't edit these modules, run the generator again instead.\n\
+             \n\
+             #![allow(unused_imports)]\n\
+             \n",
+            self.schema.name
+        );
+
+        let enumerations = self
+            .schema
+            .types
+            .iter()
+            .filter(|(_, declaration)| matches!(declaration, TypeDeclaration::Enumeration(_)))
+            .map(|(name, _)| name);
+        let mut names: Vec<_> = enumerations.chain(self.schema.entities.keys()).collect();
+        names.sort_by_key(|name| module_name(name));
+
+        for name in &names {
+            writeln!(module, "pub(crate) mod {};", module_name(name)).unwrap();
+        }
+        module.push('\n');
+
+        module.push_str(
+            "use std::ops::{Deref, DerefMut};\n\
+             \n\
+             use ifc_rs_verify_derive::{IfcEntity, IfcVerify};\n\
+             \n\
+             use crate::{\n    \
+                 id::{Id, IfcGloballyUniqueId, TypedId},\n    \
+                 parser::{\n        \
+                     bool::BoolPrimitive, integer::IntegerPrimitive, list::IfcList, optional::OptionalParameter,\n        \
+                     real::RealPrimitive, string::StringPrimitive, EntityRegistry,\n    \
+                 },\n    \
+                 prelude::{Dummy, IfcType, IfcVerify, StepValue, UnknownEntity},\n    \
+                 IFC,\n\
+             };\n\
+             \n",
+        );
+
+        for name in &names {
+            writeln!(
+                module,
+                "pub use {}::{};",
+                module_name(name),
+                type_name(name)
+            )
+            .unwrap();
+        }
+
+        module.push_str(
+            "\n/// registers the parsers of all entities which can be instantiated\n\
+             pub fn register(registry: &mut EntityRegistry) {\n",
+        );
+        for entity in self.schema.entities.values().filter(|e| !e.is_abstract) {
+            writeln!(
+                module,
+                "    registry.register::<{}>(\"{}\");",
+                type_name(&entity.name),
+                entity.name.to_uppercase()
+            )
+            .unwrap();
+        }
+        module.push_str("}\n");
+
+        module
+    }
+
+    fn enumeration(&self, name: &str, values: &[String]) -> String {
+        let rust_name = type_name(name);
+
+        let mut enumeration = format!(
+            "use std::str::FromStr;\n\
+             \n\
+             use strum::{{Display, EnumString, VariantNames}};\n\
+             use winnow::combinator::{{alt, delimited}};\n\
+             use winnow::Parser;\n\
+             \n\
+             use crate::parser::*;\n\
+             \n\
+             {}\
+             #[derive(EnumString, VariantNames, Display, Clone, Copy)]\n\
+             pub enum {rust_name} {{\n",
+            self.documentation(name)
+        );
+
+        for (index, value) in values.iter().enumerate() {
+            if index > 0 {
+                enumeration.push('\n');
+            }
+            writeln!(
+                enumeration,
+                "    #[strum(to_string = \".{value}.\")]\n    {},",
+                variant_name(value)
+            )
+            .unwrap();
+        }
+
+        write!(
+            enumeration,
+            "}}\n\
+             \n\
+             impl IFCParse for {rust_name} {{\n    \
+                 fn parse<'a>() -> impl IFCParser<'a, Self> {{\n        \
+                     let variants: [&str; Self::VARIANTS.len()] =\n            \
+                         Self::VARIANTS.try_into().expect(\"statically known\");\n\
+                     \n        \
+                     delimited(\n            \
+                         p_space_or_comment(),\n            \
+                         alt(variants\n                \
+                             .map(|v| (v, Self::from_str(v).expect(\"valid {rust_name}\")))\n                \
+                             .map(|(k, v)| k.map(move |_| v))),\n            \
+                         p_space_or_comment(),\n        \
+                     )\n    \
+                 }}\n\
+             }}\n\
+             \n\
+             crate::traits::reflect::step_enumeration!({rust_name});\n"
+        )
+        .unwrap();
+
+        enumeration
+    }
+
+    fn entity(&self, entity: &Entity) -> Result<String> {
+        let rust_name = type_name(&entity.name);
+
+        let mut code = format!("use super::*;\n\n{}", self.documentation(&entity.name));
+        code.push_str("#[derive(IfcVerify, IfcEntity)]\n");
+        let schema = (!entity.is_abstract)
+            .then(|| format!("name = \"{}\"", entity.name))
+            .into_iter()
+            .chain(
+                entity
+                    .derived
+                    .iter()
+                    .map(|derived| format!("derived = \"{derived}\"")),
+            )
+            .collect::<Vec<_>>();
+        if !schema.is_empty() {
+            writeln!(code, "#[ifc({})]", schema.join(", ")).unwrap();
+        }
+        let mut fields = Vec::new();
+        for attribute in &entity.attributes {
+            let field_type = self.field_type(&attribute.ty)?;
+            let ifc_types = field_type.ifc_types().join(", ");
+
+            let mut rust_type = field_type.rust_type();
+            if attribute.optional
+                || self
+                    .schema
+                    .is_derived_in_subtype(&entity.name, &attribute.name)
+            {
+                rust_type = format!("OptionalParameter<{rust_type}>");
+            }
+
+            let mut field = String::new();
+            if !ifc_types.is_empty() {
+                writeln!(field, "    #[ifc_types({ifc_types})]").unwrap();
+            }
+            // the derive takes the upper camel case of the field as name of the attribute
+            if upper_camel_case(&snake_case(&attribute.name)) != attribute.name {
+                writeln!(field, "    #[ifc(name = \"{}\")]", attribute.name).unwrap();
+            }
+            writeln!(
+                field,
+                "    pub {}: {rust_type},",
+                field_name(&attribute.name)
+            )
+            .unwrap();
+            fields.push(field);
+        }
+
+        // supertypes without any attributes don't add anything to parse or write
+        let supertype = entity
+            .supertype
+            .as_deref()
+            .filter(|supertype| self.attribute_count(supertype) > 0);
+        let supertype = supertype.map(|supertype| {
+            let supertype_name = type_name(supertype);
+            let mut field = field_name(&supertype_name);
+
+            // the field of the supertype mustn't shadow an attribute
+            if entity
+                .attributes
+                .iter()
+                .any(|attribute| field_name(&attribute.name) == field)
+            {
+                field = "supertype".to_owned();
+            }
+
+            fields.insert(
+                0,
+                format!("    #[inherited]\n    {field}: {supertype_name},\n"),
+            );
+
+            (supertype_name, field)
+        });
+
+        if fields.is_empty() {
+            writeln!(code, "pub struct {rust_name} {{}}").unwrap();
+        } else {
+            writeln!(code, "pub struct {rust_name} {{\n{}}}", fields.join("\n")).unwrap();
+        }
+
+        if let Some((supertype_name, field)) = supertype {
+            write!(
+                code,
+                "\n\
+                 impl Deref for {rust_name} {{\n    \
+                     type Target = {supertype_name};\n\
+                     \n    \
+                     fn deref(&self) -> &Self::Target {{\n        \
+                         &self.{field}\n    \
+                     }}\n\
+                 }}\n\
+                 \n\
+                 impl DerefMut for {rust_name} {{\n    \
+                     fn deref_mut(&mut self) -> &mut Self::Target {{\n        \
+                         &mut self.{field}\n    \
+                     }}\n\
+                 }}\n"
+            )
+            .unwrap();
+        }
+
+        if !entity.is_abstract {
+            write!(code, "\nimpl IfcType for {rust_name} {{}}\n").unwrap();
+        }
+
+        Ok(code)
+    }
+
+    fn field_type(&self, ty: &TypeExpression) -> Result<FieldType> {
+        let field_type = match ty {
+            TypeExpression::Real => FieldType::Value("RealPrimitive".to_owned()),
+            TypeExpression::Integer => FieldType::Value("IntegerPrimitive".to_owned()),
+            TypeExpression::String => FieldType::Value("StringPrimitive".to_owned()),
+            TypeExpression::Boolean | TypeExpression::Logical => {
+                FieldType::Value("BoolPrimitive".to_owned())
+            }
+            // binary literals aren't modelled by the crate
+            TypeExpression::Binary => FieldType::StepValue(Vec::new()),
+            TypeExpression::Aggregate(item) => match self.field_type(item)? {
+                // the derive only visits ids in lists of direct references
+                item @ (FieldType::List(_) | FieldType::StepValue(_)) if item.has_references() => {
+                    FieldType::StepValue(item.ifc_types().to_vec())
+                }
+                item => FieldType::List(Box::new(item)),
+            },
+            TypeExpression::Named(name) if name == "IfcGloballyUniqueId" => {
+                FieldType::Value("IfcGloballyUniqueId".to_owned())
+            }
+            TypeExpression::Named(name) => {
+                if self.schema.entities.contains_key(name) {
+                    self.reference(name)
+                } else {
+                    match self.schema.types.get(name) {
+                        Some(TypeDeclaration::Defined(ty)) => self.field_type(ty)?,
+                        Some(TypeDeclaration::Enumeration(_)) => FieldType::Value(type_name(name)),
+                        Some(TypeDeclaration::Select(_)) => self.select(name)?,
+                        None => bail!("unknown type {name}"),
+                    }
+                }
+            }
+        };
+
+        Ok(field_type)
+    }
+
+    fn reference(&self, entity: &str) -> FieldType {
+        let concrete = self.concrete_names(entity);
+
+        match concrete.as_slice() {
+            [single] if self.schema.subtypes(entity).next().is_none() => {
+                FieldType::TypedId(single.clone())
+            }
+            _ => FieldType::Id(concrete),
+        }
+    }
+
+    /// Selects of entities are references to any of them. Selects which include defined types
+    /// are written as typed values, e.g. `IFCLABEL('Wall')`, and are kept as [`StepValue`].
+    fn select(&self, name: &str) -> Result<FieldType> {
+        let mut entities = Vec::new();
+        let mut only_entities = true;
+
+        let mut pending = vec![name];
+        while let Some(name) = pending.pop() {
+            match self.schema.types.get(name) {
+                Some(TypeDeclaration::Select(members)) => {
+                    pending.extend(members.iter().map(String::as_str))
+                }
+                Some(_) => only_entities = false,
+                None if self.schema.entities.contains_key(name) => {
+                    entities.extend(self.concrete_names(name))
+                }
+                None => bail!("unknown type {name} in select"),
+            }
+        }
+
+        entities.sort();
+        entities.dedup();
+
+        Ok(if only_entities {
+            FieldType::Id(entities)
+        } else {
+            FieldType::StepValue(entities)
+        })
+    }
+
+    /// number of explicit attributes of the entity including the ones of its supertypes
+    fn attribute_count(&self, entity: &str) -> usize {
+        self.schema.entities.get(entity).map_or(0, |entity| {
+            entity.attributes.len()
+                + entity
+                    .supertype
+                    .as_deref()
+                    .map_or(0, |supertype| self.attribute_count(supertype))
+        })
+    }
+
+    fn concrete_names(&self, entity: &str) -> Vec<String> {
+        self.schema
+            .concrete_subtypes(entity)
+            .into_iter()
+            .map(|entity| type_name(&entity.name))
+            .collect()
+    }
+
+    fn documentation(&self, name: &str) -> String {
+        let schema = self.schema.name.to_uppercase();
+
+        let link = if schema.starts_with("IFC4X3") {
+            Some(format!(
+                "https://ifc43-docs.standards.buildingsmart.org/IFC/RELEASE/IFC4x3/HTML/lexical/{name}.htm"
+            ))
+        } else if schema.starts_with("IFC4") {
+            Some(format!(
+                "https://standards.buildingsmart.org/IFC/RELEASE/IFC4/ADD2_TC1/HTML/link/{}.htm",
+                name.to_lowercase()
+            ))
+        } else {
+            None
+        };
+
+        match link {
+            Some(link) => format!(
+                "/// {name} of the {} schema.\n///\n/// {link}\n",
+                self.schema.name
+            ),
+            None => format!("/// {name} of the {} schema.\n", self.schema.name),
+        }
+    }
+}
+
+/// name of the rust type, e.g. `IfcWallStandardCase` -> `WallStandardCase`
+fn type_name(name: &str) -> String {
+    match name.strip_prefix("Ifc") {
+        Some(stripped) if stripped.starts_with(|c: char| c.is_ascii_uppercase()) => {
+            stripped.to_owned()
+        }
+        _ => name.to_owned(),
+    }
+}
+
+/// snake case of a camel case name, e.g. `Axis2Placement3D` -> `axis2_placement3d` or
+/// `SIUnit` -> `si_unit`
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next = chars.get(index + 1).copied().unwrap_or_default();
+
+            // `2D` and `3D` stay together with their number
+            let dimension = previous.is_ascii_digit() && c == 'D' && !next.is_ascii_lowercase();
+
+            let starts_word = previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next.is_ascii_lowercase());
+
+            if starts_word && !dimension {
+                snake.push('_');
+            }
+        }
+
+        snake.push(c.to_ascii_lowercase());
+    }
+
+    snake
+}
+
+/// modules named after rust keywords are declared as raw identifiers, e.g. `mod r#loop;`
+fn file_name(name: &str) -> String {
+    format!("{}.rs", snake_case(&type_name(name)))
+}
+
+fn module_name(name: &str) -> String {
+    escape_keyword(snake_case(&type_name(name)))
+}
+
+fn field_name(name: &str) -> String {
+    escape_keyword(snake_case(name))
+}
+
+/// `global_id` -> `GlobalId`, the counterpart of the derive to [`field_name`]
+fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// enumeration values are upper case, e.g. `NOTDEFINED` -> `NotDefined`
+fn variant_name(value: &str) -> String {
+    let name: String = match value {
+        "NOTDEFINED" => "NotDefined".to_owned(),
+        "USERDEFINED" => "UserDefined".to_owned(),
+        _ => value
+            .split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut part = part.to_lowercase();
+                part[..1].make_ascii_uppercase();
+                part
+            })
+            .collect(),
+    };
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+fn escape_keyword(name: String) -> String {
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("r#{name}")
+    } else if matches!(name.as_str(), "crate" | "self" | "super") {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{field_name, module_name, type_name, variant_name};
+
+    #[test]
+    fn converts_names() {
+        assert_eq!(type_name("IfcWallStandardCase"), "WallStandardCase");
+        assert_eq!(module_name("IfcWallStandardCase"), "wall_standard_case");
+        assert_eq!(module_name("IfcSIUnit"), "si_unit");
+        assert_eq!(module_name("IfcBSplineCurve"), "b_spline_curve");
+        assert_eq!(module_name("IfcAxis2Placement3D"), "axis2_placement3d");
+        assert_eq!(
+            module_name("IfcCartesianPointList2D"),
+            "cartesian_point_list2d"
+        );
+        assert_eq!(module_name("IfcLoop"), "r#loop");
+
+        assert_eq!(field_name("GlobalId"), "global_id");
+        assert_eq!(field_name("RefLatitude"), "ref_latitude");
+        assert_eq!(field_name("Type"), "r#type");
+
+        assert_eq!(variant_name("NOTDEFINED"), "NotDefined");
+        assert_eq!(variant_name("POLYLINE_FORM"), "PolylineForm");
+        assert_eq!(variant_name("MOVABLE"), "Movable");
+    }
+}
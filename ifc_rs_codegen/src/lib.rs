@@ -0,0 +1,11 @@
+//! Generates the entity types of `ifc_rs` from the EXPRESS definition of an IFC schema, e.g.
+//! `IFC4.exp`, which is available from buildingSMART.
+//!
+//! Entities, enumerations and their inheritance are generated. SELECT types are not, attributes
+//! of selects are references or generic values, see [`generator::generate`].
+
+/// module containing the parser for the parts of EXPRESS schemas which describe the entities
+pub mod express;
+
+/// module containing the generation of the rust modules from a parsed schema
+pub mod generator;
@@ -0,0 +1,45 @@
+use std::{fs, path::PathBuf, process::Command};
+
+use anyhow::{bail, Context, Result};
+use ifc_rs_codegen::{express::Schema, generator::generate};
+
+/// Usage: `ifc_rs_codegen <schema.exp> <output directory>`
+///
+/// Writes one module per entity and enumeration of the schema into the output directory,
+/// together with a `mod.rs` which declares them and registers the entities.
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [schema_path, output] = args.as_slice() else {
+        bail!("usage: ifc_rs_codegen <schema.exp> <output directory>");
+    };
+
+    let source =
+        fs::read_to_string(schema_path).with_context(|| format!("failed to read {schema_path}"))?;
+    let schema = Schema::parse(&source).with_context(|| format!("invalid schema {schema_path}"))?;
+    let files = generate(&schema)?;
+
+    let output = PathBuf::from(output);
+    fs::create_dir_all(&output)?;
+    for (name, content) in &files {
+        fs::write(output.join(name), content)?;
+    }
+
+    // the generated code is laid out close to rustfmt already, long attribute lists are left to it
+    let formatted = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(files.keys().map(|name| output.join(name)))
+        .status();
+    if !formatted.is_ok_and(|status| status.success()) {
+        eprintln!("rustfmt failed, the generated modules are left unformatted");
+    }
+
+    println!(
+        "generated {} entities and {} types of {} into {}",
+        schema.entities.len(),
+        files.len() - schema.entities.len() - 1,
+        schema.name,
+        output.display()
+    );
+
+    Ok(())
+}
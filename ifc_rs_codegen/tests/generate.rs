@@ -0,0 +1,204 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use ifc_rs_codegen::{express::Schema, generator::generate};
+
+/// directory of `ifc_rs` in which the modules generated from the excerpts are compiled with its
+/// tests
+const GENERATED: &str = "../ifc_rs/src/generated_excerpts";
+
+#[test]
+fn generates_ifc4_excerpt() -> anyhow::Result<()> {
+    let schema = Schema::parse(&fs::read_to_string("tests/fixtures/IFC4_excerpt.exp")?)?;
+    let files = generate(&schema)?;
+
+    assert_eq!(schema.name, "IFC4");
+    assert_modules(
+        &schema,
+        &files,
+        &[
+            "change_action_enum.rs",
+            "derived_unit_enum.rs",
+            "geometric_projection_enum.rs",
+            "si_prefix.rs",
+            "si_unit_name.rs",
+            "state_enum.rs",
+            "unit_enum.rs",
+            "wall_type_enum.rs",
+        ],
+        &[
+            "axis2_placement.rs",
+            "measure_value.rs",
+            "simple_value.rs",
+            "unit.rs",
+            "value.rs",
+        ],
+    );
+
+    for name in [
+        "mod.rs",
+        "wall.rs",
+        "wall_standard_case.rs",
+        "wall_type_enum.rs",
+        "root.rs",
+        "si_unit.rs",
+        "property_single_value.rs",
+        "representation_item.rs",
+        "geometric_representation_sub_context.rs",
+        "local_placement.rs",
+        "cartesian_point.rs",
+    ] {
+        insta::assert_snapshot!(name, files[name]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn generates_ifc2x3_excerpt() -> anyhow::Result<()> {
+    let schema = Schema::parse(&fs::read_to_string("tests/fixtures/IFC2X3_excerpt.exp")?)?;
+    let files = generate(&schema)?;
+
+    assert_eq!(schema.name, "IFC2X3");
+    assert_modules(
+        &schema,
+        &files,
+        &[
+            "change_action_enum.rs",
+            "geometric_projection_enum.rs",
+            "si_prefix.rs",
+            "si_unit_name.rs",
+            "state_enum.rs",
+            "unit_enum.rs",
+        ],
+        &[
+            "axis2_placement.rs",
+            "measure_value.rs",
+            "simple_value.rs",
+            "unit.rs",
+            "value.rs",
+        ],
+    );
+
+    // walls without predefined type, mandatory owner history, reals without defined type and
+    // entities named after rust keywords
+    for name in [
+        "wall.rs",
+        "root.rs",
+        "geometric_representation_context.rs",
+        "material_layer.rs",
+        "loop.rs",
+        "poly_loop.rs",
+    ] {
+        insta::assert_snapshot!(format!("ifc2x3_{name}"), files[name]);
+    }
+    assert!(files["mod.rs"].contains("pub(crate) mod r#loop;\n"));
+
+    Ok(())
+}
+
+#[test]
+fn generates_ifc4x3_excerpt() -> anyhow::Result<()> {
+    let schema = Schema::parse(&fs::read_to_string("tests/fixtures/IFC4X3_excerpt.exp")?)?;
+    let files = generate(&schema)?;
+
+    assert_eq!(schema.name, "IFC4X3_ADD2");
+    assert_modules(
+        &schema,
+        &files,
+        &[
+            "change_action_enum.rs",
+            "geometric_projection_enum.rs",
+            "state_enum.rs",
+            "wall_type_enum.rs",
+        ],
+        &[
+            "axis2_placement.rs",
+            "segment_index_select.rs",
+            "simple_value.rs",
+            "unit.rs",
+        ],
+    );
+    assert!(!files.contains_key("wall_standard_case.rs"));
+
+    // built elements, extensible selects, placements relative to any object placement and
+    // nested aggregates
+    for name in [
+        "wall.rs",
+        "built_element.rs",
+        "property_single_value.rs",
+        "object_placement.rs",
+        "cartesian_point_list2d.rs",
+        "indexed_poly_curve.rs",
+    ] {
+        insta::assert_snapshot!(format!("ifc4x3_{name}"), files[name]);
+    }
+
+    Ok(())
+}
+
+/// Checks that there's one module per entity, one per enumeration and the `mod.rs`. Selects don't
+/// get modules, the attributes of their type are references or `StepValue`s.
+fn assert_modules(
+    schema: &Schema,
+    files: &BTreeMap<String, String>,
+    enumerations: &[&str],
+    selects: &[&str],
+) {
+    for name in enumerations.iter().chain(["mod.rs"].iter()) {
+        assert!(files.contains_key(*name), "{name} is missing");
+    }
+    for name in selects {
+        assert!(!files.contains_key(*name), "{name} was generated");
+    }
+
+    assert_eq!(files.len(), schema.entities.len() + enumerations.len() + 1);
+}
+
+/// The modules generated from the excerpts are part of the tests of `ifc_rs`, which makes sure
+/// that they compile against the crate. Run with `UPDATE_GENERATED=1` to write them again after
+/// changing the generator.
+#[test]
+fn generated_excerpts_are_up_to_date() -> anyhow::Result<()> {
+    let update = std::env::var_os("UPDATE_GENERATED").is_some();
+
+    for (fixture, module) in [
+        ("IFC2X3_excerpt.exp", "ifc2x3"),
+        ("IFC4_excerpt.exp", "ifc4"),
+        ("IFC4X3_excerpt.exp", "ifc4x3"),
+    ] {
+        let source = fs::read_to_string(Path::new("tests/fixtures").join(fixture))?;
+        let files = generate(&Schema::parse(&source)?)?;
+        let directory = Path::new(GENERATED).join(module);
+
+        if update {
+            if directory.exists() {
+                fs::remove_dir_all(&directory)?;
+            }
+            fs::create_dir_all(&directory)?;
+            for (name, content) in &files {
+                fs::write(directory.join(name), content)?;
+            }
+            continue;
+        }
+
+        let mut written = fs::read_dir(&directory)?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        written.sort();
+        assert_eq!(
+            written,
+            files.keys().cloned().collect::<Vec<_>>(),
+            "modules of {module} differ, run the tests with UPDATE_GENERATED=1"
+        );
+
+        for (name, content) in &files {
+            assert_eq!(
+                &fs::read_to_string(directory.join(name))?,
+                content,
+                "{module}/{name} differs, run the tests with UPDATE_GENERATED=1"
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -10,12 +10,15 @@ pub enum IdOrListType {
     TypedIdList(TokenStream),
     IdOr(TokenStream),
     IdOrList(TokenStream),
+    /// dynamic value which may contain ids anywhere, e.g. the members of a select type
+    StepValue,
 }
 
 impl IdOrListType {
     pub fn check_segment(segment: &PathSegment) -> Option<Self> {
         match segment.ident.to_string().as_str() {
             "Id" => Some(Self::Id),
+            "StepValue" => Some(Self::StepValue),
             "IfcList" => match &segment.arguments {
                 PathArguments::AngleBracketed(args) => {
                     args.args.first().and_then(|generic_type| -> Option<Self> {
@@ -69,7 +72,7 @@ impl IdOrListType {
     }
 
     pub fn needs_arguments(&self) -> bool {
-        matches!(self, Self::Id | Self::List)
+        matches!(self, Self::Id | Self::List | Self::StepValue)
     }
 }
 
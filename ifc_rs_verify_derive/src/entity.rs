@@ -0,0 +1,148 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident, Type};
+
+use crate::schema::EntitySchema;
+
+/// A struct field in attribute order. Inherited fields parse and write all attributes of the
+/// supertype, without its keyword.
+struct EntityField {
+    name: Ident,
+    ty: Type,
+    inherited: bool,
+}
+
+impl EntityField {
+    fn parser(&self) -> TokenStream {
+        let name = &self.name;
+        let ty = &self.ty;
+
+        if self.inherited {
+            quote! { #name: <#ty>::parse_attributes(), }
+        } else {
+            quote! { #name: <#ty as crate::parser::IFCParse>::parse(), }
+        }
+    }
+
+    fn writer(&self) -> TokenStream {
+        let name = &self.name;
+
+        if self.inherited {
+            quote! { self.#name.fmt_attributes(f)?; }
+        } else {
+            quote! { write!(f, "{}", self.#name)?; }
+        }
+    }
+}
+
+pub fn derive(ast: DeriveInput) -> syn::Result<TokenStream> {
+    let schema = EntitySchema::new(&ast.attrs)?;
+    let struct_name = &ast.ident;
+
+    let fields: Vec<EntityField> = match &ast.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(named_fields) => named_fields
+                .named
+                .iter()
+                .map(|field| EntityField {
+                    name: field.ident.clone().expect("named field should have a name"),
+                    ty: field.ty.clone(),
+                    inherited: field.attrs.iter().any(|a| a.path().is_ident("inherited")),
+                })
+                .collect(),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    struct_name,
+                    "IfcEntity is only implemented for structs with named fields.",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                struct_name,
+                "IfcEntity is only implemented for structs.",
+            ))
+        }
+    };
+
+    let (impls, types, where_clause) = ast.generics.split_for_impl();
+
+    let parsers = fields.iter().enumerate().map(|(index, field)| {
+        let comma = (index > 0).then(|| quote! { _: crate::parser::comma::Comma::parse(), });
+        let parser = field.parser();
+
+        quote! {
+            #comma
+            #parser
+        }
+    });
+
+    let writers = fields.iter().enumerate().map(|(index, field)| {
+        let comma = (index > 0).then(|| quote! { f.write_str(",")?; });
+        let writer = field.writer();
+
+        quote! {
+            #comma
+            #writer
+        }
+    });
+
+    let (parse, fmt) = match (schema.start(), schema.keyword()) {
+        (Some(start), Some(keyword)) => {
+            let keyword = format!("{keyword}(");
+
+            (
+                quote! {
+                    winnow::combinator::delimited(
+                        #start,
+                        Self::parse_attributes(),
+                        crate::parser::p_space_or_comment_surrounded(");"),
+                    )
+                },
+                quote! {
+                    f.write_str(#keyword)?;
+                    self.fmt_attributes(f)?;
+                    f.write_str(");")
+                },
+            )
+        }
+        _ => (
+            quote! { Self::parse_attributes() },
+            quote! { self.fmt_attributes(f) },
+        ),
+    };
+
+    Ok(quote! {
+        impl #impls #struct_name #types #where_clause {
+            /// Parses the attributes without the keyword, as they're written for subtypes.
+            pub(crate) fn parse_attributes<'a>() -> impl crate::parser::IFCParser<'a, Self> {
+                use crate::parser::IFCParse;
+
+                winnow::seq! {
+                    #struct_name {
+                        #(#parsers)*
+                    }
+                }
+            }
+
+            /// Writes the attributes without the keyword, as they're written for subtypes.
+            pub(crate) fn fmt_attributes(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                #(#writers)*
+
+                Ok(())
+            }
+        }
+
+        impl #impls crate::parser::IFCParse for #struct_name #types #where_clause {
+            fn parse<'a>() -> impl crate::parser::IFCParser<'a, Self> {
+                #parse
+            }
+        }
+
+        impl #impls std::fmt::Display for #struct_name #types #where_clause {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                #fmt
+            }
+        }
+    })
+}
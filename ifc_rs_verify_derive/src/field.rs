@@ -95,9 +95,12 @@ impl Field {
             }
         });
 
+        // entities which aren't modelled by the crate can't be checked
         let allow_dummy_check = quote! {
 
-            if t.type_id() == std::any::TypeId::of::<Dummy>() {
+            if t.type_id() == std::any::TypeId::of::<Dummy>()
+                || t.type_id() == std::any::TypeId::of::<UnknownEntity>()
+            {
                 correct_type = true;
             }
 
@@ -130,16 +133,128 @@ impl Field {
         let typed_str = typed.to_string();
 
         quote! {
-            if t.type_id() != std::any::TypeId::of::<#typed>() && t.type_id() != std::any::TypeId::of::<Dummy>() {
+            if t.type_id() != std::any::TypeId::of::<#typed>()
+                && t.type_id() != std::any::TypeId::of::<Dummy>()
+                && t.type_id() != std::any::TypeId::of::<UnknownEntity>()
+            {
                 anyhow::bail!("Variable {} of type {}: expected type {} but found {} ({})", #var_name, #struct_name, #typed_str, t.type_name(), id);
             }
         }
     }
 }
 
+impl Field {
+    /// whether the field has type annotations which can be checked
+    pub fn has_checks(&self) -> bool {
+        !(self.data_type.needs_arguments() && self.ifc_types.types().is_empty())
+    }
+
+    fn lookup(&self) -> TokenStream {
+        let struct_name = self.struct_name.to_string();
+        let var_name = self.variable_name.to_string();
+
+        quote! {
+            let t = ifc.data.try_get_untyped(id).ok_or_else(|| {
+                anyhow::anyhow!("Variable {} of type {} references {} which doesn't exist", #var_name, #struct_name, id)
+            })?;
+        }
+    }
+
+    /// calls the visitor for every id referenced by the field
+    pub fn visit_ids(&self) -> TokenStream {
+        let var_name = &self.variable_name;
+        let name = var_name.to_string();
+
+        let visit = |value: TokenStream, id_or_list: &IdOrListType| match id_or_list {
+            IdOrListType::Id => quote! {
+                visitor(#name, *#value);
+            },
+            IdOrListType::List => quote! {
+                #value.0.iter().for_each(|id| visitor(#name, *id));
+            },
+            IdOrListType::TypedId(_) => quote! {
+                visitor(#name, #value.id());
+            },
+            IdOrListType::TypedIdList(_) => quote! {
+                #value.0.iter().for_each(|typed_id| visitor(#name, typed_id.id()));
+            },
+            IdOrListType::IdOr(_) => quote! {
+                if let Some(typed_id) = #value.id() {
+                    visitor(#name, typed_id.id());
+                }
+            },
+            IdOrListType::IdOrList(_) => quote! {
+                #value.0.iter().filter_map(|id_or| id_or.id()).for_each(|typed_id| visitor(#name, typed_id.id()));
+            },
+            IdOrListType::StepValue => quote! {
+                #value.ids().for_each(|id| visitor(#name, id));
+            },
+        };
+
+        match &self.data_type {
+            DataType::Id(id_or_list) => visit(quote! { (&self.#var_name) }, id_or_list),
+            DataType::OptionalParameter(id_or_list) => {
+                let visit = visit(quote! { value }, id_or_list);
+
+                quote! {
+                    if let Some(value) = self.#var_name.custom() {
+                        #visit
+                    }
+                }
+            }
+        }
+    }
+
+    /// calls the visitor with mutable access to every id referenced by the field
+    pub fn visit_ids_mut(&self) -> TokenStream {
+        let var_name = &self.variable_name;
+        let name = var_name.to_string();
+
+        let visit = |value: TokenStream, id_or_list: &IdOrListType| match id_or_list {
+            IdOrListType::Id => quote! {
+                visitor(#name, #value);
+            },
+            IdOrListType::List => quote! {
+                #value.0.iter_mut().for_each(|id| visitor(#name, id));
+            },
+            IdOrListType::TypedId(_) => quote! {
+                visitor(#name, #value.id_mut());
+            },
+            IdOrListType::TypedIdList(_) => quote! {
+                #value.0.iter_mut().for_each(|typed_id| visitor(#name, typed_id.id_mut()));
+            },
+            IdOrListType::IdOr(_) => quote! {
+                if let Some(typed_id) = #value.id_mut() {
+                    visitor(#name, typed_id.id_mut());
+                }
+            },
+            IdOrListType::IdOrList(_) => quote! {
+                #value.0.iter_mut().filter_map(|id_or| id_or.id_mut()).for_each(|typed_id| visitor(#name, typed_id.id_mut()));
+            },
+            IdOrListType::StepValue => quote! {
+                #value.ids_mut().for_each(|id| visitor(#name, id));
+            },
+        };
+
+        match &self.data_type {
+            DataType::Id(id_or_list) => visit(quote! { (&mut self.#var_name) }, id_or_list),
+            DataType::OptionalParameter(id_or_list) => {
+                let visit = visit(quote! { value }, id_or_list);
+
+                quote! {
+                    if let Some(value) = self.#var_name.custom_mut() {
+                        #visit
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl ToTokens for Field {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let var_name = &self.variable_name;
+        let lookup = self.lookup();
 
         let check = match &self.data_type {
             DataType::Id(id_or_list) => match id_or_list {
@@ -149,18 +264,33 @@ impl ToTokens for Field {
                     quote! {
 
                         let id = self.#var_name;
-                        let t = ifc.data.get_untyped(id);
+                        #lookup
                         #multiple
 
                     }
                 }
+                IdOrListType::StepValue => {
+                    let multiple = self.loop_check();
+
+                    quote! {
+
+                        self.#var_name.ids().try_for_each(|id| {
+                            #lookup
+                            #multiple
+
+                            Ok(())
+                        })?;
+
+                    }
+                }
                 IdOrListType::List => {
                     let multiple = self.loop_check();
 
                     quote! {
 
                         self.#var_name.0.iter().try_for_each(|id| {
-                            let t = ifc.data.get_untyped(*id);
+                            let id = *id;
+                            #lookup
                             #multiple
 
                             Ok(())
@@ -174,7 +304,7 @@ impl ToTokens for Field {
                     quote! {
 
                         let id = self.#var_name.id();
-                        let t = ifc.data.get_untyped(id);
+                        #lookup
                         #single
 
                     }
@@ -186,7 +316,7 @@ impl ToTokens for Field {
 
                         self.#var_name.0.iter().try_for_each(|typed_id| {
                             let id = typed_id.id();
-                            let t = ifc.data.get_untyped(id);
+                            #lookup
                             #single
 
                             Ok(())
@@ -201,7 +331,7 @@ impl ToTokens for Field {
 
                         if let Some(typed_id) = self.#var_name.id() {
                             let id = typed_id.id();
-                            let t = ifc.data.get_untyped(id);
+                            #lookup
                             #single
                         }
 
@@ -215,7 +345,7 @@ impl ToTokens for Field {
                         self.#var_name.0.iter().try_for_each(|id_or| {
                             if let Some(typed_id) = id_or.id() {
                                 let id = typed_id.id();
-                                let t = ifc.data.get_untyped(id);
+                                #lookup
                                 #single
                             }
 
@@ -226,13 +356,30 @@ impl ToTokens for Field {
                 }
             },
             DataType::OptionalParameter(optional) => match optional {
+                IdOrListType::StepValue => {
+                    let multiple = self.loop_check();
+
+                    quote! {
+
+                        if let Some(#var_name) = self.#var_name.custom() {
+                            #var_name.ids().try_for_each(|id| {
+                                #lookup
+                                #multiple
+
+                                Ok(())
+                            })?;
+                        }
+
+                    }
+                }
                 IdOrListType::Id => {
                     let multiple = self.loop_check();
 
                     quote! {
 
                         if let Some(id) = self.#var_name.custom() {
-                            let t = ifc.data.get_untyped(*id);
+                            let id = *id;
+                            #lookup
                             #multiple
                         }
 
@@ -245,7 +392,8 @@ impl ToTokens for Field {
 
                         if let Some(#var_name) = self.#var_name.custom() {
                             #var_name.0.iter().try_for_each(|id| {
-                                let t = ifc.data.get_untyped(*id);
+                                let id = *id;
+                            #lookup
                                 #multiple
 
                                 Ok(())
@@ -261,7 +409,7 @@ impl ToTokens for Field {
 
                         if let Some(typed_id) = self.#var_name.custom() {
                             let id = typed_id.id();
-                            let t = ifc.data.get_untyped(id);
+                            #lookup
                             #single
                         }
 
@@ -275,7 +423,7 @@ impl ToTokens for Field {
                         if let Some(#var_name) = self.#var_name.custom() {
                             #var_name.0.iter().try_for_each(|typed_id| {
                                 let id = typed_id.id();
-                                let t = ifc.data.get_untyped(id);
+                                #lookup
                                 #single
 
                                 Ok(())
@@ -292,7 +440,7 @@ impl ToTokens for Field {
                         if let Some(id_or) = self.#var_name.custom() {
                             if let Some(typed_id) = id_or.id() {
                                 let id = typed_id.id();
-                                let t = ifc.data.get_untyped(id);
+                                #lookup
                                 #single
                             }
                         }
@@ -308,7 +456,7 @@ impl ToTokens for Field {
                             #var_name.0.iter().try_for_each(|id_or| {
                                 if let Some(typed_id) = id_or.id() {
                                     let id = typed_id.id();
-                                    let t = ifc.data.get_untyped(id);
+                                    #lookup
                                     #single
                                 }
 
@@ -1,13 +1,19 @@
 mod data_type;
+mod entity;
 mod field;
 mod inherited;
+mod reflect;
+mod schema;
 
 use data_type::DataType;
 use field::{Field, IfcTypesTokenType};
 use inherited::InheritedField;
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 
 use quote::{quote, ToTokens};
+use reflect::ReflectedField;
+use schema::EntitySchema;
 use syn::{parse_macro_input, Data, DeriveInput, Fields};
 
 enum FieldType {
@@ -41,12 +47,29 @@ impl ToTokens for FieldType {
     }
 }
 
-#[proc_macro_derive(IfcVerify, attributes(ifc_types, inherited))]
+/// Implements `IfcVerify` from the struct fields: the type checks of referenced ids with
+/// `#[ifc_types(...)]`, the traversal of all referenced ids and the reflection of the attributes.
+///
+/// Attributes are named after the fields in upper camel case or with `#[ifc(name = "...")]`.
+/// Entities which can be instantiated name their schema type with `#[ifc(name = "IfcWall")]`,
+/// see `IfcEntity`. `#[inherited]` fields contribute all attributes of the supertype.
+#[proc_macro_derive(IfcVerify, attributes(ifc, ifc_types, inherited))]
 pub fn ifc_type_builder(item: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(item as DeriveInput);
 
     let struct_name = ast.ident;
 
+    let reflection = match (&ast.data, EntitySchema::new(&ast.attrs)) {
+        (Data::Struct(data_struct), Ok(schema)) => {
+            match ReflectedField::collect(&data_struct.fields) {
+                Ok(fields) => reflect::methods(&struct_name, &schema, &fields),
+                Err(err) => return err.to_compile_error().into(),
+            }
+        }
+        (_, Err(err)) => return err.to_compile_error().into(),
+        _ => TokenStream2::new(),
+    };
+
     let ifc_types: Vec<FieldType> = match ast.data {
         Data::Struct(data_struct) => match data_struct.fields {
             Fields::Named(named_fields) => named_fields
@@ -75,20 +98,14 @@ pub fn ifc_type_builder(item: TokenStream) -> TokenStream {
                         .clone();
 
                     match collected.clone() {
-                        IfcTypesTokenType::Types(types) => {
-                            DataType::new(&field.ty).and_then(|data_type| {
-                                if data_type.needs_arguments() && types.is_empty() {
-                                    None
-                                } else {
-                                    Some(FieldType::Normal(Field {
-                                        struct_name: struct_name.clone(),
-                                        variable_name,
-                                        data_type,
-                                        ifc_types: collected,
-                                    }))
-                                }
+                        IfcTypesTokenType::Types(_) => DataType::new(&field.ty).map(|data_type| {
+                            FieldType::Normal(Field {
+                                struct_name: struct_name.clone(),
+                                variable_name,
+                                data_type,
+                                ifc_types: collected,
                             })
-                        }
+                        }),
                         IfcTypesTokenType::Inherited => {
                             Some(FieldType::Inherited(InheritedField { variable_name }))
                         }
@@ -112,6 +129,11 @@ pub fn ifc_type_builder(item: TokenStream) -> TokenStream {
 
     let (fields, inheriteds) = FieldType::split(ifc_types);
 
+    // plain ids without `ifc_types` can't be type checked, but they are still visited
+    let visit_ids: Vec<_> = fields.iter().map(|field| field.visit_ids()).collect();
+    let visit_ids_mut: Vec<_> = fields.iter().map(|field| field.visit_ids_mut()).collect();
+
+    let fields: Vec<_> = fields.into_iter().filter(Field::has_checks).collect();
     let check_var_functions: Vec<_> = fields.iter().map(|field| field.check_function()).collect();
 
     TokenStream::from(quote! {
@@ -133,6 +155,52 @@ pub fn ifc_type_builder(item: TokenStream) -> TokenStream {
 
                 Ok(())
             }
+
+            #[allow(unused_variables)]
+            fn visit_ids(&self, visitor: &mut dyn FnMut(&'static str, Id)) {
+                #(
+                    self.#inheriteds.visit_ids(visitor);
+                )*
+
+                #(
+                    #visit_ids
+                )*
+            }
+
+            #[allow(unused_variables)]
+            fn visit_ids_mut(&mut self, visitor: &mut dyn FnMut(&'static str, &mut Id)) {
+                #(
+                    self.#inheriteds.visit_ids_mut(visitor);
+                )*
+
+                #(
+                    #visit_ids_mut
+                )*
+            }
+
+            #reflection
         }
     })
 }
+
+/// Implements `IFCParse` and `Display` from the order of the struct fields.
+///
+/// Entities name their schema type with `#[ifc(name = "IfcWall")]`, which is written in upper case
+/// as keyword, and can accept further types while parsing with `alias = "..."`. Abstract
+/// supertypes have no keyword and only parse and write their attributes. `#[inherited]` fields are flattened into the entity with the generated
+/// `parse_attributes` and `fmt_attributes`, so the supertype has to derive `IfcEntity` as well.
+///
+/// So far walls, slabs, roofs, doors, windows, openings and shading devices use it together with
+/// their supertypes `Element`, `Product`, `Object` and `Root`, as well as all modules of
+/// `ifc_rs_codegen`. The other hand-written entities still implement `IFCParse` and `Display`
+/// themselves.
+// TODO: migrate the remaining hand-written entities, starting with the spatial elements. Entities
+// which accept more than one form, like 2D and 3D points, need support for that in the derive.
+#[proc_macro_derive(IfcEntity, attributes(ifc, inherited))]
+pub fn ifc_entity(item: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(item as DeriveInput);
+
+    entity::derive(ast)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,172 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, spanned::Spanned, Fields, Ident, LitStr, Member, Type};
+
+use crate::schema::{field_name, upper_camel_case, EntitySchema};
+
+/// A struct field in attribute order, which is either a single attribute or all attributes of an
+/// `#[inherited]` supertype.
+pub struct ReflectedField {
+    member: Member,
+    ty: Type,
+    /// name of the attribute in the schema, `None` for inherited fields
+    name: Option<String>,
+}
+
+impl ReflectedField {
+    pub fn collect(fields: &Fields) -> syn::Result<Vec<Self>> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let member = match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::Unnamed(index.into()),
+                };
+
+                let inherited = field.attrs.iter().any(|a| a.path().is_ident("inherited"));
+                let name = match (field_name(&field.attrs)?, &field.ident) {
+                    _ if inherited => None,
+                    (Some(name), _) => Some(name.value()),
+                    (None, Some(ident)) => Some(upper_camel_case(&ident.unraw().to_string())),
+                    (None, None) => {
+                        return Err(syn::Error::new(
+                            field.span(),
+                            "fields of tuple structs need a schema name, e.g. \
+                             `#[ifc(name = \"Coordinates\")]`",
+                        ))
+                    }
+                };
+
+                Ok(Self {
+                    member,
+                    ty: field.ty.clone(),
+                    name,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Generates the reflection methods of `IfcVerify` from the struct fields.
+pub fn methods(
+    struct_name: &Ident,
+    schema: &EntitySchema,
+    fields: &[ReflectedField],
+) -> TokenStream {
+    let attribute_types = fields.iter().map(|field| {
+        let ty = &field.ty;
+
+        match &field.name {
+            None => quote! {
+                attributes.extend(<#ty as IfcVerify>::attribute_types());
+            },
+            Some(name) => quote! {
+                attributes.push(crate::traits::reflect::Attribute {
+                    name: #name,
+                    kind: <#ty as crate::traits::reflect::StepAttribute>::KIND,
+                    derived: false,
+                });
+            },
+        }
+    });
+
+    let derived = &schema.derived;
+    let derived = (!derived.is_empty()).then(|| {
+        quote! {
+            for attribute in attributes.iter_mut() {
+                attribute.derived |= [#(#derived),*].contains(&attribute.name);
+            }
+        }
+    });
+
+    let attribute_values = fields.iter().map(|field| {
+        let member = &field.member;
+
+        match &field.name {
+            None => quote! {
+                values.extend(self.#member.attribute_values());
+            },
+            Some(_) => quote! {
+                values.push(crate::traits::reflect::StepAttribute::to_step_value(&self.#member));
+            },
+        }
+    });
+
+    let struct_name_str = struct_name.to_string();
+    let set_attribute_values = fields.iter().map(|field| {
+        let member = &field.member;
+        let ty = &field.ty;
+
+        match &field.name {
+            None => quote! {
+                let count = <#ty as IfcVerify>::attribute_types().len();
+                if position < count {
+                    return self.#member.set_attribute_value(position, value);
+                }
+                position -= count;
+            },
+            Some(name) => quote! {
+                if position == 0 {
+                    self.#member = crate::traits::reflect::StepAttribute::from_step_value(value)
+                        .map_err(|err| anyhow::anyhow!("invalid {} of {}: {}", #name, #struct_name_str, err))?;
+                    return Ok(());
+                }
+                position -= 1;
+            },
+        }
+    });
+
+    let entity_type = schema.name.as_ref().map(|name: &LitStr| {
+        quote! {
+            fn static_entity_type() -> Option<&'static crate::traits::reflect::EntityType> {
+                static ENTITY_TYPE: std::sync::LazyLock<crate::traits::reflect::EntityType> =
+                    std::sync::LazyLock::new(|| {
+                        crate::traits::reflect::EntityType::new(
+                            #name,
+                            <#struct_name as IfcVerify>::attribute_types(),
+                        )
+                    });
+
+                Some(&ENTITY_TYPE)
+            }
+
+            fn entity_type(&self) -> Option<&'static crate::traits::reflect::EntityType> {
+                <Self as IfcVerify>::static_entity_type()
+            }
+        }
+    });
+
+    quote! {
+        #[allow(unused_mut)]
+        fn attribute_types() -> Vec<crate::traits::reflect::Attribute> {
+            let mut attributes = Vec::new();
+            #(#attribute_types)*
+            #derived
+
+            attributes
+        }
+
+        #entity_type
+
+        #[allow(unused_mut)]
+        fn attribute_values(&self) -> Vec<crate::parser::StepValue> {
+            let mut values = Vec::new();
+            #(#attribute_values)*
+
+            values
+        }
+
+        #[allow(unused_assignments, unused_mut)]
+        fn set_attribute_value(
+            &mut self,
+            index: usize,
+            value: &crate::parser::StepValue,
+        ) -> anyhow::Result<()> {
+            let mut position = index;
+            #(#set_attribute_values)*
+
+            anyhow::bail!("{} has no attribute {}", #struct_name_str, index)
+        }
+    }
+}
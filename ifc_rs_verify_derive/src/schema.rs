@@ -0,0 +1,102 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, LitStr};
+
+/// Schema information of an entity, given with
+/// `#[ifc(name = "IfcWall", alias = "IfcWallStandardCase")]`.
+///
+/// Structs without a name are abstract supertypes like `Root` or `Element`, which are only ever
+/// written as part of the entities inheriting from them. Attributes which the entity derives from
+/// others are listed with `derived = "Dimensions"`.
+#[derive(Default)]
+pub struct EntitySchema {
+    pub name: Option<LitStr>,
+    pub aliases: Vec<LitStr>,
+    pub derived: Vec<LitStr>,
+}
+
+impl EntitySchema {
+    pub fn new(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut schema = Self::default();
+
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("ifc")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    schema.name = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("alias") {
+                    schema.aliases.push(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("derived") {
+                    schema.derived.push(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `name`, `alias` or `derived`"))
+                }
+            })?;
+        }
+
+        if schema.name.is_none() {
+            if let Some(alias) = schema.aliases.first() {
+                return Err(syn::Error::new(alias.span(), "`alias` requires a `name`"));
+            }
+        }
+
+        Ok(schema)
+    }
+
+    /// STEP keyword of the entity, e.g. `IFCWALL` for `IfcWall`
+    pub fn keyword(&self) -> Option<String> {
+        self.name.as_ref().map(|name| name.value().to_uppercase())
+    }
+
+    /// parser for the keyword including the opening bracket
+    pub fn start(&self) -> Option<TokenStream> {
+        let name = self.name.as_ref()?;
+
+        let starts = std::iter::once(name)
+            .chain(&self.aliases)
+            .map(|name| {
+                let start = format!("{}(", name.value().to_uppercase());
+                quote! { crate::parser::p_space_or_comment_surrounded(#start) }
+            })
+            .collect::<Vec<_>>();
+
+        Some(match starts.as_slice() {
+            [start] => start.clone(),
+            starts => quote! { winnow::combinator::alt((#(#starts),*)) },
+        })
+    }
+}
+
+/// Name of a field in the schema, given with `#[ifc(name = "ForLayerSet")]` if it isn't the upper
+/// camel case of the field name.
+pub fn field_name(attributes: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    let mut name = None;
+
+    for attribute in attributes.iter().filter(|a| a.path().is_ident("ifc")) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `name`"))
+            }
+        })?;
+    }
+
+    Ok(name)
+}
+
+/// `global_id` -> `GlobalId`
+pub fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
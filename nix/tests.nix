@@ -17,6 +17,7 @@
           text = ''
             cargo nextest --version
             cargo nextest run --release
+            cargo nextest run --release --package ifc_rs --features rayon
             echo SUCCESS
           '';
         };